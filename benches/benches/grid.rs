@@ -58,6 +58,44 @@ fn build_grid_flat_hierarchy(col_count: usize, row_count: usize) -> (TaffyTree,
     (taffy, root)
 }
 
+/// A grid with many auto-placed single-cell items interspersed with randomly positioned items that span 2x2 cells
+fn build_grid_auto_placement_hierarchy(
+    auto_item_count: usize,
+    spanning_item_count: usize,
+    grid_auto_flow: GridAutoFlow,
+) -> (TaffyTree, NodeId) {
+    const COLUMN_COUNT: i16 = 100;
+    let mut taffy = TaffyTree::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(12345);
+
+    let row_count = (auto_item_count + spanning_item_count * 4) as i16 / COLUMN_COUNT;
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![length(10.0); COLUMN_COUNT as usize],
+        grid_auto_rows: vec![length(10.0)],
+        grid_auto_flow,
+        ..Default::default()
+    };
+
+    let mut children: Vec<_> =
+        iter::from_fn(|| Some(build_random_leaf(&mut taffy, &mut rng))).take(auto_item_count).collect();
+    for _ in 0..spanning_item_count {
+        let column_start = rng.gen_range(1..COLUMN_COUNT);
+        let row_start = rng.gen_range(1..row_count);
+        let child_style = Style {
+            grid_column: Line { start: line(column_start), end: span(2) },
+            grid_row: Line { start: line(row_start), end: span(2) },
+            ..Default::default()
+        };
+        let child = taffy.new_leaf(child_style).unwrap();
+        let index = rng.gen_range(0..=children.len());
+        children.insert(index, child);
+    }
+
+    let root = taffy.new_with_children(style, children.as_slice()).unwrap();
+    (taffy, root)
+}
+
 /// A helper function to recursively construct a deep tree
 pub fn build_deep_grid_tree(
     tree: &mut TaffyTree,
@@ -118,6 +156,40 @@ fn taffy_benchmarks(c: &mut Criterion) {
     }
     group.finish();
 
+    let mut group = c.benchmark_group("grid/many-columns");
+    group.sample_size(10);
+    for col_count in [100usize, 1000].iter() {
+        group.bench_with_input(
+            BenchmarkId::new(format!("{c}x1", c = col_count), col_count),
+            col_count,
+            |b, &col_count| {
+                b.iter_batched(
+                    || build_grid_flat_hierarchy(col_count, 1),
+                    |(mut taffy, root)| taffy.compute_layout(root, length(12000.0)).unwrap(),
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("grid/auto-placement");
+    group.sample_size(10);
+    for grid_auto_flow in [GridAutoFlow::Row, GridAutoFlow::RowDense].iter() {
+        group.bench_with_input(
+            BenchmarkId::new(format!("{grid_auto_flow:?}"), "10k single-cell + 1k 2x2"),
+            grid_auto_flow,
+            |b, &grid_auto_flow| {
+                b.iter_batched(
+                    || build_grid_auto_placement_hierarchy(10_000, 1_000, grid_auto_flow),
+                    |(mut taffy, root)| taffy.compute_layout(root, max_content()).unwrap(),
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+
     let mut group = c.benchmark_group("grid/deep");
     group.sample_size(10);
     for (tracks, levels) in [(2, 5), (3, 4), (2, 7) /*, (3, 5)*/].iter() {
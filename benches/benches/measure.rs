@@ -0,0 +1,149 @@
+//! This file includes benchmarks for trees containing large numbers of measured (text) leaves
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use taffy::prelude::*;
+use taffy::style::Style;
+
+/// The number of text leaves in each container node
+const LEAVES_PER_CONTAINER: usize = 100;
+
+/// Container style: a wrapping row of text leaves
+fn container_style() -> Style {
+    Style { flex_wrap: FlexWrap::Wrap, ..Default::default() }
+}
+
+/// A crude approximation of text measurement: each character is 10px wide and 10px tall, and text
+/// wraps at the available width (or at the known width if there is one)
+fn measure_text(
+    char_count: usize,
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+) -> Size<f32> {
+    const CHAR_SIZE: f32 = 10.0;
+    let max_width = char_count as f32 * CHAR_SIZE;
+    let width = known_dimensions.width.unwrap_or_else(|| match available_space.width {
+        AvailableSpace::MinContent => CHAR_SIZE,
+        AvailableSpace::MaxContent => max_width,
+        AvailableSpace::Definite(width) => width.min(max_width).max(CHAR_SIZE),
+    });
+    let height = known_dimensions.height.unwrap_or_else(|| (max_width / width).ceil() * CHAR_SIZE);
+    Size { width, height }
+}
+
+/// Build a tree in which every leaf is measured via the tree-level measure function, using a per-node
+/// context to hold the length of the leaf's text
+fn build_taffy_text_hierarchy(leaf_count: usize) -> (TaffyTree<usize>, NodeId) {
+    let mut taffy = TaffyTree::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(12345);
+
+    let containers: Vec<NodeId> = (0..leaf_count / LEAVES_PER_CONTAINER)
+        .map(|_| {
+            let leaves: Vec<NodeId> = (0..LEAVES_PER_CONTAINER)
+                .map(|_| taffy.new_leaf_with_context(Style::DEFAULT, rng.gen_range(1..200)).unwrap())
+                .collect();
+            taffy.new_with_children(container_style(), &leaves).unwrap()
+        })
+        .collect();
+
+    let root_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+    let root = taffy.new_with_children(root_style, &containers).unwrap();
+    (taffy, root)
+}
+
+/// Build the same tree using Taffy 0.3, in which every leaf has its own boxed measure function
+#[cfg(feature = "taffy03")]
+fn build_taffy_03_text_hierarchy(leaf_count: usize) -> (taffy_03::Taffy, taffy_03::prelude::Node) {
+    use taffy_03::prelude::{AvailableSpace as AvailableSpace03, Node as Node03, Size as Size03, Style as Style03};
+
+    let mut taffy = taffy_03::Taffy::new();
+    let mut rng = ChaCha8Rng::seed_from_u64(12345);
+
+    let convert_available_space = |space| match space {
+        AvailableSpace03::Definite(val) => AvailableSpace::Definite(val),
+        AvailableSpace03::MinContent => AvailableSpace::MinContent,
+        AvailableSpace03::MaxContent => AvailableSpace::MaxContent,
+    };
+
+    let containers: Vec<Node03> = (0..leaf_count / LEAVES_PER_CONTAINER)
+        .map(|_| {
+            let leaves: Vec<Node03> = (0..LEAVES_PER_CONTAINER)
+                .map(|_| {
+                    let char_count: usize = rng.gen_range(1..200);
+                    let measure = taffy_03::node::MeasureFunc::Boxed(Box::new(
+                        move |known_dimensions: Size03<Option<f32>>, available_space: Size03<AvailableSpace03>| {
+                            let size = measure_text(
+                                char_count,
+                                Size { width: known_dimensions.width, height: known_dimensions.height },
+                                Size {
+                                    width: convert_available_space(available_space.width),
+                                    height: convert_available_space(available_space.height),
+                                },
+                            );
+                            Size03 { width: size.width, height: size.height }
+                        },
+                    ));
+                    taffy.new_leaf_with_measure(Style03::DEFAULT, measure).unwrap()
+                })
+                .collect();
+            let style = Style03 { flex_wrap: taffy_03::prelude::FlexWrap::Wrap, ..Default::default() };
+            taffy.new_with_children(style, &leaves).unwrap()
+        })
+        .collect();
+
+    let root_style = Style03 { flex_direction: taffy_03::prelude::FlexDirection::Column, ..Default::default() };
+    let root = taffy.new_with_children(root_style, &containers).unwrap();
+    (taffy, root)
+}
+
+fn taffy_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("measure/text leaves");
+    group.sample_size(10);
+    for leaf_count in [10_000usize, 50_000].iter() {
+        #[cfg(feature = "taffy03")]
+        group.bench_with_input(
+            BenchmarkId::new("Taffy 0.3 (boxed measure functions)", leaf_count),
+            leaf_count,
+            |b, &leaf_count| {
+                b.iter_batched(
+                    || build_taffy_03_text_hierarchy(leaf_count),
+                    |(mut taffy, root)| {
+                        let available_space = taffy_03::prelude::Size {
+                            width: taffy_03::prelude::AvailableSpace::Definite(1000.0),
+                            height: taffy_03::prelude::AvailableSpace::MaxContent,
+                        };
+                        taffy.compute_layout(root, available_space).unwrap()
+                    },
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("Taffy 0.4 (measure function + context)", leaf_count),
+            leaf_count,
+            |b, &leaf_count| {
+                b.iter_batched(
+                    || build_taffy_text_hierarchy(leaf_count),
+                    |(mut taffy, root)| {
+                        let available_space = Size { width: length(1000.0), height: AvailableSpace::MaxContent };
+                        taffy
+                            .compute_layout_with_measure(
+                                root,
+                                available_space,
+                                |known_dimensions, available_space, _node_id, char_count| {
+                                    measure_text(char_count.copied().unwrap_or(0), known_dimensions, available_space)
+                                },
+                            )
+                            .unwrap()
+                    },
+                    criterion::BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, taffy_benchmarks);
+criterion_main!(benches);
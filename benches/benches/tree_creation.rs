@@ -38,6 +38,28 @@ fn build_taffy_flat_hierarchy(total_node_count: u32, use_with_capacity: bool) ->
     (taffy, root)
 }
 
+/// A tree with many children that have shallow depth, constructed from the top down using `add_children_batch`
+fn build_taffy_flat_hierarchy_batched(total_node_count: u32) -> (TaffyTree, NodeId) {
+    let mut taffy = TaffyTree::with_capacity(total_node_count as usize);
+    let mut rng = ChaCha8Rng::seed_from_u64(12345);
+    let mut sub_children_counts = Vec::new();
+    let mut node_count = 0;
+
+    while node_count < total_node_count {
+        let sub_children_count = rng.gen_range(1..=4);
+        sub_children_counts.push(sub_children_count);
+        node_count += 1 + sub_children_count;
+    }
+
+    let root = taffy.new_leaf(Style::DEFAULT).unwrap();
+    let children = taffy.add_children_batch(root, sub_children_counts.iter().map(|_| Style::DEFAULT)).unwrap();
+    for (node, sub_children_count) in children.into_iter().zip(sub_children_counts) {
+        taffy.add_children_batch(node, (0..sub_children_count).map(|_| Style::DEFAULT)).unwrap();
+    }
+
+    (taffy, root)
+}
+
 #[cfg(feature = "yoga")]
 /// A tree with many children that have shallow depth
 fn build_yoga_flat_hierarchy(total_node_count: u32) -> (yg::YogaTree, yg::NodeId) {
@@ -90,6 +112,15 @@ fn taffy_benchmarks(c: &mut Criterion) {
                 std::hint::black_box(root);
             })
         });
+
+        let benchmark_id = BenchmarkId::new(format!("TaffyTree::add_children_batch"), node_count);
+        group.bench_with_input(benchmark_id, node_count, |b, &node_count| {
+            b.iter(|| {
+                let (tree, root) = build_taffy_flat_hierarchy_batched(node_count);
+                std::hint::black_box(tree);
+                std::hint::black_box(root);
+            })
+        });
     }
     group.finish();
 }
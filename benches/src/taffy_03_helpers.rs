@@ -124,6 +124,8 @@ fn convert_dimension(input: taffy::style::Dimension) -> taffy_03::style::Dimensi
         taffy::style::Dimension::Length(val) => taffy_03::style::Dimension::Points(val),
         taffy::style::Dimension::Percent(val) => taffy_03::style::Dimension::Percent(val),
         taffy::style::Dimension::Auto => taffy_03::style::Dimension::Auto,
+        // Taffy 0.3 doesn't support `flex-basis: content`
+        taffy::style::Dimension::Content => taffy_03::style::Dimension::Auto,
     }
 }
 
@@ -130,6 +130,8 @@ fn set_node_children(tree: &mut yg::YogaTree, node_id: yg::DefaultKey, children:
 fn into_yg_units(dim: impl Into<tf::Dimension>) -> yg::StyleUnit {
     match dim.into() {
         tf::Dimension::Auto => yg::StyleUnit::Auto,
+        // Yoga doesn't support `flex-basis: content`
+        tf::Dimension::Content => yg::StyleUnit::Auto,
         tf::Dimension::Length(val) => yg::StyleUnit::Point(yg::OrderedFloat(val)),
         tf::Dimension::Percent(val) => yg::StyleUnit::Percent(yg::OrderedFloat(val)),
     }
@@ -164,6 +166,9 @@ fn content_into_align(align: Option<tf::AlignContent>) -> yg::Align {
         Some(tf::AlignContent::SpaceBetween) => yg::Align::SpaceBetween,
         Some(tf::AlignContent::SpaceAround) => yg::Align::SpaceAround,
         Some(tf::AlignContent::SpaceEvenly) => unimplemented!(),
+        Some(tf::AlignContent::SafeEnd) => unimplemented!(),
+        Some(tf::AlignContent::SafeFlexEnd) => unimplemented!(),
+        Some(tf::AlignContent::SafeCenter) => unimplemented!(),
     }
 }
 
@@ -179,6 +184,9 @@ fn content_into_justify(align: Option<tf::JustifyContent>) -> yg::Justify {
         Some(tf::JustifyContent::SpaceAround) => yg::Justify::SpaceAround,
         Some(tf::JustifyContent::Stretch) => unimplemented!(),
         Some(tf::JustifyContent::SpaceEvenly) => unimplemented!(),
+        Some(tf::JustifyContent::SafeEnd) => unimplemented!(),
+        Some(tf::JustifyContent::SafeFlexEnd) => unimplemented!(),
+        Some(tf::JustifyContent::SafeCenter) => unimplemented!(),
     }
 }
 
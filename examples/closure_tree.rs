@@ -0,0 +1,57 @@
+mod common {
+    pub mod text;
+}
+use common::text::{text_measure_function, FontMetrics, TextContext, WritingMode, LOREM_IPSUM};
+use taffy::prelude::*;
+use taffy::ClosureTree;
+
+// A UI tree owned by some other library (standing in for e.g. a `petgraph` graph). Taffy only needs to be able to
+// get the style and the children of each node, so the tree can be laid out in place without copying it into a
+// `TaffyTree` or implementing Taffy's traits for it.
+struct Widget {
+    style: Style,
+    text: Option<TextContext>,
+    children: Vec<usize>,
+    layout: Layout,
+}
+
+fn widget(style: Style, children: Vec<usize>) -> Widget {
+    Widget { style, text: None, children, layout: Layout::new() }
+}
+
+fn text(text: &str) -> Widget {
+    let text = TextContext { text_content: text.to_string(), writing_mode: WritingMode::Horizontal };
+    Widget { style: Style::default(), text: Some(text), children: Vec::new(), layout: Layout::new() }
+}
+
+fn main() {
+    let mut widgets = vec![
+        widget(Style { flex_direction: FlexDirection::Column, size: length(400.0), ..Default::default() }, vec![1, 2]),
+        widget(Style { size: Size { width: percent(1.0), height: length(40.0) }, ..Default::default() }, vec![]),
+        widget(Style { flex_grow: 1.0, padding: length(10.0), ..Default::default() }, vec![3]),
+        text(LOREM_IPSUM),
+    ];
+
+    let font_metrics = FontMetrics { char_width: 10.0, char_height: 10.0 };
+    let mut layouts = vec![Layout::new(); widgets.len()];
+
+    let mut tree = ClosureTree::new(
+        &widgets,
+        |widgets, node| &widgets[usize::from(node)].style,
+        |widgets, node| widgets[usize::from(node)].children.iter().map(|&child| NodeId::from(child)),
+    )
+    .with_measure(|known_dimensions, available_space, node, _style| match &widgets[usize::from(node)].text {
+        Some(text) => text_measure_function(known_dimensions, available_space, text, &font_metrics),
+        None => Size::ZERO,
+    });
+    tree.compute_layout(NodeId::from(0usize), Size::MAX_CONTENT, |node, layout| layouts[usize::from(node)] = *layout);
+
+    // Once the adaptor (and so its borrow of the tree) has been dropped, the layouts can be stored in the tree
+    for (widget, layout) in widgets.iter_mut().zip(layouts) {
+        widget.layout = layout;
+    }
+
+    for (index, widget) in widgets.iter().enumerate() {
+        println!("node {index}: {:?} at {:?}", widget.layout.size, widget.layout.location);
+    }
+}
@@ -495,6 +495,9 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-start" => quote!(align_content: Some(taffy::style::AlignContent::FlexStart),),
             "flex-end" => quote!(align_content: Some(taffy::style::AlignContent::FlexEnd),),
             "center" => quote!(align_content: Some(taffy::style::AlignContent::Center),),
+            "safe end" => quote!(align_content: Some(taffy::style::AlignContent::SafeEnd),),
+            "safe flex-end" => quote!(align_content: Some(taffy::style::AlignContent::SafeFlexEnd),),
+            "safe center" => quote!(align_content: Some(taffy::style::AlignContent::SafeCenter),),
             "stretch" => quote!(align_content: Some(taffy::style::AlignContent::Stretch),),
             "space-between" => quote!(align_content: Some(taffy::style::AlignContent::SpaceBetween),),
             "space-around" => quote!(align_content: Some(taffy::style::AlignContent::SpaceAround),),
@@ -511,6 +514,9 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
             "flex-start" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexStart),),
             "flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::FlexEnd),),
             "center" => quote!(justify_content: Some(taffy::style::JustifyContent::Center),),
+            "safe end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeEnd),),
+            "safe flex-end" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeFlexEnd),),
+            "safe center" => quote!(justify_content: Some(taffy::style::JustifyContent::SafeCenter),),
             "stretch" => quote!(justify_content: Some(taffy::style::AlignContent::Stretch),),
             "space-between" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceBetween),),
             "space-around" => quote!(justify_content: Some(taffy::style::JustifyContent::SpaceAround),),
@@ -528,6 +534,11 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
     let min_size = quote_object_prop("min_size", style, generate_size);
     let max_size = quote_object_prop("max_size", style, generate_size);
     let aspect_ratio = quote_number_prop("aspect_ratio", style, |value: f32| quote!(Some(#value)));
+    let contain_size = match style["containSize"] {
+        Value::Bool(true) => quote!(contain_size: true,),
+        _ => quote!(),
+    };
+    let contain_intrinsic_size = quote_object_prop("contain_intrinsic_size", style, generate_contain_intrinsic_size);
 
     let gap = quote_object_prop("gap", style, generate_gap);
 
@@ -630,6 +641,8 @@ fn generate_node(ident: &str, node: &Value) -> TokenStream {
         #min_size
         #max_size
         #aspect_ratio
+        #contain_size
+        #contain_intrinsic_size
         #margin
         #padding
         #inset
@@ -659,6 +672,32 @@ fn generate_size(size: &serde_json::Map<String, Value>) -> TokenStream {
     )
 }
 
+fn generate_contain_intrinsic_size(size: &serde_json::Map<String, Value>) -> TokenStream {
+    dim_quoted!(size, width, generate_optional_length, quote!(None));
+    dim_quoted!(size, height, generate_optional_length, quote!(None));
+    quote!(
+        taffy::geometry::Size {
+            #width #height
+        }
+    )
+}
+
+fn generate_optional_length(dimen: &serde_json::Map<String, Value>) -> TokenStream {
+    let unit = dimen.get("unit").unwrap();
+    let value = || dimen.get("value").unwrap().as_f64().unwrap() as f32;
+
+    match unit {
+        Value::String(ref unit) => match unit.as_ref() {
+            "px" => {
+                let value = value();
+                quote!(Some(#value))
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 fn generate_gap(size: &serde_json::Map<String, Value>) -> TokenStream {
     dim_quoted_renamed!(size, column, width, generate_length_percentage, quote!(zero()));
     dim_quoted_renamed!(size, row, height, generate_length_percentage, quote!(zero()));
@@ -717,6 +756,7 @@ fn generate_dimension(dimen: &serde_json::Map<String, Value>) -> TokenStream {
     match unit {
         Value::String(ref unit) => match unit.as_ref() {
             "auto" => quote!(taffy::style::Dimension::Auto),
+            "content" => quote!(taffy::style::Dimension::Content),
             "px" => {
                 let value = value();
                 quote!(taffy::style::Dimension::Length(#value))
@@ -116,6 +116,7 @@ function parseDimension(input, options = { allowFrUnits: false }) {
   if (input.endsWith('px')) return { unit: 'px', value: parseFloat(input.replace('px', '')) };
   if (input.endsWith('%')) return { unit: 'percent', value: parseFloat(input.replace('%', '')) / 100 };
   if (input === 'auto') return { unit: 'auto' };
+  if (input === 'content') return { unit: 'content' };
   if (input === 'min-content') return { unit: 'min-content' };
   if (input === 'max-content') return { unit: 'max-content' };
   return undefined;
@@ -244,6 +245,8 @@ function describeElement(e) {
       minSize: parseSize({ width: e.style.minWidth, height: e.style.minHeight }),
       maxSize: parseSize({ width: e.style.maxWidth, height: e.style.maxHeight }),
       aspectRatio: parseRatio(e.style.aspectRatio),
+      containSize: /\b(size|strict)\b/.test(e.style.contain),
+      containIntrinsicSize: parseSize({ width: e.style.containIntrinsicWidth, height: e.style.containIntrinsicHeight }),
 
       margin: parseEdges({
         left: e.style.marginLeft,
@@ -309,8 +312,8 @@ function describeElement(e) {
     smartRoundedLayout: {
       width: Math.round(boundingRect.right) - Math.round(boundingRect.left),
       height: Math.round(boundingRect.bottom) - Math.round(boundingRect.top),
-      x: Math.round(boundingRect.x - parentBoundingRect.x),
-      y: Math.round(boundingRect.y - parentBoundingRect.y),
+      x: Math.round(boundingRect.x) - Math.round(parentBoundingRect.x),
+      y: Math.round(boundingRect.y) - Math.round(parentBoundingRect.y),
       scrollWidth: e.scrollWidth,
       scrollHeight: e.scrollHeight,
       clientWidth: e.clientWidth,
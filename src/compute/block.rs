@@ -1,17 +1,17 @@
 //! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
-use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AvailableSpace, Display, LengthPercentageAuto, Overflow, Position};
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::style::{AlignItems, AlignSelf, AvailableSpace, Display, LengthPercentageAuto, Overflow, Position};
 use crate::style_helpers::TaffyMaxContent;
 use crate::tree::{CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
-use crate::util::debug::debug_log;
+use crate::util::debug::{debug_log, profile_algorithm};
 use crate::util::sys::f32_max;
 use crate::util::sys::Vec;
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 
 #[cfg(feature = "content_size")]
-use super::common::content_size::compute_content_size_contribution;
+use super::common::content_size::{add_end_padding, compute_content_size_contribution};
 
 /// Per-child data that is accumulated and modified over the course of the layout algorithm
 struct BlockItem {
@@ -36,6 +36,8 @@ struct BlockItem {
 
     /// The position style of the item
     position: Position,
+    /// The item's justify_self property, or the parent's justify_items property if not set
+    justify_self: Option<AlignSelf>,
     /// The final offset of this item
     inset: Rect<LengthPercentageAuto>,
     /// The margin of this item
@@ -58,11 +60,13 @@ struct BlockItem {
 
 /// Computes the layout of [`LayoutPartialTree`] according to the block layout algorithm
 pub fn compute_block_layout(tree: &mut impl LayoutPartialTree, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    profile_algorithm!(Block);
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
     let style = tree.get_style(node_id);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio;
+    let contain_size = style.contain_size;
     let margin = style.margin.resolve_or_zero(parent_size.width);
     let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
     let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
@@ -82,14 +86,27 @@ pub fn compute_block_layout(tree: &mut impl LayoutPartialTree, node_id: NodeId,
     });
 
     // Block nodes automatically stretch fit their width to fit available space if available space is definite
+    // (subject to their min and max size)
     let available_space_based_size =
-        Size { width: available_space.width.into_option().maybe_sub(margin.horizontal_axis_sum()), height: None };
+        Size { width: available_space.width.into_option().maybe_sub(margin.horizontal_axis_sum()), height: None }
+            .maybe_clamp(min_size, max_size);
 
-    let styled_based_known_dimensions = known_dimensions
-        .or(min_max_definite_size)
-        .or(clamped_style_size)
-        .or(available_space_based_size)
-        .maybe_max(padding_border_size);
+    // A container with size containment may specify the size of its content box in place of the size of its contents
+    let contain_intrinsic_size = if contain_size {
+        style.contain_intrinsic_size.maybe_add(padding_border_size).maybe_clamp(min_size, max_size)
+    } else {
+        Size::NONE
+    };
+
+    let known_dimensions =
+        known_dimensions.or(min_max_definite_size).or(clamped_style_size).or(available_space_based_size);
+
+    // If only one dimension is known (for example because the width stretches to fill the available space, as it does
+    // for a root node with an auto width) then the other dimension is derived from the aspect ratio (if any)
+    let aspect_ratio_size = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+
+    let mut styled_based_known_dimensions =
+        known_dimensions.or(aspect_ratio_size).or(contain_intrinsic_size).maybe_max(padding_border_size);
 
     // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
     // is ComputeSize (and thus the container's size is all that we're interested in)
@@ -99,6 +116,14 @@ pub fn compute_block_layout(tree: &mut impl LayoutPartialTree, node_id: NodeId,
         }
     }
 
+    // A container with size containment is sized as if it had no children (see `compute_inner`). If its size is not
+    // already known then compute it up front so that its children are laid out within that size.
+    if contain_size && run_mode == RunMode::PerformLayout && !styled_based_known_dimensions.both_axis_defined() {
+        let size_inputs =
+            LayoutInput { known_dimensions: styled_based_known_dimensions, run_mode: RunMode::ComputeSize, ..inputs };
+        styled_based_known_dimensions = compute_inner(tree, node_id, size_inputs).size.map(Some);
+    }
+
     debug_log!("BLOCK");
     compute_inner(tree, node_id, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
 }
@@ -113,6 +138,8 @@ fn compute_inner(tree: &mut impl LayoutPartialTree, node_id: NodeId, inputs: Lay
     let raw_padding = style.padding;
     let raw_border = style.border;
     let raw_margin = style.margin;
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+    let overflow = style.overflow;
     let aspect_ratio = style.aspect_ratio;
     let size = style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
     let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
@@ -134,7 +161,7 @@ fn compute_inner(tree: &mut impl LayoutPartialTree, node_id: NodeId, inputs: Lay
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let content_box_inset = padding_border + scrollbar_gutter;
-    let container_content_box_size = known_dimensions.maybe_sub(content_box_inset.sum_axes());
+    let container_content_box_size = known_dimensions.maybe_sub(content_box_inset.sum_axes()).maybe_max(Size::ZERO);
 
     // Determine margin collapsing behaviour
     let own_margins_collapse_with_children = Line {
@@ -162,11 +189,17 @@ fn compute_inner(tree: &mut impl LayoutPartialTree, node_id: NodeId, inputs: Lay
         || border.bottom > 0.0;
 
     // 1. Generate items
-    let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    // A container with size containment is sized as if it had no children
+    let mut items = if run_mode == RunMode::ComputeSize && style.contain_size {
+        Vec::new()
+    } else {
+        let justify_items = style.justify_items;
+        generate_item_list(tree, node_id, container_content_box_size, justify_items)
+    };
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
-        let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum());
+        let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum()).maybe_max(0.0);
         let intrinsic_width = determine_content_based_container_width(tree, &items, available_width)
             + content_box_inset.horizontal_axis_sum();
         intrinsic_width.maybe_clamp(min_size.width, max_size.width).maybe_max(Some(padding_border_size.width))
@@ -232,7 +265,11 @@ fn compute_inner(tree: &mut impl LayoutPartialTree, node_id: NodeId, inputs: Lay
         !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-    let content_size = inflow_content_size.f32_max(absolute_content_size);
+    let content_size = {
+        #[cfg(feature = "content_size")]
+        let inflow_content_size = add_end_padding(inflow_content_size, resolved_padding, overflow);
+        inflow_content_size.f32_max(absolute_content_size)
+    };
 
     LayoutOutput {
         size: final_outer_size,
@@ -261,6 +298,7 @@ fn generate_item_list(
     tree: &impl LayoutPartialTree,
     node: NodeId,
     node_inner_size: Size<Option<f32>>,
+    justify_items: Option<AlignItems>,
 ) -> Vec<BlockItem> {
     tree.child_ids(node)
         .map(|child_node_id| (child_node_id, tree.get_style(child_node_id)))
@@ -280,6 +318,7 @@ fn generate_item_list(
                 overflow: child_style.overflow,
                 scrollbar_width: child_style.scrollbar_width,
                 position: child_style.position,
+                justify_self: child_style.justify_self.or(justify_items),
                 inset: child_style.inset,
                 margin: child_style.margin,
                 padding,
@@ -341,7 +380,7 @@ fn perform_final_layout_on_in_flow_children(
     own_margins_collapse_with_children: Line<bool>,
 ) -> (Size<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet) {
     // Resolve container_inner_width for sizing child nodes using intial content_box_inset
-    let container_inner_width = container_outer_width - content_box_inset.horizontal_axis_sum();
+    let container_inner_width = f32_max(container_outer_width - content_box_inset.horizontal_axis_sum(), 0.0);
     let parent_size = Size { width: Some(container_outer_width), height: None };
     let available_space =
         Size { width: AvailableSpace::Definite(container_inner_width), height: AvailableSpace::MinContent };
@@ -354,15 +393,40 @@ fn perform_final_layout_on_in_flow_children(
     let mut is_collapsing_with_first_margin_set = true;
     for item in items.iter_mut() {
         if item.position == Position::Absolute {
-            item.static_position.y = committed_y_offset;
+            item.static_position = Point { x: resolved_content_box_inset.left, y: committed_y_offset };
         } else {
             let item_margin = item.margin.map(|margin| margin.resolve_to_option(container_outer_width));
             let item_non_auto_margin = item_margin.map(|m| m.unwrap_or(0.0));
             let item_non_auto_x_margin_sum = item_non_auto_margin.horizontal_axis_sum();
-            let known_dimensions = item
-                .size
-                .map_width(|width| Some(width.unwrap_or(container_inner_width - item_non_auto_x_margin_sum)))
-                .maybe_clamp(item.min_size, item.max_size);
+            // Items with an auto width are stretched to fill the container unless they are aligned in the inline axis
+            // by justify-self (or their parent's justify-items), in which case they are sized to fit their content
+            let is_stretched = matches!(item.justify_self, None | Some(AlignSelf::Stretch));
+            let stretched_width = container_inner_width - item_non_auto_x_margin_sum;
+            let width = item.size.width.unwrap_or_else(|| {
+                if is_stretched {
+                    return stretched_width;
+                }
+                // fit-content: min(max-content, max(min-content, stretched width))
+                let mut measure_width = |available_width: AvailableSpace| {
+                    tree.measure_child_size(
+                        item.node_id,
+                        item.size.maybe_clamp(item.min_size, item.max_size),
+                        parent_size,
+                        Size { width: available_width, height: AvailableSpace::MinContent },
+                        SizingMode::InherentSize,
+                        AbsoluteAxis::Horizontal,
+                        Line::TRUE,
+                    )
+                };
+                let max_content_width = measure_width(AvailableSpace::MaxContent);
+                if max_content_width <= stretched_width {
+                    max_content_width
+                } else {
+                    f32_max(measure_width(AvailableSpace::MinContent), stretched_width)
+                }
+            });
+            let known_dimensions =
+                Size { width: Some(width), height: item.size.height }.maybe_clamp(item.min_size, item.max_size);
 
             let item_layout = tree.perform_child_layout(
                 item.node_id,
@@ -381,9 +445,9 @@ fn perform_final_layout_on_in_flow_children(
             // Note: Vertical auto-margins for relatively positioned block items simply resolve to 0.
             // See: https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
             let free_x_space = f32_max(0.0, container_inner_width - final_size.width - item_non_auto_x_margin_sum);
+            let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
             let x_axis_auto_margin_size = {
-                let auto_margin_count = item_margin.left.is_none() as u8 + item_margin.right.is_none() as u8;
-                if auto_margin_count == 2 && item.size.width.is_none() {
+                if auto_margin_count == 2 && item.size.width.is_none() && is_stretched {
                     0.0
                 } else if auto_margin_count > 0 {
                     free_x_space / auto_margin_count as f32
@@ -391,6 +455,14 @@ fn perform_final_layout_on_in_flow_children(
                     0.0
                 }
             };
+
+            // Align the item within any remaining space in the inline axis. Auto margins take precedence.
+            let x_alignment_offset = match item.justify_self {
+                _ if auto_margin_count > 0 => 0.0,
+                Some(AlignSelf::Center) => free_x_space / 2.0,
+                Some(AlignSelf::End | AlignSelf::FlexEnd) => free_x_space,
+                _ => 0.0,
+            };
             let resolved_margin = Rect {
                 left: item_margin.left.unwrap_or(x_axis_auto_margin_size),
                 right: item_margin.right.unwrap_or(x_axis_auto_margin_size),
@@ -419,7 +491,7 @@ fn perform_final_layout_on_in_flow_children(
                 y: committed_y_offset + active_collapsible_margin_set.resolve(),
             };
             let location = Point {
-                x: resolved_content_box_inset.left + inset_offset.x + resolved_margin.left,
+                x: resolved_content_box_inset.left + inset_offset.x + resolved_margin.left + x_alignment_offset,
                 y: committed_y_offset + inset_offset.y + y_margin_offset,
             };
 
@@ -634,15 +706,19 @@ fn perform_absolute_layout_on_absolute_children(
             bottom: margin.bottom.unwrap_or(auto_margin.bottom),
         };
 
+        // The static position is relative to the container's border box, whereas item_offset is relative to the
+        // containing block (the container's padding box)
+        let static_position =
+            Point { x: item.static_position.x - area_offset.x, y: item.static_position.y - area_offset.y };
         let item_offset = Point {
             x: left
                 .map(|left| left + resolved_margin.left)
                 .or(right.map(|right| area_size.width - final_size.width - right - resolved_margin.right))
-                .unwrap_or(resolved_margin.left),
+                .unwrap_or(static_position.x + resolved_margin.left),
             y: top
                 .map(|top| top + resolved_margin.top)
                 .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
-                .unwrap_or(item.static_position.y + resolved_margin.top),
+                .unwrap_or(static_position.y + resolved_margin.top),
         };
 
         // Note: axis intentionally switched here as scrollbars take up space in the opposite axis
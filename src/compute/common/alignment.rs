@@ -6,6 +6,15 @@ use crate::style::AlignContent;
 ///   - For both the Flexbox and CSS Grid algorithms
 /// CSS Grid does not apply gaps as part of alignment, so the gap parameter should
 /// always be set to zero for CSS Grid.
+///
+/// When there is negative free space (the items overflow) the distributed alignment modes fall back to:
+///   - `Stretch` and `SpaceBetween`: `FlexStart` (so the items overflow the end of the container)
+///   - `SpaceAround` and `SpaceEvenly`: `Center` (so the items overflow both sides of the container equally)
+///
+/// `SpaceBetween` also falls back to `FlexStart` when there is only a single item.
+///
+/// The "safe" alignment modes (`SafeEnd`, `SafeFlexEnd` and `SafeCenter`) fall back to `Start` when there is negative
+/// free space, so that the items never overflow the start edge of the container.
 pub(crate) fn compute_alignment_offset(
     free_space: f32,
     num_items: usize,
@@ -33,8 +42,29 @@ pub(crate) fn compute_alignment_offset(
                 }
             }
             AlignContent::Center => free_space / 2.0,
-            AlignContent::Stretch => 0.0,
-            AlignContent::SpaceBetween => 0.0,
+            AlignContent::SafeEnd => free_space.max(0.0),
+            AlignContent::SafeFlexEnd => {
+                if layout_is_flex_reversed {
+                    0.0
+                } else {
+                    free_space.max(0.0)
+                }
+            }
+            AlignContent::SafeCenter => free_space.max(0.0) / 2.0,
+            AlignContent::Stretch => {
+                if layout_is_flex_reversed {
+                    free_space
+                } else {
+                    0.0
+                }
+            }
+            AlignContent::SpaceBetween => {
+                if layout_is_flex_reversed && (free_space < 0.0 || num_items <= 1) {
+                    free_space
+                } else {
+                    0.0
+                }
+            }
             AlignContent::SpaceAround => {
                 if free_space >= 0.0 {
                     (free_space / num_items as f32) / 2.0
@@ -58,6 +88,9 @@ pub(crate) fn compute_alignment_offset(
             AlignContent::End => 0.0,
             AlignContent::FlexEnd => 0.0,
             AlignContent::Center => 0.0,
+            AlignContent::SafeEnd => 0.0,
+            AlignContent::SafeFlexEnd => 0.0,
+            AlignContent::SafeCenter => 0.0,
             AlignContent::Stretch => 0.0,
             AlignContent::SpaceBetween => free_space / (num_items - 1) as f32,
             AlignContent::SpaceAround => free_space / num_items as f32,
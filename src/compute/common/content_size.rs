@@ -1,5 +1,5 @@
 //! Generic CSS content size code that is shared between all CSS algorithms.
-use crate::geometry::{Point, Size};
+use crate::geometry::{Point, Rect, Size};
 use crate::style::Overflow;
 use crate::util::sys::f32_max;
 
@@ -30,3 +30,21 @@ pub(crate) fn compute_content_size_contribution(
         Size::ZERO
     }
 }
+
+#[inline(always)]
+/// Add a scroll container's end padding to the extent of its in-flow content. As per the CSS rules for
+/// [scrollable overflow](https://www.w3.org/TR/css-overflow-3/#scrollable), the end padding of a scroll container is
+/// part of the area that can be scrolled to, so that its content can be scrolled clear of its end edges.
+pub(crate) fn add_end_padding(
+    inflow_content_size: Size<f32>,
+    padding: Rect<f32>,
+    overflow: Point<Overflow>,
+) -> Size<f32> {
+    if !inflow_content_size.has_non_zero_area() {
+        return inflow_content_size;
+    }
+    Size {
+        width: inflow_content_size.width + if overflow.x.is_scroll_container() { padding.right } else { 0.0 },
+        height: inflow_content_size.height + if overflow.y.is_scroll_container() { padding.bottom } else { 0.0 },
+    }
+}
@@ -9,13 +9,13 @@ use crate::style::{FlexDirection, Style};
 use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
 use crate::tree::{Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
-use crate::util::debug::debug_log;
+use crate::util::debug::{debug_log, profile_algorithm};
 use crate::util::sys::{f32_max, new_vec_with_capacity, Vec};
 use crate::util::MaybeMath;
-use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::util::{sanitize_factor, MaybeResolve, ResolveOrZero};
 
 #[cfg(feature = "content_size")]
-use super::common::content_size::compute_content_size_contribution;
+use super::common::content_size::{add_end_padding, compute_content_size_contribution};
 
 /// The intermediate results of a flexbox calculation for a single item
 struct FlexItem {
@@ -124,6 +124,12 @@ struct AlgoConstants {
     max_size: Size<Option<f32>>,
     /// The margin of this section
     margin: Rect<f32>,
+    /// The padding of this section
+    #[cfg(feature = "content_size")]
+    padding: Rect<f32>,
+    /// The overflow style of this section
+    #[cfg(feature = "content_size")]
+    overflow: Point<Overflow>,
     /// The border of this section
     border: Rect<f32>,
     /// The space between the content box and the border box.
@@ -153,11 +159,16 @@ struct AlgoConstants {
 
 /// Computes the layout of [`LayoutPartialTree`] according to the flexbox algorithm
 pub fn compute_flexbox_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    profile_algorithm!(Flexbox);
     let LayoutInput { known_dimensions, parent_size, run_mode, .. } = inputs;
     let style = tree.get_style(node);
 
     // Pull these out earlier to avoid borrowing issues
     let aspect_ratio = style.aspect_ratio;
+    let contain_size = style.contain_size;
+    let padding = style.padding.resolve_or_zero(parent_size.width);
+    let border = style.border.resolve_or_zero(parent_size.width);
+    let padding_border_size = (padding + border).sum_axes();
     let min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
     let max_size = style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
     let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
@@ -171,7 +182,18 @@ pub fn compute_flexbox_layout(tree: &mut impl LayoutPartialTree, node: NodeId, i
         (Some(min), Some(max)) if max <= min => Some(min),
         _ => None,
     });
-    let styled_based_known_dimensions = known_dimensions.or(min_max_definite_size).or(clamped_style_size);
+    // A container with size containment may specify the size of its content box in place of the size of its contents
+    let contain_intrinsic_size = if contain_size {
+        style.contain_intrinsic_size.maybe_add(padding_border_size).maybe_clamp(min_size, max_size)
+    } else {
+        Size::NONE
+    };
+    // The container's border-box can never be smaller than its padding and border
+    let mut styled_based_known_dimensions = known_dimensions
+        .or(min_max_definite_size)
+        .or(clamped_style_size)
+        .or(contain_intrinsic_size)
+        .maybe_max(padding_border_size);
 
     // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
     // is ComputeSize (and thus the container's size is all that we're interested in)
@@ -181,6 +203,14 @@ pub fn compute_flexbox_layout(tree: &mut impl LayoutPartialTree, node: NodeId, i
         }
     }
 
+    // A container with size containment is sized as if it had no children (see `compute_preliminary`). If its size is
+    // not already known then compute it up front so that its children are laid out within that size.
+    if contain_size && run_mode == RunMode::PerformLayout && !styled_based_known_dimensions.both_axis_defined() {
+        let size_inputs =
+            LayoutInput { known_dimensions: styled_based_known_dimensions, run_mode: RunMode::ComputeSize, ..inputs };
+        styled_based_known_dimensions = compute_preliminary(tree, node, size_inputs).size.map(Some);
+    }
+
     debug_log!("FLEX: single-pass");
     compute_preliminary(tree, node, LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs })
 }
@@ -197,8 +227,13 @@ fn compute_preliminary(tree: &mut impl LayoutPartialTree, node: NodeId, inputs:
     // 9.1. Initial Setup
 
     // 1. Generate anonymous flex items as described in §4 Flex Items.
+    // A container with size containment is sized as if it had no items
     debug_log!("generate_anonymous_flex_items");
-    let mut flex_items = generate_anonymous_flex_items(tree, node, &constants);
+    let mut flex_items = if run_mode == RunMode::ComputeSize && tree.get_style(node).contain_size {
+        Vec::new()
+    } else {
+        generate_anonymous_flex_items(tree, node, &constants)
+    };
 
     // 9.2. Line Length Determination
 
@@ -232,8 +267,9 @@ fn compute_preliminary(tree: &mut impl LayoutPartialTree, node: NodeId, inputs:
     // and then re-resolve gaps based on newly determined size
     debug_log!("determine_container_main_size");
     let original_gap = constants.gap;
-    if let Some(inner_main_size) = constants.node_inner_size.main(constants.dir) {
-        let outer_main_size = inner_main_size + constants.content_box_inset.main_axis_sum(constants.dir);
+    if let (Some(outer_main_size), Some(inner_main_size)) =
+        (constants.node_outer_size.main(constants.dir), constants.node_inner_size.main(constants.dir))
+    {
         constants.inner_container_size.set_main(constants.dir, inner_main_size);
         constants.container_size.set_main(constants.dir, outer_main_size);
     } else {
@@ -326,7 +362,12 @@ fn compute_preliminary(tree: &mut impl LayoutPartialTree, node: NodeId, inputs:
 
     // Do a final layout pass and gather the resulting layouts
     debug_log!("final_layout_pass");
-    let inflow_content_size = final_layout_pass(tree, &mut flex_lines, &constants);
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut inflow_content_size = final_layout_pass(tree, &mut flex_lines, &constants);
+    #[cfg(feature = "content_size")]
+    {
+        inflow_content_size = add_end_padding(inflow_content_size, constants.padding, constants.overflow);
+    }
 
     // Before returning we perform absolute layout on all absolutely positioned children
     debug_log!("perform_absolute_layout_on_absolute_children");
@@ -406,7 +447,7 @@ fn compute_constants(
     content_box_inset.bottom += scrollbar_gutter.y;
 
     let node_outer_size = known_dimensions;
-    let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
+    let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes()).maybe_max(Size::ZERO);
     let gap = style.gap.resolve_or_zero(node_inner_size.or(Size::zero()));
 
     let container_size = Size::zero();
@@ -421,6 +462,10 @@ fn compute_constants(
         min_size: style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio),
         max_size: style.max_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio),
         margin,
+        #[cfg(feature = "content_size")]
+        padding,
+        #[cfg(feature = "content_size")]
+        overflow: style.overflow,
         border,
         gap,
         content_box_inset,
@@ -453,18 +498,18 @@ fn generate_anonymous_flex_items(
         .filter(|(_, _, style)| style.display != Display::None)
         .map(|(index, child, child_style)| {
             let aspect_ratio = child_style.aspect_ratio;
+            let size = child_style.size.maybe_resolve(constants.node_inner_size);
+            let (min_size, max_size) = size.maybe_resolve_min_max_with_aspect_ratio(
+                child_style.min_size.maybe_resolve(constants.node_inner_size),
+                child_style.max_size.maybe_resolve(constants.node_inner_size),
+                aspect_ratio,
+            );
             FlexItem {
                 node: child,
                 order: index as u32,
-                size: child_style.size.maybe_resolve(constants.node_inner_size).maybe_apply_aspect_ratio(aspect_ratio),
-                min_size: child_style
-                    .min_size
-                    .maybe_resolve(constants.node_inner_size)
-                    .maybe_apply_aspect_ratio(aspect_ratio),
-                max_size: child_style
-                    .max_size
-                    .maybe_resolve(constants.node_inner_size)
-                    .maybe_apply_aspect_ratio(aspect_ratio),
+                size: size.maybe_apply_aspect_ratio(aspect_ratio),
+                min_size,
+                max_size,
 
                 inset: child_style.inset.zip_size(constants.node_inner_size, |p, s| p.maybe_resolve(s)),
                 margin: child_style.margin.resolve_or_zero(constants.node_inner_size.width),
@@ -474,8 +519,8 @@ fn generate_anonymous_flex_items(
                 align_self: child_style.align_self.unwrap_or(constants.align_items),
                 overflow: child_style.overflow,
                 scrollbar_width: child_style.scrollbar_width,
-                flex_grow: child_style.flex_grow,
-                flex_shrink: child_style.flex_shrink,
+                flex_grow: sanitize_factor(child_style.flex_grow),
+                flex_shrink: sanitize_factor(child_style.flex_shrink),
                 flex_basis: 0.0,
                 inner_flex_basis: 0.0,
                 violation: 0.0,
@@ -515,19 +560,25 @@ fn determine_available_space(
 ) -> Size<AvailableSpace> {
     // Note: min/max/preferred size styles have already been applied to known_dimensions in the `compute` function above
     let width = match known_dimensions.width {
-        Some(node_width) => AvailableSpace::Definite(node_width - constants.content_box_inset.horizontal_axis_sum()),
+        Some(node_width) => {
+            AvailableSpace::Definite(f32_max(node_width - constants.content_box_inset.horizontal_axis_sum(), 0.0))
+        }
         None => outer_available_space
             .width
             .maybe_sub(constants.margin.horizontal_axis_sum())
-            .maybe_sub(constants.content_box_inset.horizontal_axis_sum()),
+            .maybe_sub(constants.content_box_inset.horizontal_axis_sum())
+            .maybe_max(0.0),
     };
 
     let height = match known_dimensions.height {
-        Some(node_height) => AvailableSpace::Definite(node_height - constants.content_box_inset.vertical_axis_sum()),
+        Some(node_height) => {
+            AvailableSpace::Definite(f32_max(node_height - constants.content_box_inset.vertical_axis_sum(), 0.0))
+        }
         None => outer_available_space
             .height
             .maybe_sub(constants.margin.vertical_axis_sum())
-            .maybe_sub(constants.content_box_inset.vertical_axis_sum()),
+            .maybe_sub(constants.content_box_inset.vertical_axis_sum())
+            .maybe_max(0.0),
     };
 
     Size { width, height }
@@ -609,7 +660,18 @@ fn determine_flex_base_size(
             // So B will just work here by using main_size without special handling for aspect_ratio
 
             let flex_basis = child_style.flex_basis.maybe_resolve(constants.node_inner_size.main(dir));
-            let main_size = child.size.main(dir);
+
+            // A flex basis of `content` ignores the item's main size. But B still applies, so we recompute the main size
+            // from the cross size and aspect ratio alone
+            let main_size = match child_style.flex_basis {
+                Dimension::Content => child_style
+                    .size
+                    .maybe_resolve(constants.node_inner_size)
+                    .with_main(dir, None)
+                    .maybe_apply_aspect_ratio(child_style.aspect_ratio)
+                    .main(dir),
+                _ => child.size.main(dir),
+            };
             if let Some(flex_basis) = flex_basis.or(main_size) {
                 break 'flex_basis flex_basis;
             };
@@ -696,7 +758,10 @@ fn determine_flex_base_size(
         let style_min_main_size =
             child.min_size.or(child.overflow.map(Overflow::maybe_into_automatic_min_size).into()).main(dir);
 
-        child.resolved_minimum_main_size = style_min_main_size.unwrap_or({
+        // An item's main size can never be smaller than its padding and border (its content box can't be negative),
+        // even if its min size is explicitly smaller than that
+        let padding_border_main_sum = padding_border_axes_sums.main(dir).unwrap_or(0.0);
+        child.resolved_minimum_main_size = style_min_main_size.map(|min| min.max(padding_border_main_sum)).unwrap_or({
             let min_content_main_size = {
                 let child_available_space = Size::MIN_CONTENT.with_cross(dir, cross_axis_available_space);
 
@@ -748,7 +813,17 @@ fn collect_flex_lines<'a>(
         lines.push(FlexLine { items: flex_items.as_mut_slice(), cross_size: 0.0, offset_cross: 0.0 });
         lines
     } else {
-        match available_space.main(constants.dir) {
+        // If the container's main size is indefinite then its items are wrapped at its max main size (if it has one)
+        let inner_max_main_size = constants
+            .max_size
+            .main(constants.dir)
+            .map(|max| f32_max(max - constants.content_box_inset.main_axis_sum(constants.dir), 0.0));
+        let main_axis_available_space = match available_space.main(constants.dir) {
+            AvailableSpace::MinContent => AvailableSpace::MinContent,
+            available_space => available_space.maybe_min(inner_max_main_size),
+        };
+
+        match main_axis_available_space {
             // If we're sizing under a max-content constraint then the flex items will never wrap
             // (at least for now - future extensions to the CSS spec may add provisions for forced wrap points)
             AvailableSpace::MaxContent => {
@@ -934,13 +1009,14 @@ fn determine_container_main_size(
                                 //
                                 // Ultimately, this was not found by reading the spec, but by trial and error fixing tests to align with Webkit/Firefox output.
                                 // (see the `flex_basis_unconstraint_row` and `flex_basis_uncontraint_column` generated tests which demonstrate this)
+                                let item_padding_border_sum = (item.padding + item.border).main_axis_sum(constants.dir);
                                 if constants.is_row {
-                                    content_main_size.maybe_clamp(style_min, style_max).max(main_content_box_inset)
+                                    content_main_size.maybe_clamp(style_min, style_max).max(item_padding_border_sum)
                                 } else {
                                     content_main_size
                                         .max(item.flex_basis)
                                         .maybe_clamp(style_min, style_max)
-                                        .max(main_content_box_inset)
+                                        .max(item_padding_border_sum)
                                 }
                             }
                         };
@@ -1001,7 +1077,13 @@ fn determine_container_main_size(
                     main_size = f32_max(main_size, item_main_size_sum + gap_sum)
                 }
 
-                main_size + main_content_box_inset
+                // Items are only wrapped onto multiple lines here if they overflow the container's max main size
+                // (see `collect_flex_lines`), in which case the container fills its max main size
+                let size = main_size + main_content_box_inset;
+                match constants.max_size.main(constants.dir) {
+                    Some(max_main_size) if lines.len() > 1 => f32_max(size, max_main_size),
+                    _ => size,
+                }
             }
         }
     });
@@ -1075,7 +1157,12 @@ fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants, orig
     let initial_free_space = constants.node_inner_size.main(constants.dir).maybe_sub(used_space).unwrap_or(0.0);
 
     // 4. Loop
+    //
+    // Each pass through the loop freezes at least one item, so it should run at most once per item. But comparisons
+    // against NaN are always false, so we also cap the number of passes to ensure that bad input can never hang layout.
 
+    let max_iterations = line.items.len();
+    let mut iteration_count = 0;
     loop {
         // a. Check for flexible items. If all the flex items on the line are frozen,
         //    free space has been distributed; exit this loop.
@@ -1188,8 +1275,15 @@ fn resolve_flexible_lengths(line: &mut FlexLine, constants: &AlgoConstants, orig
         //    - Negative
         //        Freeze all the items with max violations.
 
+        iteration_count += 1;
+        let force_freeze = iteration_count >= max_iterations;
+        if force_freeze {
+            debug_log!("Flexible length resolution did not converge. Freezing all items.");
+        }
+
         for child in &mut unfrozen {
             match total_violation {
+                _ if force_freeze => child.frozen = true,
                 v if v > 0.0 => child.frozen = child.violation > 0.0,
                 v if v < 0.0 => child.frozen = child.violation < 0.0,
                 _ => child.frozen = true,
@@ -1396,6 +1490,17 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
                 })
                 .fold(0.0, |acc, x| acc.max(x));
         }
+
+        // If the flex container is single-line, then clamp the line's cross-size to be within the container's computed
+        // min and max cross sizes (converted from border-box sizes to content-box sizes)
+        if !constants.is_wrap {
+            let cross_axis_padding_border = constants.content_box_inset.cross_axis_sum(constants.dir);
+            let cross_min_size = constants.min_size.cross(constants.dir).maybe_sub(cross_axis_padding_border);
+            let cross_max_size = constants.max_size.cross(constants.dir).maybe_sub(cross_axis_padding_border);
+            for line in flex_lines.iter_mut() {
+                line.cross_size = line.cross_size.maybe_clamp(cross_min_size, cross_max_size);
+            }
+        }
     }
 }
 
@@ -1449,22 +1554,30 @@ fn determine_used_cross_size(tree: &impl LayoutPartialTree, flex_lines: &mut [Fl
 
         for child in line.items.iter_mut() {
             let child_style = tree.get_style(child.node);
+            // Note: NaN lengths are treated as auto
+            let cross_size_is_auto = match child_style.size.cross(constants.dir) {
+                Dimension::Auto | Dimension::Content => true,
+                Dimension::Length(length) => length.is_nan(),
+                Dimension::Percent(_) => false,
+            };
             child.target_size.set_cross(
                 constants.dir,
                 if child.align_self == AlignSelf::Stretch
                     && !child.margin_is_auto.cross_start(constants.dir)
                     && !child.margin_is_auto.cross_end(constants.dir)
-                    && child_style.size.cross(constants.dir) == Dimension::Auto
+                    && cross_size_is_auto
                 {
                     // For some reason this particular usage of max_width is an exception to the rule that max_width's transfer
                     // using the aspect_ratio (if set). Both Chrome and Firefox agree on this. And reading the spec, it seems like
                     // a reasonable interpretation. Although it seems to me that the spec *should* apply aspect_ratio here.
                     let max_size_ignoring_aspect_ratio = child_style.max_size.maybe_resolve(constants.node_inner_size);
 
-                    (line_cross_size - child.margin.cross_axis_sum(constants.dir)).maybe_clamp(
-                        child.min_size.cross(constants.dir),
-                        max_size_ignoring_aspect_ratio.cross(constants.dir),
-                    )
+                    (line_cross_size - child.margin.cross_axis_sum(constants.dir))
+                        .maybe_clamp(
+                            child.min_size.cross(constants.dir),
+                            max_size_ignoring_aspect_ratio.cross(constants.dir),
+                        )
+                        .max(0.0)
                 } else {
                     child.hypothetical_inner_size.cross(constants.dir)
                 },
@@ -1892,8 +2005,12 @@ fn perform_absolute_layout_on_absolute_children(
     node: NodeId,
     constants: &AlgoConstants,
 ) -> Size<f32> {
-    let container_width = constants.container_size.width;
-    let container_height = constants.container_size.height;
+    // Percentage insets, sizes and margins of absolutely positioned children resolve against the containing block,
+    // which is the container's padding box
+    let inset_relative_size =
+        constants.container_size - constants.border.sum_axes() - Size::from(constants.scrollbar_gutter);
+    let container_width = inset_relative_size.width;
+    let container_height = inset_relative_size.height;
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut content_size = Size::ZERO;
@@ -1923,16 +2040,14 @@ fn perform_absolute_layout_on_absolute_children(
         let bottom = child_style.inset.bottom.maybe_resolve(container_height);
 
         // Compute known dimensions from min/max/inherent size styles
-        let style_size =
-            child_style.size.maybe_resolve(constants.container_size).maybe_apply_aspect_ratio(aspect_ratio);
+        let style_size = child_style.size.maybe_resolve(inset_relative_size).maybe_apply_aspect_ratio(aspect_ratio);
         let min_size = child_style
             .min_size
-            .maybe_resolve(constants.container_size)
+            .maybe_resolve(inset_relative_size)
             .maybe_apply_aspect_ratio(aspect_ratio)
             .or(padding_border_sum.map(Some))
             .maybe_max(padding_border_sum);
-        let max_size =
-            child_style.max_size.maybe_resolve(constants.container_size).maybe_apply_aspect_ratio(aspect_ratio);
+        let max_size = child_style.max_size.maybe_resolve(inset_relative_size).maybe_apply_aspect_ratio(aspect_ratio);
         let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
 
         // Fill in width from left/right and reapply aspect ratio if:
@@ -1956,7 +2071,7 @@ fn perform_absolute_layout_on_absolute_children(
         let layout_output = tree.perform_child_layout(
             child,
             known_dimensions,
-            constants.node_inner_size,
+            inset_relative_size.map(Some),
             Size {
                 width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
                 height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
@@ -1970,8 +2085,8 @@ fn perform_absolute_layout_on_absolute_children(
         let non_auto_margin = margin.map(|m| m.unwrap_or(0.0));
 
         let free_space = Size {
-            width: constants.container_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
-            height: constants.container_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
+            width: container_width - final_size.width - non_auto_margin.horizontal_axis_sum(),
+            height: container_height - final_size.height - non_auto_margin.vertical_axis_sum(),
         }
         .f32_max(Size::ZERO);
 
@@ -2015,22 +2130,40 @@ fn perform_absolute_layout_on_absolute_children(
         } else if let Some(end) = end_main {
             constants.container_size.main(constants.dir)
                 - constants.border.main_end(constants.dir)
+                - constants.scrollbar_gutter.main(constants.dir)
                 - final_size.main(constants.dir)
                 - end
                 - resolved_margin.main_end(constants.dir)
         } else {
+            // The safe alignment values fall back to Start if the item would overflow the container
+            let justify_content = match constants.justify_content.unwrap_or(JustifyContent::Start) {
+                JustifyContent::SafeEnd | JustifyContent::SafeFlexEnd | JustifyContent::SafeCenter
+                    if constants.container_size.main(constants.dir)
+                        - constants.content_box_inset.main_axis_sum(constants.dir)
+                        - final_size.main(constants.dir)
+                        - resolved_margin.main_axis_sum(constants.dir)
+                        < 0.0 =>
+                {
+                    JustifyContent::Start
+                }
+                justify_content => justify_content,
+            };
+
             // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
             // treat it as if it wasn't set (and thus we default to FlexStart behaviour)
-            match (constants.justify_content.unwrap_or(JustifyContent::Start), constants.is_wrap_reverse) {
+            match (justify_content, constants.is_wrap_reverse) {
                 (JustifyContent::SpaceBetween, _)
                 | (JustifyContent::Start, _)
                 | (JustifyContent::Stretch, false)
                 | (JustifyContent::FlexStart, false)
-                | (JustifyContent::FlexEnd, true) => {
+                | (JustifyContent::FlexEnd, true)
+                | (JustifyContent::SafeFlexEnd, true) => {
                     constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
                 }
                 (JustifyContent::End, _)
+                | (JustifyContent::SafeEnd, _)
                 | (JustifyContent::FlexEnd, false)
+                | (JustifyContent::SafeFlexEnd, false)
                 | (JustifyContent::FlexStart, true)
                 | (JustifyContent::Stretch, true) => {
                     constants.container_size.main(constants.dir)
@@ -2038,7 +2171,10 @@ fn perform_absolute_layout_on_absolute_children(
                         - final_size.main(constants.dir)
                         - resolved_margin.main_end(constants.dir)
                 }
-                (JustifyContent::SpaceEvenly, _) | (JustifyContent::SpaceAround, _) | (JustifyContent::Center, _) => {
+                (JustifyContent::SpaceEvenly, _)
+                | (JustifyContent::SpaceAround, _)
+                | (JustifyContent::Center, _)
+                | (JustifyContent::SafeCenter, _) => {
                     (constants.container_size.main(constants.dir)
                         + constants.content_box_inset.main_start(constants.dir)
                         - constants.content_box_inset.main_end(constants.dir)
@@ -2057,6 +2193,7 @@ fn perform_absolute_layout_on_absolute_children(
         } else if let Some(end) = end_cross {
             constants.container_size.cross(constants.dir)
                 - constants.border.cross_end(constants.dir)
+                - constants.scrollbar_gutter.cross(constants.dir)
                 - final_size.cross(constants.dir)
                 - end
                 - resolved_margin.cross_end(constants.dir)
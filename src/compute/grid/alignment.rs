@@ -18,38 +18,59 @@ pub(super) fn align_tracks(
     padding: Line<f32>,
     border: Line<f32>,
     tracks: &mut [GridTrack],
+    gutters: &mut [GridTrack],
     track_alignment_style: AlignContent,
 ) {
-    let used_size: f32 = tracks.iter().map(|track| track.base_size).sum();
+    let used_size: f32 = tracks.iter().map(|track| track.base_size).sum::<f32>()
+        + gutters.iter().map(|gutter| gutter.base_size).sum::<f32>();
     let free_space = grid_container_content_box_size - used_size;
     let origin = padding.start + border.start;
 
-    // Count the number of non-collapsed tracks (not counting gutters)
-    let num_tracks = tracks.iter().skip(1).step_by(2).filter(|track| !track.is_collapsed).count();
+    // Count the number of non-collapsed tracks
+    let num_tracks = tracks.iter().filter(|track| !track.is_collapsed).count();
 
     // Grid layout treats gaps as full tracks rather than applying them at alignment so we
     // simply pass zero here. Grid layout is never reversed.
     let gap = 0.0;
     let layout_is_reversed = false;
 
-    // Compute offsets
+    // Compute offsets. Each track is preceded by the gutter (grid line) with the same index.
     let mut total_offset = origin;
-    tracks.iter_mut().enumerate().for_each(|(i, track)| {
-        // Odd tracks are gutters (but slices are zero-indexed, so odd tracks have even indicies)
-        let is_gutter = i % 2 == 0;
+    tracks.iter_mut().zip(gutters.iter_mut()).enumerate().for_each(|(i, (track, gutter))| {
+        gutter.offset = total_offset;
+        total_offset += gutter.base_size;
 
-        // The first non-gutter track is index 1
-        let is_first = i == 1;
-
-        let offset = if is_gutter {
-            0.0
-        } else {
-            compute_alignment_offset(free_space, num_tracks, gap, track_alignment_style, layout_is_reversed, is_first)
-        };
+        let is_first = i == 0;
+        let offset =
+            compute_alignment_offset(free_space, num_tracks, gap, track_alignment_style, layout_is_reversed, is_first);
 
         track.offset = total_offset + offset;
         total_offset = total_offset + offset + track.base_size;
     });
+
+    // The final grid line follows the last track
+    let last_gutter = gutters.last_mut().unwrap();
+    last_gutter.offset = total_offset;
+}
+
+/// The alignment given by the (non-standard) `align_tracks` or `justify_tracks` property to the items whose grid
+/// areas start in the track with the given index. The last value is repeated for tracks beyond the end of the list.
+///
+/// Each item is aligned on its own, so the distributed alignments use their single-item fallbacks, and the "safe"
+/// alignments (which `AlignSelf` has no equivalent of) are treated as their unsafe counterparts.
+#[cfg(feature = "grid_track_alignment")]
+pub(super) fn track_item_alignment(track_alignments: &[AlignContent], track_index: u16) -> Option<AlignItems> {
+    let alignment = track_alignments.get(track_index as usize).or(track_alignments.last())?;
+    Some(match alignment {
+        AlignContent::Start | AlignContent::SpaceBetween => AlignItems::Start,
+        AlignContent::End | AlignContent::SafeEnd => AlignItems::End,
+        AlignContent::FlexStart => AlignItems::FlexStart,
+        AlignContent::FlexEnd | AlignContent::SafeFlexEnd => AlignItems::FlexEnd,
+        AlignContent::Center | AlignContent::SafeCenter | AlignContent::SpaceAround | AlignContent::SpaceEvenly => {
+            AlignItems::Center
+        }
+        AlignContent::Stretch => AlignItems::Stretch,
+    })
 }
 
 /// Align and size a grid item into it's final position
@@ -77,14 +98,14 @@ pub(super) fn align_and_position_item(
     let padding = style.padding.map(|p| p.resolve_or_zero(Some(grid_area_size.width)));
     let border = style.border.map(|p| p.resolve_or_zero(Some(grid_area_size.width)));
     let padding_border_size = (padding + border).sum_axes();
-    let inherent_size = style.size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-    let min_size = style
-        .min_size
-        .maybe_resolve(grid_area_size)
-        .or(padding_border_size.map(Some))
-        .maybe_max(padding_border_size)
-        .maybe_apply_aspect_ratio(aspect_ratio);
-    let max_size = style.max_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
+    let raw_size = style.size.maybe_resolve(grid_area_size);
+    let (min_size, max_size) = raw_size.maybe_resolve_min_max_with_aspect_ratio(
+        style.min_size.maybe_resolve(grid_area_size),
+        style.max_size.maybe_resolve(grid_area_size),
+        aspect_ratio,
+    );
+    let min_size = min_size.or(padding_border_size.map(Some)).maybe_max(padding_border_size);
+    let inherent_size = raw_size.maybe_apply_aspect_ratio(aspect_ratio);
 
     // Resolve default alignment styles if they are set on neither the parent or the node itself
     // Note: if the child has a preferred aspect ratio but neither width or height are set, then the width is stretched
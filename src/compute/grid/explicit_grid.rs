@@ -4,7 +4,7 @@ use super::types::{GridTrack, TrackCounts};
 use crate::geometry::{AbsoluteAxis, Size};
 use crate::style::{GridTrackRepetition, LengthPercentage, NonRepeatedTrackSizingFunction, Style, TrackSizingFunction};
 use crate::style_helpers::TaffyAuto;
-use crate::util::sys::{GridTrackVec, Vec};
+use crate::util::sys::{f32_max, GridLineBuffer, GridTrackBuffer, GridTrackVec};
 use crate::util::MaybeMath;
 use crate::util::ResolveOrZero;
 
@@ -36,17 +36,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     }
 
     // Compute that number of track generated by single track definition and repetitions with a fixed repetition count
-    let non_auto_repeating_track_count = template
-        .iter()
-        .map(|track_def| {
-            use GridTrackRepetition::{AutoFill, AutoFit, Count};
-            match track_def {
-                TrackSizingFunction::Single(_) => 1,
-                TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
-                TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0,
-            }
-        })
-        .sum::<u16>();
+    let non_auto_repeating_track_count = compute_non_auto_repeating_track_count(template);
 
     let auto_repetition_count = template.iter().filter(|track_def| track_def.is_auto_repetition()).count() as u16;
     let all_track_defs_have_fixed_component = template.iter().all(|track_def| match track_def {
@@ -92,15 +82,19 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
     // Otherwise, if the grid container has a definite min size in the relevant axis:
     //   - then the number of repetitions is the smallest possible positive integer that fulfills that minimum requirement
     // Otherwise, the specified track list repeats only once.
+    //
+    // The size or max size is floored by the min size (if any) as the container can never be smaller than its min size.
+    // So if the min size is larger than the max size then the repetitions fill the min size.
     let style_size = preferred_size.get_abs(axis);
     let style_min_size = style.min_size.get_abs(axis).into_option();
     let style_max_size = style.max_size.get_abs(axis).into_option();
 
-    let outer_container_size = style_size.maybe_min(style_max_size).or(style_max_size).or(style_min_size);
+    let outer_container_size =
+        style_size.maybe_min(style_max_size).or(style_max_size).maybe_max(style_min_size).or(style_min_size);
     let inner_container_size = outer_container_size.map(|size| {
         let padding_sum = style.padding.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
         let border_sum = style.border.resolve_or_zero(outer_container_size).grid_axis_sum(axis);
-        size - padding_sum - border_sum
+        f32_max(size - padding_sum - border_sum, 0.0)
     });
     let size_is_maximum = style_size.is_some() || style_max_size.is_some();
 
@@ -112,10 +106,14 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
 
             /// ...treating each track as its max track sizing function if that is definite or as its minimum track sizing function
             /// otherwise, flooring the max track sizing function by the min track sizing function if both are definite
+            ///
+            /// A template containing an auto-repetition is only valid if every track has a fixed min or max track sizing
+            /// function, and these are always definite here as the container size is known. So the fallback of zero is
+            /// never expected to be used.
             fn track_definite_value(sizing_function: &NonRepeatedTrackSizingFunction, parent_size: Option<f32>) -> f32 {
                 let max_size = sizing_function.max.definite_value(parent_size);
-                let min_size = sizing_function.max.definite_value(parent_size);
-                max_size.map(|max| max.maybe_min(min_size)).or(min_size).unwrap()
+                let min_size = sizing_function.min.definite_value(parent_size);
+                max_size.map(|max| max.maybe_max(min_size)).or(min_size).unwrap_or(0.0)
             }
 
             let non_repeating_track_used_space: f32 = template
@@ -139,10 +137,12 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                 .sum();
             let gap_size = style.gap.get_abs(axis).resolve_or_zero(Some(inner_container_size));
 
-            // Compute the amount of space that a single repetition of the repeated track list takes
+            // Compute the amount of space that a single repetition of the repeated track list takes. Track sizes are floored
+            // at 1px to avoid dividing by zero (and thus generating an infinite number of tracks), as suggested by the spec.
+            // See: https://www.w3.org/TR/css-grid-1/#auto-repeat
             let per_repetition_track_used_space: f32 = repetition_definition
                 .iter()
-                .map(|sizing_function| track_definite_value(sizing_function, parent_size))
+                .map(|sizing_function| f32_max(track_definite_value(sizing_function, parent_size), 1.0))
                 .sum::<f32>();
 
             // We special case the first repetition here because the number of gaps in the first repetition
@@ -169,42 +169,64 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                 //
                 // In all cases we add the additional repetition that was already accounted for in the special-case computation above
                 if size_is_maximum {
-                    (num_repetition_that_fit.floor() as u16) + 1
+                    (num_repetition_that_fit.floor() as u16).saturating_add(1)
                 } else {
-                    (num_repetition_that_fit.ceil() as u16) + 1
+                    (num_repetition_that_fit.ceil() as u16).saturating_add(1)
                 }
             }
         }
     };
 
-    non_auto_repeating_track_count + (repetition_track_count * num_repetitions)
+    non_auto_repeating_track_count.saturating_add(repetition_track_count.saturating_mul(num_repetitions))
+}
+
+/// Compute the number of tracks generated by the single track definitions and the fixed-count
+/// repetitions (e.g. `repeat(3, ...)`) in a track template. Auto-repetitions contribute zero tracks.
+fn compute_non_auto_repeating_track_count(template: &[TrackSizingFunction]) -> u16 {
+    template
+        .iter()
+        .map(|track_def| {
+            use GridTrackRepetition::{AutoFill, AutoFit, Count};
+            match track_def {
+                TrackSizingFunction::Single(_) => 1,
+                TrackSizingFunction::Repeat(Count(count), tracks) => count * tracks.len() as u16,
+                TrackSizingFunction::Repeat(AutoFit | AutoFill, _) => 0,
+            }
+        })
+        .sum::<u16>()
 }
 
 /// Resolve the track sizing functions of explicit tracks, automatically created tracks, and gutters
 /// given a set of track counts and all of the relevant styles
 pub(super) fn initialize_grid_tracks(
-    tracks: &mut Vec<GridTrack>,
+    tracks: &mut GridTrackBuffer<GridTrack>,
+    gutters: &mut GridLineBuffer<GridTrack>,
     counts: TrackCounts,
     track_template: &GridTrackVec<TrackSizingFunction>,
     auto_tracks: &[NonRepeatedTrackSizingFunction],
     gap: LengthPercentage,
     track_has_items: impl Fn(usize) -> bool,
 ) {
-    // Clear vector (in case this is a re-layout), reserve space for all tracks ahead of time to reduce allocations,
-    // and push the initial gutter
+    // Clear vectors (in case this is a re-layout), reserve space for all tracks and gutters ahead of time to
+    // reduce allocations, and push the initial gutter
     tracks.clear();
-    tracks.reserve((counts.len() * 2) + 1);
-    tracks.push(GridTrack::gutter(gap));
+    gutters.clear();
+    #[cfg(not(feature = "fixed-capacity-grids"))]
+    {
+        tracks.reserve(counts.len());
+        gutters.reserve(counts.len() + 1);
+    }
+    gutters.push(GridTrack::gutter(gap));
 
     // Create negative implicit tracks
     if counts.negative_implicit > 0 {
         if auto_tracks.is_empty() {
             let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
-            create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
+            create_implicit_tracks(tracks, gutters, counts.negative_implicit, iter, gap)
         } else {
             let offset = auto_tracks.len() - (counts.negative_implicit as usize % auto_tracks.len());
             let iter = auto_tracks.iter().copied().cycle().skip(offset);
-            create_implicit_tracks(tracks, counts.negative_implicit, iter, gap)
+            create_implicit_tracks(tracks, gutters, counts.negative_implicit, iter, gap)
         }
     }
 
@@ -222,7 +244,7 @@ pub(super) fn initialize_grid_tracks(
                         sizing_function.min_sizing_function(),
                         sizing_function.max_sizing_function(),
                     ));
-                    tracks.push(GridTrack::gutter(gap));
+                    gutters.push(GridTrack::gutter(gap));
                     current_track_index += 1;
                 }
                 TrackSizingFunction::Repeat(Count(count), repeated_tracks) => {
@@ -232,12 +254,13 @@ pub(super) fn initialize_grid_tracks(
                             sizing_function.min_sizing_function(),
                             sizing_function.max_sizing_function(),
                         ));
-                        tracks.push(GridTrack::gutter(gap));
+                        gutters.push(GridTrack::gutter(gap));
                         current_track_index += 1;
                     });
                 }
                 TrackSizingFunction::Repeat(repetition_kind @ (AutoFit | AutoFill), repeated_tracks) => {
-                    let auto_repeated_track_count = (counts.explicit - (track_template.len() as u16 - 1)) as usize;
+                    let auto_repeated_track_count =
+                        (counts.explicit - compute_non_auto_repeating_track_count(track_template)) as usize;
                     let iter = repeated_tracks.iter().copied().cycle();
                     for track_def in iter.take(auto_repeated_track_count) {
                         let mut track =
@@ -251,7 +274,7 @@ pub(super) fn initialize_grid_tracks(
                         }
 
                         tracks.push(track);
-                        tracks.push(gutter);
+                        gutters.push(gutter);
 
                         current_track_index += 1;
                     }
@@ -263,20 +286,33 @@ pub(super) fn initialize_grid_tracks(
     // Create positive implicit tracks
     if auto_tracks.is_empty() {
         let iter = core::iter::repeat(NonRepeatedTrackSizingFunction::AUTO);
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, gutters, counts.positive_implicit, iter, gap)
     } else {
         let iter = auto_tracks.iter().copied().cycle();
-        create_implicit_tracks(tracks, counts.positive_implicit, iter, gap)
+        create_implicit_tracks(tracks, gutters, counts.positive_implicit, iter, gap)
+    }
+
+    // The gutters on either side of a collapsed track collapse so that they coincide. So a single gutter remains
+    // between two non-collapsed tracks however many collapsed tracks lie between them, and no gutter remains between
+    // the last non-collapsed track and any collapsed tracks that follow it.
+    // See: https://www.w3.org/TR/css-grid-1/#gutters
+    let mut has_following_track = false;
+    for (track, gutter) in tracks.iter().zip(gutters.iter_mut().skip(1)).rev() {
+        if !has_following_track {
+            gutter.collapse();
+        }
+        has_following_track |= !track.is_collapsed;
     }
 
     // Mark first and last grid lines as collapsed
-    tracks.first_mut().unwrap().collapse();
-    tracks.last_mut().unwrap().collapse();
+    gutters.first_mut().unwrap().collapse();
+    gutters.last_mut().unwrap().collapse();
 }
 
 /// Utility function for repeating logic of creating implicit tracks
 fn create_implicit_tracks(
-    tracks: &mut Vec<GridTrack>,
+    tracks: &mut GridTrackBuffer<GridTrack>,
+    gutters: &mut GridLineBuffer<GridTrack>,
     count: u16,
     mut auto_tracks_iter: impl Iterator<Item = NonRepeatedTrackSizingFunction>,
     gap: LengthPercentage,
@@ -284,7 +320,7 @@ fn create_implicit_tracks(
     for _ in 0..count {
         let track_def = auto_tracks_iter.next().unwrap();
         tracks.push(GridTrack::new(track_def.min_sizing_function(), track_def.max_sizing_function()));
-        tracks.push(GridTrack::gutter(gap));
+        gutters.push(GridTrack::gutter(gap));
     }
 }
 
@@ -292,11 +328,11 @@ fn create_implicit_tracks(
 mod test {
     use super::compute_explicit_grid_size_in_axis;
     use super::initialize_grid_tracks;
-    use crate::compute::grid::types::GridTrackKind;
     use crate::compute::grid::types::TrackCounts;
     use crate::compute::grid::util::*;
     use crate::geometry::AbsoluteAxis;
     use crate::prelude::*;
+    use crate::util::sys::{GridLineBuffer, GridTrackBuffer};
 
     #[test]
     fn explicit_grid_sizing_no_repeats() {
@@ -376,6 +412,79 @@ mod test {
         assert_eq!(height, 5);
     }
 
+    #[test]
+    fn explicit_grid_sizing_auto_fill_max_size() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            max_size: Size { width: length(140.0), height: length(90.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![length(40.0)])],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 3);
+        assert_eq!(height, 4);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_min_and_max_size() {
+        use GridTrackRepetition::AutoFill;
+        // The max size determines the number of repetitions, as any number that fits into it fulfills the min size
+        let grid_style = Style {
+            display: Display::Grid,
+            min_size: Size { width: length(50.0), height: length(30.0) },
+            max_size: Size { width: length(140.0), height: length(90.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![length(40.0)])],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 3);
+        assert_eq!(height, 4);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_min_size_larger_than_max_size() {
+        use GridTrackRepetition::AutoFill;
+        // The min size overrides the max size, so the repetitions fill the min size
+        let grid_style = Style {
+            display: Display::Grid,
+            min_size: Size { width: length(200.0), height: length(120.0) },
+            max_size: Size { width: length(140.0), height: length(90.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![length(40.0)])],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 5);
+        assert_eq!(height, 6);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_min_size_larger_than_size() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: length(100.0), height: length(50.0) },
+            min_size: Size { width: length(140.0), height: length(90.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![length(40.0)])],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(20.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 3);
+        assert_eq!(height, 4);
+    }
+
     #[test]
     fn explicit_grid_sizing_auto_fill_multiple_repeated_tracks() {
         use GridTrackRepetition::AutoFill;
@@ -464,6 +573,153 @@ mod test {
         assert_eq!(height, 4); // 20px vertical padding
     }
 
+    #[test]
+    fn explicit_grid_sizing_integer_repeat() {
+        let grid_style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![length(10.0), repeat(3, vec![length(20.0), fr(1.0)])],
+            grid_template_rows: vec![repeat(2, vec![auto()])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 7);
+        assert_eq!(height, 2);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_integer_repeat_and_auto_fill() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: length(120.0), height: length(120.0) },
+            grid_template_columns: vec![
+                repeat(2, vec![length(10.0), length(20.0)]),
+                repeat(AutoFill, vec![length(30.0)]),
+            ],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(40.0)]), repeat(2, vec![length(20.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 6); // 60px of fixed-count tracks + 2 auto-filled 30px tracks
+        assert_eq!(height, 4); // 2 auto-filled 40px tracks + 40px of fixed-count tracks
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_percentage_indefinite_size() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![repeat(AutoFill, vec![percent(0.5)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        assert_eq!(width, 1);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_fixed_min_intrinsic_max() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: length(100.0), height: length(100.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![minmax(length(20.0), auto())])],
+            grid_template_rows: vec![repeat(AutoFill, vec![minmax(percent(0.3), max_content())])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 5);
+        assert_eq!(height, 3);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_max_floored_by_min() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: length(100.0), height: length(100.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![minmax(length(30.0), length(20.0))])],
+            grid_template_rows: vec![repeat(AutoFill, vec![minmax(length(20.0), length(30.0))])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        assert_eq!(width, 3);
+        assert_eq!(height, 3);
+    }
+
+    #[test]
+    fn explicit_grid_sizing_auto_fill_zero_size_tracks() {
+        use GridTrackRepetition::AutoFill;
+        let grid_style = Style {
+            display: Display::Grid,
+            size: Size { width: length(100.0), height: length(100.0) },
+            grid_template_columns: vec![repeat(AutoFill, vec![percent(0.0)])],
+            grid_template_rows: vec![repeat(AutoFill, vec![length(0.0), length(0.0)])],
+            ..Default::default()
+        };
+        let preferred_size = grid_style.size.map(|s| s.into_option());
+        let width = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Horizontal);
+        let height = compute_explicit_grid_size_in_axis(&grid_style, preferred_size, AbsoluteAxis::Vertical);
+        // Track sizes are floored at 1px for the purpose of computing the number of repetitions
+        assert_eq!(width, 100);
+        assert_eq!(height, 100);
+    }
+
+    #[test]
+    fn test_initialize_grid_tracks_integer_repeat_and_auto_fill() {
+        use GridTrackRepetition::AutoFill;
+        let px10 = LengthPercentage::Length(10.0);
+        let px20 = LengthPercentage::Length(20.0);
+        let px30 = LengthPercentage::Length(30.0);
+
+        // Setup test
+        let track_template =
+            vec![length(10.0), repeat(2, vec![length(20.0), fr(1.0)]), repeat(AutoFill, vec![length(30.0)])];
+        let track_counts = TrackCounts { negative_implicit: 0, explicit: 8, positive_implicit: 0 };
+
+        // Call function
+        let mut tracks = GridTrackBuffer::new();
+        let mut gutters = GridLineBuffer::new();
+        initialize_grid_tracks(
+            &mut tracks,
+            &mut gutters,
+            track_counts,
+            &track_template,
+            &[],
+            LengthPercentage::Length(0.0),
+            |_| false,
+        );
+
+        // Assertions
+        let expected_tracks = vec![
+            (MinTrackSizingFunction::Fixed(px10), MaxTrackSizingFunction::Fixed(px10)),
+            // Integer repetition
+            (MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Fraction(1.0)),
+            (MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Fraction(1.0)),
+            // Auto-repetition fills the remaining explicit track count
+            (MinTrackSizingFunction::Fixed(px30), MaxTrackSizingFunction::Fixed(px30)),
+            (MinTrackSizingFunction::Fixed(px30), MaxTrackSizingFunction::Fixed(px30)),
+            (MinTrackSizingFunction::Fixed(px30), MaxTrackSizingFunction::Fixed(px30)),
+        ];
+
+        assert_eq!(tracks.len(), expected_tracks.len(), "Number of tracks doesn't match");
+        for (idx, (actual, (min, max))) in tracks.into_iter().zip(expected_tracks).enumerate() {
+            assert_eq!(actual.min_track_sizing_function, min, "Track {idx} (0-based index)");
+            assert_eq!(actual.max_track_sizing_function, max, "Track {idx} (0-based index)");
+        }
+        assert_eq!(gutters.len(), 9, "Number of gutters doesn't match");
+    }
+
     #[test]
     fn test_initialize_grid_tracks() {
         let px0 = LengthPercentage::Length(0.0);
@@ -478,42 +734,46 @@ mod test {
         let gap = px20;
 
         // Call function
-        let mut tracks = Vec::new();
-        initialize_grid_tracks(&mut tracks, track_counts, &track_template, &auto_tracks, gap, |_| false);
+        let mut tracks = GridTrackBuffer::new();
+        let mut gutters = GridLineBuffer::new();
+        initialize_grid_tracks(&mut tracks, &mut gutters, track_counts, &track_template, &auto_tracks, gap, |_| false);
 
         // Assertions
-        let expected = vec![
-            // Gutter
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px0), MaxTrackSizingFunction::Fixed(px0)),
+        let expected_tracks = vec![
             // Negative implict tracks
-            (GridTrackKind::Track, MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
             // Explicit tracks
-            (GridTrackKind::Track, MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fraction(2.0)), // Note: separate min-max functions
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Fraction(1.0)), // Note: min sizing function of flex sizing functions is auto
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fraction(2.0)), // Note: separate min-max functions
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Fraction(1.0)), // Note: min sizing function of flex sizing functions is auto
             // Positive implict tracks
-            (GridTrackKind::Track, MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px20), MaxTrackSizingFunction::Fixed(px20)),
-            (GridTrackKind::Track, MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
-            (GridTrackKind::Gutter, MinTrackSizingFunction::Fixed(px0), MaxTrackSizingFunction::Fixed(px0)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
+            (MinTrackSizingFunction::Fixed(px100), MaxTrackSizingFunction::Fixed(px100)),
+            (MinTrackSizingFunction::Auto, MaxTrackSizingFunction::Auto),
         ];
 
-        assert_eq!(tracks.len(), expected.len(), "Number of tracks doesn't match");
-
-        for (idx, (actual, (kind, min, max))) in tracks.into_iter().zip(expected).enumerate() {
-            assert_eq!(actual.kind, kind, "Track {idx} (0-based index)");
+        assert_eq!(tracks.len(), expected_tracks.len(), "Number of tracks doesn't match");
+        for (idx, (actual, (min, max))) in tracks.into_iter().zip(expected_tracks).enumerate() {
             assert_eq!(actual.min_track_sizing_function, min, "Track {idx} (0-based index)");
             assert_eq!(actual.max_track_sizing_function, max, "Track {idx} (0-based index)");
         }
+
+        // There is one more gutter (grid line) than there are tracks. The first and last lines are always collapsed.
+        let expected_gutters = [px0, px20, px20, px20, px20, px20, px20, px20, px20, px0];
+        assert_eq!(gutters.len(), expected_gutters.len(), "Number of gutters doesn't match");
+        for (idx, (actual, size)) in gutters.into_iter().zip(expected_gutters).enumerate() {
+            assert_eq!(
+                actual.min_track_sizing_function,
+                MinTrackSizingFunction::Fixed(size),
+                "Gutter {idx} (0-based index)"
+            );
+            assert_eq!(
+                actual.max_track_sizing_function,
+                MaxTrackSizingFunction::Fixed(size),
+                "Gutter {idx} (0-based index)"
+            );
+        }
     }
 }
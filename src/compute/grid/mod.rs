@@ -1,25 +1,34 @@
 //! This module is a partial implementation of the CSS Grid Level 1 specification
 //! <https://www.w3.org/TR/css-grid-1>
+#[cfg(feature = "content_size")]
+use crate::compute::common::content_size::add_end_padding;
+use crate::compute::{compute_hidden_layout, compute_leaf_layout};
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{AlignContent, AlignItems, AlignSelf, AvailableSpace, Display, Overflow, Position};
 use crate::style_helpers::*;
 use crate::tree::{Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
-use crate::util::debug::debug_log;
-use crate::util::sys::{f32_max, GridTrackVec, Vec};
+use crate::util::debug::{debug_log, profile_algorithm};
+#[cfg(feature = "fixed-capacity-grids")]
+use crate::util::sys::MAX_GRID_TRACKS;
+use crate::util::sys::{f32_max, GridLineBuffer, GridTrackBuffer, Vec};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
+#[cfg(feature = "grid_track_alignment")]
+use alignment::track_item_alignment;
 use alignment::{align_and_position_item, align_tracks};
 use explicit_grid::{compute_explicit_grid_size_in_axis, initialize_grid_tracks};
 use implicit_grid::compute_grid_size_estimate;
 use placement::place_grid_items;
 use track_sizing::{
-    determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
+    determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, sum_gutter_sizes,
+    track_sizing_algorithm,
 };
 use types::{CellOccupancyMatrix, GridTrack};
 
-pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine};
+pub(crate) use types::GridCoordinate;
+pub use types::{GridLine, OriginZeroLine, TrackCounts};
 
 mod alignment;
 mod explicit_grid;
@@ -29,14 +38,56 @@ mod track_sizing;
 mod types;
 mod util;
 
+/// The error returned by [`try_compute_grid_layout`] when a grid container needs more tracks (rows or columns) in an
+/// axis than can be stored. This can only happen when the `fixed-capacity-grids` feature is enabled, in which case the
+/// limit is `MAX_GRID_TRACKS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridTrackLimitExceeded {
+    /// The grid container that could not be laid out
+    pub node: NodeId,
+    /// The number of tracks that the grid container needed in its largest axis
+    pub track_count: usize,
+}
+
 /// Grid layout algorithm
 /// This consists of a few phases:
 ///   - Resolving the explicit grid
 ///   - Placing items (which also resolves the implicit grid)
 ///   - Track (row/column) sizing
 ///   - Alignment & Final item placement
+///
+/// If the grid needs more tracks than can be stored (see [`GridTrackLimitExceeded`]) then the container is sized as if
+/// it were empty and its children are hidden. Use [`try_compute_grid_layout`] to detect this case.
 pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
-    let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+    match try_compute_grid_layout(tree, node, inputs) {
+        Ok(output) => output,
+        Err(_) => {
+            if inputs.run_mode == RunMode::PerformLayout {
+                compute_hidden_layout(tree, node);
+            }
+            let style = tree.get_style(node).clone();
+            compute_leaf_layout(inputs, &style, |_, _| Size::ZERO)
+        }
+    }
+}
+
+/// Grid layout algorithm which returns an error rather than laying out the grid container as if it were empty if the
+/// grid needs more tracks than can be stored (see [`GridTrackLimitExceeded`])
+pub fn try_compute_grid_layout(
+    tree: &mut impl LayoutPartialTree,
+    node: NodeId,
+    inputs: LayoutInput,
+) -> Result<LayoutOutput, GridTrackLimitExceeded> {
+    profile_algorithm!(Grid);
+    let LayoutInput { mut known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
+
+    // A container with size containment is sized as if it had no grid items (see below). If its size is not already
+    // known then compute it up front so that its grid items are laid out within that size.
+    let contain_size = tree.get_style(node).contain_size;
+    if contain_size && run_mode == RunMode::PerformLayout && !known_dimensions.both_axis_defined() {
+        let size_inputs = LayoutInput { run_mode: RunMode::ComputeSize, ..inputs };
+        known_dimensions = try_compute_grid_layout(tree, node, size_inputs)?.size.map(Some);
+    }
 
     let get_child_styles_iter = |node| tree.child_ids(node).map(|child_node: NodeId| tree.get_style(child_node));
     let style = tree.get_style(node).clone();
@@ -62,12 +113,16 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     // 2. Grid Item Placement
     // Match items (children) to a definite grid position (row start/end and column start/end position)
     let mut items = Vec::with_capacity(tree.child_count(node));
-    let mut cell_occupancy_matrix = CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts);
+    let mut cell_occupancy_matrix =
+        CellOccupancyMatrix::with_track_counts(est_col_counts, est_row_counts, style.grid_auto_flow.primary_axis());
+    // A container with size containment is sized as if it had no grid items
+    let skip_items = contain_size && run_mode == RunMode::ComputeSize;
     let in_flow_children_iter = || {
         tree.child_ids(node)
             .enumerate()
             .map(|(index, child_node)| (index, child_node, tree.get_style(child_node)))
             .filter(|(_, _, style)| style.display != Display::None && style.position != Position::Absolute)
+            .filter(move |_| !skip_items)
     };
     place_grid_items(
         &mut cell_occupancy_matrix,
@@ -82,13 +137,27 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     let final_col_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Horizontal);
     let final_row_counts = *cell_occupancy_matrix.track_counts(AbsoluteAxis::Vertical);
 
+    // Tracks are stored in fixed-capacity arrays when the `fixed-capacity-grids` feature is enabled, so a grid which
+    // needs more tracks than that can't be laid out
+    #[cfg(feature = "fixed-capacity-grids")]
+    {
+        let track_count = final_col_counts.len().max(final_row_counts.len());
+        if track_count > MAX_GRID_TRACKS {
+            debug_log!("Grid track limit exceeded", dbg:track_count);
+            return Err(GridTrackLimitExceeded { node, track_count });
+        }
+    }
+
     // 3. Initialize Tracks
     // Initialize (explicit and implicit) grid tracks (and gutters)
     // This resolves the min and max track sizing functions for all tracks and gutters
-    let mut columns = GridTrackVec::new();
-    let mut rows = GridTrackVec::new();
+    let mut columns = GridTrackBuffer::new();
+    let mut column_gutters = GridLineBuffer::new();
+    let mut rows = GridTrackBuffer::new();
+    let mut row_gutters = GridLineBuffer::new();
     initialize_grid_tracks(
         &mut columns,
+        &mut column_gutters,
         final_col_counts,
         &style.grid_template_columns,
         &style.grid_auto_columns,
@@ -97,6 +166,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     );
     initialize_grid_tracks(
         &mut rows,
+        &mut row_gutters,
         final_row_counts,
         &style.grid_template_rows,
         &style.grid_auto_rows,
@@ -137,16 +207,16 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     let available_grid_space = Size {
         width: constrained_available_space
             .width
-            .map_definite_value(|space| space - content_box_inset.horizontal_axis_sum()),
+            .map_definite_value(|space| f32_max(space - content_box_inset.horizontal_axis_sum(), 0.0)),
         height: constrained_available_space
             .height
-            .map_definite_value(|space| space - content_box_inset.vertical_axis_sum()),
+            .map_definite_value(|space| f32_max(space - content_box_inset.vertical_axis_sum(), 0.0)),
     };
 
     let outer_node_size = known_dimensions.or(size).maybe_clamp(min_size, max_size).maybe_max(padding_border_size);
     let mut inner_node_size = Size {
-        width: outer_node_size.width.map(|space| space - content_box_inset.horizontal_axis_sum()),
-        height: outer_node_size.height.map(|space| space - content_box_inset.vertical_axis_sum()),
+        width: outer_node_size.width.map(|space| f32_max(space - content_box_inset.horizontal_axis_sum(), 0.0)),
+        height: outer_node_size.height.map(|space| f32_max(space - content_box_inset.vertical_axis_sum(), 0.0)),
     };
 
     debug_log!("parent_size", dbg:parent_size);
@@ -160,6 +230,20 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     // each axis, and doing it up-front here means we don't have to keep repeating that calculation
     resolve_item_track_indexes(&mut items, final_col_counts, final_row_counts);
 
+    // Items that don't set their own alignment take the (non-standard) alignment of the track that they start in
+    #[cfg(feature = "grid_track_alignment")]
+    for item in items.iter_mut() {
+        let child_style = tree.get_style(item.node);
+        if child_style.align_self.is_none() {
+            let track_alignment = track_item_alignment(&style.align_tracks, item.row_indexes.start);
+            item.align_self = track_alignment.unwrap_or(item.align_self);
+        }
+        if child_style.justify_self.is_none() {
+            let track_alignment = track_item_alignment(&style.justify_tracks, item.column_indexes.start);
+            item.justify_self = track_alignment.unwrap_or(item.justify_self);
+        }
+    }
+
     // For each item, and in each axis, determine whether the item crosses any flexible (fr) tracks
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut items, &columns, &rows);
@@ -173,16 +257,20 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         AbstractAxis::Inline,
         min_size.get(AbstractAxis::Inline),
         max_size.get(AbstractAxis::Inline),
+        style.grid_align_content(AbstractAxis::Inline),
         style.grid_align_content(AbstractAxis::Block),
         available_grid_space,
         inner_node_size,
         &mut columns,
+        &mut column_gutters,
         &mut rows,
+        &mut row_gutters,
         &mut items,
         |track: &GridTrack, parent_size: Option<f32>| track.max_track_sizing_function.definite_value(parent_size),
         has_baseline_aligned_item,
     );
-    let initial_column_sum = columns.iter().map(|track| track.base_size).sum::<f32>();
+    let initial_column_sum =
+        columns.iter().map(|track| track.base_size).sum::<f32>() + sum_gutter_sizes(&column_gutters);
     inner_node_size.width = inner_node_size.width.or_else(|| initial_column_sum.into());
 
     items.iter_mut().for_each(|item| item.available_space_cache = None);
@@ -193,32 +281,41 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         AbstractAxis::Block,
         min_size.get(AbstractAxis::Block),
         max_size.get(AbstractAxis::Block),
+        style.grid_align_content(AbstractAxis::Block),
         style.grid_align_content(AbstractAxis::Inline),
         available_grid_space,
         inner_node_size,
         &mut rows,
+        &mut row_gutters,
         &mut columns,
+        &mut column_gutters,
         &mut items,
         |track: &GridTrack, _| Some(track.base_size),
         false, // TODO: Support baseline alignment in the vertical axis
     );
-    let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>();
+    let initial_row_sum = rows.iter().map(|track| track.base_size).sum::<f32>() + sum_gutter_sizes(&row_gutters);
     inner_node_size.height = inner_node_size.height.or_else(|| initial_row_sum.into());
 
     debug_log!("initial_column_sum", dbg:initial_column_sum);
     debug_log!("initial_row_sum", dbg:initial_row_sum);
 
     // 6. Compute container size
+    // A container with size containment may specify the size of its content box in place of the size of its tracks
+    let contain_intrinsic_size = if contain_size { style.contain_intrinsic_size } else { Size::NONE };
     let resolved_style_size = known_dimensions.or(preferred_size);
     let container_border_box = Size {
         width: resolved_style_size
             .get(AbstractAxis::Inline)
-            .unwrap_or_else(|| initial_column_sum + content_box_inset.horizontal_axis_sum())
+            .unwrap_or_else(|| {
+                contain_intrinsic_size.width.unwrap_or(initial_column_sum) + content_box_inset.horizontal_axis_sum()
+            })
             .maybe_clamp(min_size.width, max_size.width)
             .max(padding_border_size.width),
         height: resolved_style_size
             .get(AbstractAxis::Block)
-            .unwrap_or_else(|| initial_row_sum + content_box_inset.vertical_axis_sum())
+            .unwrap_or_else(|| {
+                contain_intrinsic_size.height.unwrap_or(initial_row_sum) + content_box_inset.vertical_axis_sum()
+            })
             .maybe_clamp(min_size.height, max_size.height)
             .max(padding_border_size.height),
     };
@@ -229,14 +326,14 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
 
     // If only the container's size has been requested
     if run_mode == RunMode::ComputeSize {
-        return LayoutOutput::from_outer_size(container_border_box);
+        return Ok(LayoutOutput::from_outer_size(container_border_box));
     }
 
     // 7. Resolve percentage track base sizes
     // In the case of an indefinitely sized container these resolve to zero during the "Initialise Tracks" step
     // and therefore need to be re-resolved here based on the content-sized content box of the container
     if !available_grid_space.width.is_definite() {
-        for column in &mut columns {
+        for column in columns.iter_mut().chain(column_gutters.iter_mut()) {
             let min: Option<f32> =
                 column.min_track_sizing_function.resolved_percentage_size(container_content_box.width);
             let max: Option<f32> =
@@ -245,7 +342,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         }
     }
     if !available_grid_space.height.is_definite() {
-        for row in &mut rows {
+        for row in rows.iter_mut().chain(row_gutters.iter_mut()) {
             let min: Option<f32> = row.min_track_sizing_function.resolved_percentage_size(container_content_box.height);
             let max: Option<f32> = row.max_track_sizing_function.resolved_percentage_size(container_content_box.height);
             row.base_size = row.base_size.maybe_clamp(min, max);
@@ -258,7 +355,8 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
     let mut rerun_column_sizing;
 
-    let has_percentage_column = columns.iter().any(|track| track.uses_percentage());
+    let has_percentage_column = columns.iter().any(|track| track.uses_percentage())
+        || column_gutters.iter().any(|gutter| gutter.uses_percentage());
     let parent_width_indefinite = !available_space.width.is_definite();
     rerun_column_sizing = parent_width_indefinite && has_percentage_column;
 
@@ -270,6 +368,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
                 let available_space = item.available_space(
                     AbstractAxis::Inline,
                     &rows,
+                    &row_gutters,
                     inner_node_size.height,
                     |track: &GridTrack, _| Some(track.base_size),
                 );
@@ -304,11 +403,14 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
             AbstractAxis::Inline,
             min_size.get(AbstractAxis::Inline),
             max_size.get(AbstractAxis::Inline),
+            style.grid_align_content(AbstractAxis::Inline),
             style.grid_align_content(AbstractAxis::Block),
             available_grid_space,
             inner_node_size,
             &mut columns,
+            &mut column_gutters,
             &mut rows,
+            &mut row_gutters,
             &mut items,
             |track: &GridTrack, _| Some(track.base_size),
             has_baseline_aligned_item,
@@ -320,7 +422,8 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
         let mut rerun_row_sizing;
 
-        let has_percentage_row = rows.iter().any(|track| track.uses_percentage());
+        let has_percentage_row = rows.iter().any(|track| track.uses_percentage())
+            || row_gutters.iter().any(|gutter| gutter.uses_percentage());
         let parent_height_indefinite = !available_space.height.is_definite();
         rerun_row_sizing = parent_height_indefinite && has_percentage_row;
 
@@ -332,6 +435,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
                     let available_space = item.available_space(
                         AbstractAxis::Block,
                         &columns,
+                        &column_gutters,
                         inner_node_size.width,
                         |track: &GridTrack, _| Some(track.base_size),
                     );
@@ -366,11 +470,14 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
                 AbstractAxis::Block,
                 min_size.get(AbstractAxis::Block),
                 max_size.get(AbstractAxis::Block),
+                style.grid_align_content(AbstractAxis::Block),
                 style.grid_align_content(AbstractAxis::Inline),
                 available_grid_space,
                 inner_node_size,
                 &mut rows,
+                &mut row_gutters,
                 &mut columns,
+                &mut column_gutters,
                 &mut items,
                 |track: &GridTrack, _| Some(track.base_size),
                 false, // TODO: Support baseline alignment in the vertical axis
@@ -386,6 +493,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         Line { start: padding.left, end: padding.right },
         Line { start: border.left, end: border.right },
         &mut columns,
+        &mut column_gutters,
         style.justify_content.unwrap_or(AlignContent::Stretch),
     );
     // Align rows
@@ -394,6 +502,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         Line { start: padding.top, end: padding.bottom },
         Line { start: border.top, end: border.bottom },
         &mut rows,
+        &mut row_gutters,
         style.align_content.unwrap_or(AlignContent::Stretch),
     );
 
@@ -410,10 +519,17 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
     // Position in-flow children (stored in items vector)
     for (index, item) in items.iter_mut().enumerate() {
         let grid_area = Rect {
-            top: rows[item.row_indexes.start as usize + 1].offset,
-            bottom: rows[item.row_indexes.end as usize].offset,
-            left: columns[item.column_indexes.start as usize + 1].offset,
-            right: columns[item.column_indexes.end as usize].offset,
+            top: rows[item.row_indexes.start as usize].offset,
+            bottom: row_gutters[item.row_indexes.end as usize].offset,
+            left: columns[item.column_indexes.start as usize].offset,
+            right: column_gutters[item.column_indexes.end as usize].offset,
+        };
+        #[cfg(feature = "grid_track_alignment")]
+        let container_alignment_styles = InBothAbsAxis {
+            horizontal: track_item_alignment(&style.justify_tracks, item.column_indexes.start)
+                .or(container_alignment_styles.horizontal),
+            vertical: track_item_alignment(&style.align_tracks, item.row_indexes.start)
+                .or(container_alignment_styles.vertical),
         };
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
         let (content_size_contribution, y_position, height) = align_and_position_item(
@@ -433,6 +549,11 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         }
     }
 
+    #[cfg(feature = "content_size")]
+    {
+        item_content_size_contribution = add_end_padding(item_content_size_contribution, padding, style.overflow);
+    }
+
     // Position hidden and absolutely positioned children
     let mut order = items.len() as u32;
     (0..tree.child_count(node)).for_each(|index| {
@@ -475,17 +596,18 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
                     maybe_grid_line.map(|line: OriginZeroLine| line.into_track_vec_index(final_row_counts))
                 });
 
+            // Auto grid lines resolve to the edges of the container's padding box (which excludes any scrollbar gutter)
             let grid_area = Rect {
-                top: maybe_row_indexes.start.map(|index| rows[index].offset).unwrap_or(border.top),
+                top: maybe_row_indexes.start.map(|index| row_gutters[index].offset).unwrap_or(border.top),
                 bottom: maybe_row_indexes
                     .end
-                    .map(|index| rows[index].offset)
-                    .unwrap_or(container_border_box.height - border.bottom),
-                left: maybe_col_indexes.start.map(|index| columns[index].offset).unwrap_or(border.left),
+                    .map(|index| row_gutters[index].offset)
+                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
+                left: maybe_col_indexes.start.map(|index| column_gutters[index].offset).unwrap_or(border.left),
                 right: maybe_col_indexes
                     .end
-                    .map(|index| columns[index].offset)
-                    .unwrap_or(container_border_box.width - border.right),
+                    .map(|index| column_gutters[index].offset)
+                    .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
             };
             // TODO: Baseline alignment support for absolutely positioned items (should check if is actuallty specified)
             #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
@@ -502,7 +624,7 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
 
     // If there are not items then return just the container size (no baseline)
     if items.is_empty() {
-        return LayoutOutput::from_outer_size(container_border_box);
+        return Ok(LayoutOutput::from_outer_size(container_border_box));
     }
 
     // Determine the grid container baseline(s) (currently we only compute the first baseline)
@@ -528,9 +650,9 @@ pub fn compute_grid_layout(tree: &mut impl LayoutPartialTree, node: NodeId, inpu
         item.y_position + item.baseline.unwrap_or(item.height)
     };
 
-    LayoutOutput::from_sizes_and_baselines(
+    Ok(LayoutOutput::from_sizes_and_baselines(
         container_border_box,
         item_content_size_contribution,
         Point { x: None, y: Some(grid_container_baseline) },
-    )
+    ))
 }
@@ -6,7 +6,9 @@ use crate::geometry::Line;
 use crate::geometry::{AbsoluteAxis, InBothAbsAxis};
 use crate::style::{AlignItems, GridAutoFlow, OriginZeroGridPlacement, Style};
 use crate::tree::NodeId;
+use crate::util::debug::profile_count;
 use crate::util::sys::Vec;
+use core::cmp::max;
 
 /// 8.5. Grid Item Placement Algorithm
 /// Place items into the grid, generating new rows/column into the implicit grid as required
@@ -192,8 +194,14 @@ fn place_definite_secondary_axis_item(
             .unwrap_or(primary_axis_grid_start_line),
     };
 
-    let mut position: OriginZeroLine = starting_position;
+    // Positions before the first unoccupied cell of the item's starting track cannot fit the item, so skip them
+    let mut position: OriginZeroLine =
+        match cell_occupancy_matrix.first_unoccupied_primary_line(secondary_axis_placement.start) {
+            Some(first_unoccupied_line) => max(starting_position, first_unoccupied_line),
+            None => starting_position,
+        };
     loop {
+        profile_count!(grid_placement_iterations);
         let primary_axis_placement = placement.get(primary_axis).resolve_indefinite_grid_tracks(position);
 
         let does_fit = cell_occupancy_matrix.line_area_is_unoccupied(
@@ -223,13 +231,10 @@ fn place_indefinitely_positioned_item(
     let primary_placement_style = placement.get(primary_axis);
     let secondary_placement_style = placement.get(primary_axis.other_axis());
 
-    let primary_span = primary_placement_style.indefinite_span();
     let secondary_span = secondary_placement_style.indefinite_span();
     let has_definite_primary_axis_position = primary_placement_style.is_definite();
     let primary_axis_grid_start_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_start_line();
     let primary_axis_grid_end_line = cell_occupancy_matrix.track_counts(primary_axis).implicit_end_line();
-    let secondary_axis_grid_start_line =
-        cell_occupancy_matrix.track_counts(primary_axis.other_axis()).implicit_start_line();
 
     let line_area_is_occupied = |primary_span, secondary_span| {
         !cell_occupancy_matrix.line_area_is_unoccupied(primary_axis, primary_span, secondary_span)
@@ -240,18 +245,19 @@ fn place_indefinitely_positioned_item(
     if has_definite_primary_axis_position {
         let definite_primary_placement = primary_placement_style.resolve_definite_grid_lines();
         let defined_primary_idx = definite_primary_placement.start;
+        let primary_span = definite_primary_placement.span();
 
-        // Compute starting position for search
-        if defined_primary_idx < primary_idx && secondary_idx != secondary_axis_grid_start_line {
-            secondary_idx = secondary_axis_grid_start_line;
-            primary_idx = defined_primary_idx + 1;
-        } else {
-            primary_idx = defined_primary_idx;
+        // Compute starting position for search: the item's start line is fixed, so if the cursor has already moved
+        // past it then the item must be placed in a later secondary axis track
+        if defined_primary_idx < primary_idx {
+            secondary_idx += 1;
         }
+        primary_idx = defined_primary_idx;
 
         // Item has fixed primary axis position: so we simply increment the secondary axis position
         // until we find a space that the item fits in
         loop {
+            profile_count!(grid_placement_iterations);
             let primary_span = Line { start: primary_idx, end: primary_idx + primary_span };
             let secondary_span = Line { start: secondary_idx, end: secondary_idx + secondary_span };
 
@@ -268,7 +274,21 @@ fn place_indefinitely_positioned_item(
         // Item does not have any fixed axis, so we search along the primary axis until we hit the end of the already
         // existent tracks, and then we reset the primary axis back to zero and increment the secondary axis index.
         // We continue in this vein until we find a space that the item fits in.
+        //
+        // Any area that starts in a completely full secondary axis track, or before the first unoccupied cell of
+        // its starting secondary axis track, is certain to be occupied. So we skip over such positions without
+        // checking them individually (this is purely an optimisation: it does not change where the item ends up)
+        let primary_span = primary_placement_style.indefinite_span();
+        let first_non_full_secondary_line = cell_occupancy_matrix.first_non_full_secondary_line();
         loop {
+            profile_count!(grid_placement_iterations);
+
+            // Skip completely full secondary axis tracks
+            if secondary_idx < first_non_full_secondary_line {
+                secondary_idx = first_non_full_secondary_line;
+                primary_idx = primary_axis_grid_start_line;
+            }
+
             let primary_span = Line { start: primary_idx, end: primary_idx + primary_span };
             let secondary_span = Line { start: secondary_idx, end: secondary_idx + secondary_span };
 
@@ -281,6 +301,14 @@ fn place_indefinitely_positioned_item(
                 continue;
             }
 
+            // Skip over the occupied cells at the start of the current secondary axis track
+            if let Some(first_unoccupied_line) = cell_occupancy_matrix.first_unoccupied_primary_line(secondary_idx) {
+                if primary_idx < first_unoccupied_line {
+                    primary_idx = first_unoccupied_line;
+                    continue;
+                }
+            }
+
             // If area is occupied, increment the primary index and try again
             if line_area_is_occupied(primary_span, secondary_span) {
                 primary_idx += 1;
@@ -372,7 +400,7 @@ mod tests {
             let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, child_styles_iter);
             let mut items = Vec::new();
             let mut cell_occupancy_matrix =
-                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1);
+                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1, flow.primary_axis());
 
             // Run placement algorithm
             place_grid_items(
@@ -439,6 +467,50 @@ mod tests {
             placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
         }
 
+        #[test]
+        fn test_placement_zero_span() {
+            let flow = GridAutoFlow::Row;
+            let explicit_col_count = 2;
+            let explicit_row_count = 2;
+            let children = {
+                vec![
+                    // output order, node, style (grid coords), expected_placement (oz coords)
+                    (
+                        1,
+                        (line(2), GridPlacement::Span(0), GridPlacement::Span(0), line(3)).into_grid_child(),
+                        (1, 2, 1, 2),
+                    ),
+                    (
+                        2,
+                        (GridPlacement::Span(0), auto(), auto(), GridPlacement::Span(0)).into_grid_child(),
+                        (0, 1, 0, 1),
+                    ),
+                    (3, (GridPlacement::Span(0), auto(), auto(), auto()).into_grid_child(), (1, 2, 0, 1)),
+                ]
+            };
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 2, positive_implicit: 0 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 2, positive_implicit: 0 };
+            placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
+        }
+
+        #[test]
+        fn test_placement_enormous_span() {
+            let flow = GridAutoFlow::Row;
+            let explicit_col_count = 2;
+            let explicit_row_count = 2;
+            let children = {
+                vec![
+                    // output order, node, style (grid coords), expected_placement (oz coords)
+                    (1, (line(2), GridPlacement::Span(u16::MAX), line(1), auto()).into_grid_child(), (1, 1001, 0, 1)),
+                    (2, (GridPlacement::Span(40000), line(-1), line(2), auto()).into_grid_child(), (-998, 2, 1, 2)),
+                    (3, (GridPlacement::Span(u16::MAX), auto(), auto(), auto()).into_grid_child(), (-998, 2, 2, 3)),
+                ]
+            };
+            let expected_cols = TrackCounts { negative_implicit: 998, explicit: 2, positive_implicit: 999 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 2, positive_implicit: 1 };
+            placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
+        }
+
         #[test]
         fn test_only_auto_placement_row_flow() {
             let flow = GridAutoFlow::Row;
@@ -594,4 +666,178 @@ mod tests {
             placement_test_runner(explicit_col_count, explicit_row_count, children, expected_cols, expected_rows, flow);
         }
     }
+
+    /// Placements of pseudo-randomly generated items as produced by the original cell-by-cell search.
+    /// Optimisations to the CellOccupancyMatrix or to the search must reproduce these exactly.
+    mod test_placement_golden {
+        use crate::compute::grid::implicit_grid::compute_grid_size_estimate;
+        use crate::compute::grid::types::TrackCounts;
+        use crate::compute::grid::CellOccupancyMatrix;
+        use crate::prelude::*;
+        use crate::style::GridAutoFlow;
+
+        use super::super::place_grid_items;
+
+        /// (column start, column end, row start, row end) in OriginZero coordinates
+        type Placement = (i16, i16, i16, i16);
+
+        /// A small xorshift PRNG so that the generated children are reproducible without extra dependencies
+        fn next_random(state: &mut u32, bound: u32) -> u32 {
+            *state ^= *state << 13;
+            *state ^= *state >> 17;
+            *state ^= *state << 5;
+            *state % bound
+        }
+
+        fn random_placement(state: &mut u32, is_definite: bool, explicit_track_count: i16) -> Line<GridPlacement> {
+            let span_size = 1 + next_random(state, 3) as u16;
+            let start = match is_definite {
+                true => line(1 + next_random(state, explicit_track_count as u32 + 2) as i16),
+                false => auto(),
+            };
+            Line { start, end: span(span_size) }
+        }
+
+        /// Generates a reproducible mix of definitely positioned, partially positioned and auto-placed children
+        fn random_children(seed: u32, count: usize, explicit_col_count: u16, explicit_row_count: u16) -> Vec<Style> {
+            let mut state = seed;
+            (0..count)
+                .map(|_| {
+                    let (column_is_definite, row_is_definite) = match next_random(&mut state, 10) {
+                        0 => (true, true),
+                        1 => (true, false),
+                        2 => (false, true),
+                        _ => (false, false),
+                    };
+                    Style {
+                        display: Display::Grid,
+                        grid_column: random_placement(&mut state, column_is_definite, explicit_col_count as i16),
+                        grid_row: random_placement(&mut state, row_is_definite, explicit_row_count as i16),
+                        ..Default::default()
+                    }
+                })
+                .collect()
+        }
+
+        fn golden_test_runner(
+            seed: u32,
+            flow: GridAutoFlow,
+            expected_placements: &[Placement],
+            expected_col_counts: TrackCounts,
+            expected_row_counts: TrackCounts,
+        ) {
+            let (explicit_col_count, explicit_row_count) = (5, 4);
+            let styles = random_children(seed, expected_placements.len(), explicit_col_count, explicit_row_count);
+            let children_iter = || styles.iter().enumerate().map(|(index, style)| (index, NodeId::from(index), style));
+            let estimated_sizes = compute_grid_size_estimate(explicit_col_count, explicit_row_count, styles.iter());
+            let mut items = Vec::new();
+            let mut cell_occupancy_matrix =
+                CellOccupancyMatrix::with_track_counts(estimated_sizes.0, estimated_sizes.1, flow.primary_axis());
+
+            place_grid_items(
+                &mut cell_occupancy_matrix,
+                &mut items,
+                children_iter,
+                flow,
+                AlignSelf::Start,
+                AlignSelf::Start,
+            );
+
+            let mut actual_placements: Vec<(usize, Placement)> = items
+                .iter()
+                .map(|item| {
+                    (usize::from(item.node), (item.column.start.0, item.column.end.0, item.row.start.0, item.row.end.0))
+                })
+                .collect();
+            actual_placements.sort_by_key(|(index, _)| *index);
+            let actual_placements: Vec<Placement> =
+                actual_placements.into_iter().map(|(_, placement)| placement).collect();
+            assert_eq!(actual_placements, expected_placements, "placements");
+
+            let actual_row_counts = *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Vertical);
+            assert_eq!(actual_row_counts, expected_row_counts, "row track counts");
+            let actual_col_counts = *cell_occupancy_matrix.track_counts(crate::compute::grid::AbsoluteAxis::Horizontal);
+            assert_eq!(actual_col_counts, expected_col_counts, "column track counts");
+        }
+
+        #[test]
+        fn test_golden_placement_row() {
+            #[rustfmt::skip]
+            let expected_placements = [
+                (0, 2, 0, 1), (6, 9, 0, 2), (0, 1, 4, 7), (9, 12, 0, 2),
+                (8, 11, 2, 3), (0, 1, 1, 2), (9, 12, 3, 5), (1, 2, 4, 6),
+                (3, 5, 6, 8), (9, 12, 5, 7), (3, 6, 0, 2), (0, 2, 7, 10),
+                (0, 2, 3, 4), (2, 3, 7, 10), (5, 7, 7, 8), (5, 6, 8, 9),
+                (6, 8, 8, 11), (8, 9, 8, 11), (9, 10, 8, 10), (2, 3, 1, 4),
+                (10, 11, 8, 9), (5, 7, 4, 5), (2, 4, 10, 13), (4, 5, 10, 11),
+                (4, 6, 5, 6), (0, 3, 13, 16), (0, 1, 16, 17), (0, 1, 17, 19),
+                (3, 5, 3, 5), (2, 3, 5, 7), (1, 2, 17, 19), (2, 3, 17, 19),
+                (3, 4, 17, 20), (5, 8, 2, 4), (4, 5, 17, 19), (5, 7, 17, 19),
+                (7, 9, 17, 19), (9, 12, 17, 18), (9, 11, 18, 19), (6, 9, 4, 7),
+            ];
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 5, positive_implicit: 7 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 4, positive_implicit: 16 };
+            golden_test_runner(1, GridAutoFlow::Row, &expected_placements, expected_cols, expected_rows);
+        }
+
+        #[test]
+        fn test_golden_placement_column() {
+            #[rustfmt::skip]
+            let expected_placements = [
+                (0, 1, 0, 3), (1, 3, 1, 3), (2, 5, 5, 7), (3, 4, 0, 3),
+                (3, 6, 3, 5), (4, 5, 0, 1), (7, 10, 4, 6), (9, 10, 0, 2),
+                (9, 11, 2, 4), (10, 13, 4, 7), (11, 14, 0, 2), (11, 12, 2, 4),
+                (12, 15, 2, 3), (6, 9, 1, 4), (6, 7, 4, 7), (1, 4, 5, 7),
+                (13, 16, 3, 6), (15, 16, 0, 3), (16, 17, 0, 3), (16, 19, 3, 5),
+                (17, 20, 0, 3), (20, 22, 1, 4), (20, 23, 4, 6), (6, 8, 0, 1),
+                (22, 24, 0, 3), (22, 23, 3, 4), (24, 27, 0, 1), (24, 26, 1, 4),
+                (24, 26, 4, 6), (26, 28, 1, 4), (26, 27, 4, 7), (27, 29, 4, 6),
+                (28, 31, 0, 3), (29, 32, 3, 5), (31, 32, 0, 3), (31, 32, 5, 7),
+                (32, 33, 0, 2), (32, 35, 2, 5), (32, 35, 5, 6), (33, 35, 0, 2),
+            ];
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 5, positive_implicit: 30 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 4, positive_implicit: 3 };
+            golden_test_runner(2, GridAutoFlow::Column, &expected_placements, expected_cols, expected_rows);
+        }
+
+        #[test]
+        fn test_golden_placement_row_dense() {
+            #[rustfmt::skip]
+            let expected_placements = [
+                (0, 3, 0, 1), (6, 8, 3, 5), (8, 9, 3, 5), (6, 7, 5, 8),
+                (0, 3, 7, 10), (3, 6, 7, 10), (7, 8, 5, 7), (6, 9, 8, 11),
+                (3, 6, 0, 1), (8, 9, 5, 7), (4, 6, 3, 6), (0, 1, 10, 12),
+                (6, 9, 0, 1), (1, 2, 10, 13), (2, 5, 10, 11), (5, 6, 10, 13),
+                (2, 4, 11, 13), (4, 5, 11, 14), (6, 8, 11, 13), (0, 2, 6, 7),
+                (6, 8, 13, 14), (0, 3, 3, 6), (0, 3, 13, 15), (0, 1, 1, 3),
+                (2, 5, 15, 18), (4, 5, 6, 7), (5, 7, 14, 16), (5, 8, 16, 18),
+                (4, 7, 1, 3), (7, 9, 1, 3), (0, 3, 18, 21), (3, 6, 18, 20),
+                (8, 9, 11, 14), (6, 9, 18, 21), (0, 2, 3, 5), (3, 6, 20, 22),
+                (6, 8, 21, 22), (8, 9, 14, 17), (2, 4, 4, 7), (1, 4, 1, 4),
+            ];
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 5, positive_implicit: 4 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 4, positive_implicit: 18 };
+            golden_test_runner(3, GridAutoFlow::RowDense, &expected_placements, expected_cols, expected_rows);
+        }
+
+        #[test]
+        fn test_golden_placement_column_dense() {
+            #[rustfmt::skip]
+            let expected_placements = [
+                (0, 1, 0, 2), (0, 2, 6, 8), (0, 2, 8, 9), (3, 6, 7, 10),
+                (6, 7, 3, 4), (0, 1, 3, 6), (0, 3, 9, 10), (1, 4, 0, 2),
+                (0, 2, 10, 12), (2, 5, 1, 3), (0, 3, 12, 13), (2, 5, 6, 7),
+                (8, 10, 4, 7), (2, 4, 10, 11), (6, 7, 1, 3), (3, 4, 3, 4),
+                (6, 9, 7, 9), (2, 5, 11, 12), (4, 5, 3, 6), (3, 5, 12, 13),
+                (5, 8, 4, 7), (0, 1, 2, 3), (7, 10, 0, 3), (2, 3, 7, 8),
+                (8, 11, 9, 11), (5, 6, 0, 3), (5, 6, 3, 4), (8, 11, 11, 13),
+                (5, 8, 10, 13), (10, 11, 0, 3), (9, 12, 7, 9), (1, 4, 5, 6),
+                (10, 11, 3, 5), (11, 14, 0, 3), (7, 10, 3, 4), (11, 14, 3, 6),
+                (11, 13, 9, 11), (6, 8, 9, 10), (1, 3, 2, 5), (2, 3, 8, 9),
+            ];
+            let expected_cols = TrackCounts { negative_implicit: 0, explicit: 5, positive_implicit: 9 };
+            let expected_rows = TrackCounts { negative_implicit: 0, explicit: 4, positive_implicit: 9 };
+            golden_test_runner(4, GridAutoFlow::ColumnDense, &expected_placements, expected_cols, expected_rows);
+        }
+    }
 }
@@ -7,6 +7,7 @@ use crate::style::{
 };
 use crate::style_helpers::TaffyMinContent;
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, SizingMode};
+use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, f32_min, Vec};
 use crate::util::{MaybeMath, ResolveOrZero};
 use core::cmp::Ordering;
@@ -74,6 +75,8 @@ where
     tree: &'tree mut Tree,
     /// The tracks in the opposite axis to the one we are currently sizing
     other_axis_tracks: &'oat [GridTrack],
+    /// The gutters in the opposite axis to the one we are currently sizing
+    other_axis_gutters: &'oat [GridTrack],
     /// A function that computes an estimate of an other-axis track's size which is passed to
     /// the child size measurement functions
     get_track_size_estimate: EstimateFunction,
@@ -97,6 +100,7 @@ where
         item.available_space_cached(
             self.axis,
             self.other_axis_tracks,
+            self.other_axis_gutters,
             self.inner_node_size.get(self.axis.other()),
             &self.get_track_size_estimate,
         )
@@ -136,11 +140,22 @@ where
     ///   - Else the item’s minimum contribution is its min-content contribution.
     /// Because the minimum contribution often depends on the size of the item’s content, it is considered a type of intrinsic size contribution.
     #[inline(always)]
-    fn minimum_contribution(&mut self, item: &mut GridItem, axis_tracks: &[GridTrack]) -> f32 {
+    fn minimum_contribution(
+        &mut self,
+        item: &mut GridItem,
+        axis_tracks: &[GridTrack],
+        axis_gutters: &[GridTrack],
+    ) -> f32 {
         let available_space = self.available_space(item);
         let margin_axis_sums = self.margins_axis_sums_with_baseline_shims(item);
-        let contribution =
-            item.minimum_contribution_cached(self.tree, self.axis, axis_tracks, available_space, self.inner_node_size);
+        let contribution = item.minimum_contribution_cached(
+            self.tree,
+            self.axis,
+            axis_tracks,
+            axis_gutters,
+            available_space,
+            self.inner_node_size,
+        );
         contribution + margin_axis_sums.get(self.axis)
     }
 }
@@ -177,8 +192,9 @@ pub(super) fn compute_alignment_gutter_adjustment(
     axis_inner_node_size: Option<f32>,
     get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
     tracks: &[GridTrack],
+    gutters: &[GridTrack],
 ) -> f32 {
-    if tracks.len() <= 1 {
+    if tracks.is_empty() {
         return 0.0;
     }
 
@@ -190,6 +206,9 @@ pub(super) fn compute_alignment_gutter_adjustment(
         AlignContent::End => 1,
         AlignContent::FlexEnd => 1,
         AlignContent::Center => 1,
+        AlignContent::SafeEnd => 1,
+        AlignContent::SafeFlexEnd => 1,
+        AlignContent::SafeCenter => 1,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 0,
         AlignContent::SpaceAround => 1,
@@ -202,6 +221,9 @@ pub(super) fn compute_alignment_gutter_adjustment(
         AlignContent::FlexEnd => 0,
         AlignContent::End => 0,
         AlignContent::Center => 0,
+        AlignContent::SafeEnd => 0,
+        AlignContent::SafeFlexEnd => 0,
+        AlignContent::SafeCenter => 0,
         AlignContent::Stretch => 0,
         AlignContent::SpaceBetween => 1,
         AlignContent::SpaceAround => 2,
@@ -215,13 +237,14 @@ pub(super) fn compute_alignment_gutter_adjustment(
     if let Some(axis_inner_node_size) = axis_inner_node_size {
         let free_space = tracks
             .iter()
+            .chain(gutters.iter())
             .map(|track| get_track_size_estimate(track, Some(axis_inner_node_size)))
             .sum::<Option<f32>>()
             .map(|track_size_sum| f32_max(0.0, axis_inner_node_size - track_size_sum))
             .unwrap_or(0.0);
 
         let weighted_track_count =
-            (((tracks.len() - 3) / 2) * inner_gutter_weight as usize) + (2 * outer_gutter_weight as usize);
+            ((tracks.len() - 1) * inner_gutter_weight as usize) + (2 * outer_gutter_weight as usize);
 
         return (free_space / weighted_track_count as f32) * inner_gutter_weight as f32;
     }
@@ -229,6 +252,12 @@ pub(super) fn compute_alignment_gutter_adjustment(
     0.0
 }
 
+/// The sum of the (resolved) sizes of a set of gutters
+#[inline(always)]
+pub(super) fn sum_gutter_sizes(gutters: &[GridTrack]) -> f32 {
+    gutters.iter().map(|gutter| gutter.base_size).sum()
+}
+
 /// Convert origin-zero coordinates track placement in grid track vector indexes
 #[inline(always)]
 pub(super) fn resolve_item_track_indexes(items: &mut [GridItem], column_counts: TrackCounts, row_counts: TrackCounts) {
@@ -246,14 +275,12 @@ pub(super) fn determine_if_item_crosses_flexible_or_intrinsic_tracks(
     rows: &[GridTrack],
 ) {
     for item in items {
-        item.crosses_flexible_column =
-            item.track_range_excluding_lines(AbstractAxis::Inline).any(|i| columns[i].is_flexible());
+        item.crosses_flexible_column = item.track_range(AbstractAxis::Inline).any(|i| columns[i].is_flexible());
         item.crosses_intrinsic_column =
-            item.track_range_excluding_lines(AbstractAxis::Inline).any(|i| columns[i].has_intrinsic_sizing_function());
-        item.crosses_flexible_row =
-            item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].is_flexible());
+            item.track_range(AbstractAxis::Inline).any(|i| columns[i].has_intrinsic_sizing_function());
+        item.crosses_flexible_row = item.track_range(AbstractAxis::Block).any(|i| rows[i].is_flexible());
         item.crosses_intrinsic_row =
-            item.track_range_excluding_lines(AbstractAxis::Block).any(|i| rows[i].has_intrinsic_sizing_function());
+            item.track_range(AbstractAxis::Block).any(|i| rows[i].has_intrinsic_sizing_function());
     }
 }
 
@@ -266,18 +293,21 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
     axis: AbstractAxis,
     axis_min_size: Option<f32>,
     axis_max_size: Option<f32>,
+    axis_alignment: AlignContent,
     other_axis_alignment: AlignContent,
     available_grid_space: Size<AvailableSpace>,
     inner_node_size: Size<Option<f32>>,
     axis_tracks: &mut [GridTrack],
+    axis_gutters: &mut [GridTrack],
     other_axis_tracks: &mut [GridTrack],
+    other_axis_gutters: &mut [GridTrack],
     items: &mut [GridItem],
     get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
     has_baseline_aligned_item: bool,
 ) {
     // 11.4 Initialise Track sizes
     // Initialize each track’s base size and growth limit.
-    initialize_track_sizes(axis_tracks, inner_node_size.get(axis));
+    initialize_track_sizes(axis_tracks, axis_gutters, inner_node_size.get(axis));
 
     // 11.5.1 Shim item baselines
     if has_baseline_aligned_item {
@@ -286,7 +316,7 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
 
     // If all tracks have base_size = growth_limit, then skip the rest of this function.
     // Note: this can only happen both track sizing function have the same fixed track sizing function
-    if axis_tracks.iter().all(|track| track.base_size == track.growth_limit) {
+    if axis_tracks.iter().chain(axis_gutters.iter()).all(|track| track.base_size == track.growth_limit) {
         return;
     }
 
@@ -299,12 +329,12 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
         inner_node_size.get(axis.other()),
         &get_track_size_estimate,
         other_axis_tracks,
+        other_axis_gutters,
     );
-    if other_axis_tracks.len() > 3 {
-        let len = other_axis_tracks.len();
-        let inner_gutter_tracks = other_axis_tracks[2..len].iter_mut().step_by(2);
-        for track in inner_gutter_tracks {
-            track.content_alignment_adjustment = gutter_alignment_adjustment;
+    if other_axis_tracks.len() > 1 {
+        let len = other_axis_gutters.len();
+        for gutter in other_axis_gutters[1..len - 1].iter_mut() {
+            gutter.content_alignment_adjustment = gutter_alignment_adjustment;
         }
     }
 
@@ -313,7 +343,9 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
         tree,
         axis,
         axis_tracks,
+        axis_gutters,
         other_axis_tracks,
+        other_axis_gutters,
         items,
         available_grid_space.get(axis),
         inner_node_size,
@@ -322,7 +354,7 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
 
     // 11.6. Maximise Tracks
     // Distributes free space (if any) to tracks with FINITE growth limits, up to their limits.
-    maximise_tracks(axis_tracks, inner_node_size.get(axis), available_grid_space.get(axis));
+    maximise_tracks(axis_tracks, axis_gutters, inner_node_size.get(axis), available_grid_space.get(axis));
 
     // For the purpose of the final two expansion steps ("Expand Flexible Tracks" and "Stretch auto Tracks"), we only want to expand
     // into space generated by the grid container's size (as defined by either it's preferred size style or by it's parent node through
@@ -343,6 +375,7 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
         tree,
         axis,
         axis_tracks,
+        axis_gutters,
         items,
         axis_min_size,
         axis_max_size,
@@ -352,7 +385,10 @@ pub(super) fn track_sizing_algorithm<Tree: LayoutPartialTree>(
 
     // 11.8. Stretch auto Tracks
     // This step expands tracks that have an auto max track sizing function by dividing any remaining positive, definite free space equally amongst them.
-    stretch_auto_tracks(axis_tracks, axis_min_size, axis_available_space_for_expansion);
+    // It only applies when the grid container's content alignment in this axis is `normal` or `stretch`.
+    if axis_alignment == AlignContent::Stretch {
+        stretch_auto_tracks(axis_tracks, axis_gutters, axis_min_size, axis_available_space_for_expansion);
+    }
 }
 
 /// Whether it is a minimum or maximum size's space being distributed
@@ -369,8 +405,8 @@ enum IntrinsicContributionType {
 /// Add any planned base size increases to the base size after a round of distributing space to base sizes
 /// Reset the planed base size increase to zero ready for the next round.
 #[inline(always)]
-fn flush_planned_base_size_increases(tracks: &mut [GridTrack]) {
-    for track in tracks {
+fn flush_planned_base_size_increases(tracks: &mut [GridTrack], gutters: &mut [GridTrack]) {
+    for track in tracks.iter_mut().chain(gutters.iter_mut()) {
         track.base_size += track.base_size_planned_increase;
         track.base_size_planned_increase = 0.0;
     }
@@ -379,8 +415,12 @@ fn flush_planned_base_size_increases(tracks: &mut [GridTrack]) {
 /// Add any planned growth limit increases to the growth limit after a round of distributing space to growth limits
 /// Reset the planed growth limit increase to zero ready for the next round.
 #[inline(always)]
-fn flush_planned_growth_limit_increases(tracks: &mut [GridTrack], set_infinitely_growable: bool) {
-    for track in tracks {
+fn flush_planned_growth_limit_increases(
+    tracks: &mut [GridTrack],
+    gutters: &mut [GridTrack],
+    set_infinitely_growable: bool,
+) {
+    for track in tracks.iter_mut().chain(gutters.iter_mut()) {
         if track.growth_limit_planned_increase > 0.0 {
             track.growth_limit = if track.growth_limit == f32::INFINITY {
                 track.base_size + track.growth_limit_planned_increase
@@ -398,17 +438,13 @@ fn flush_planned_growth_limit_increases(tracks: &mut [GridTrack], set_infinitely
 /// 11.4 Initialise Track sizes
 /// Initialize each track’s base size and growth limit.
 #[inline(always)]
-fn initialize_track_sizes(axis_tracks: &mut [GridTrack], axis_inner_node_size: Option<f32>) {
-    let last_track_idx = axis_tracks.len() - 1;
-
-    // First and last grid lines are always zero-sized.
-    axis_tracks[0].base_size = 0.0;
-    axis_tracks[0].growth_limit = 0.0;
-    axis_tracks[last_track_idx].base_size = 0.0;
-    axis_tracks[last_track_idx].growth_limit = 0.0;
-
-    let all_but_first_and_last = 1..last_track_idx;
-    for track in axis_tracks[all_but_first_and_last].iter_mut() {
+fn initialize_track_sizes(
+    axis_tracks: &mut [GridTrack],
+    axis_gutters: &mut [GridTrack],
+    axis_inner_node_size: Option<f32>,
+) {
+    // Note: first and last grid lines are always collapsed and thus zero-sized.
+    for track in axis_tracks.iter_mut().chain(axis_gutters.iter_mut()) {
         // For each track, if the track’s min track sizing function is:
         // - A fixed sizing function
         //     Resolve to an absolute length and use that size as the track’s initial base size.
@@ -512,7 +548,9 @@ fn resolve_intrinsic_track_sizes(
     tree: &mut impl LayoutPartialTree,
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
+    axis_gutters: &mut [GridTrack],
     other_axis_tracks: &[GridTrack],
+    other_axis_gutters: &[GridTrack],
     items: &mut [GridItem],
     axis_available_grid_space: AvailableSpace,
     inner_node_size: Size<Option<f32>>,
@@ -542,8 +580,14 @@ fn resolve_intrinsic_track_sizes(
 
     let axis_inner_node_size = inner_node_size.get(axis);
     let flex_factor_sum = axis_tracks.iter().map(|track| track.flex_factor()).sum::<f32>();
-    let mut item_sizer =
-        IntrisicSizeMeasurer { tree, other_axis_tracks, axis, inner_node_size, get_track_size_estimate };
+    let mut item_sizer = IntrisicSizeMeasurer {
+        tree,
+        other_axis_tracks,
+        other_axis_gutters,
+        axis,
+        inner_node_size,
+        get_track_size_estimate,
+    };
 
     let mut batched_item_iterator = ItemBatcher::new(axis);
     while let Some((batch, is_flex)) = batched_item_iterator.next(items) {
@@ -552,7 +596,7 @@ fn resolve_intrinsic_track_sizes(
         let batch_span = batch[0].placement(axis).span();
         if !is_flex && batch_span == 1 {
             for item in batch.iter_mut() {
-                let track_index = item.placement_indexes(axis).start + 1;
+                let track_index = item.placement_indexes(axis).start;
                 let track = &axis_tracks[track_index as usize];
 
                 // Handle base sizes
@@ -585,12 +629,13 @@ fn resolve_intrinsic_track_sizes(
                             AvailableSpace::MinContent | AvailableSpace::MaxContent
                                 if !item.overflow.get(axis).is_scroll_container() =>
                             {
-                                let axis_minimum_size = item_sizer.minimum_contribution(item, axis_tracks);
+                                let axis_minimum_size =
+                                    item_sizer.minimum_contribution(item, axis_tracks, axis_gutters);
                                 let axis_min_content_size = item_sizer.min_content_contribution(item);
                                 let limit = track.max_track_sizing_function.definite_limit(axis_inner_node_size);
                                 axis_min_content_size.maybe_min(limit).max(axis_minimum_size)
                             }
-                            _ => item_sizer.minimum_contribution(item, axis_tracks),
+                            _ => item_sizer.minimum_contribution(item, axis_tracks, axis_gutters),
                         };
                         f32_max(track.base_size, space)
                     }
@@ -632,7 +677,7 @@ fn resolve_intrinsic_track_sizes(
                 }
             }
 
-            for track in axis_tracks.iter_mut() {
+            for track in axis_tracks.iter_mut().chain(axis_gutters.iter_mut()) {
                 if track.growth_limit_planned_increase > 0.0 {
                     track.growth_limit = if track.growth_limit == f32::INFINITY {
                         track.growth_limit_planned_increase
@@ -670,14 +715,15 @@ fn resolve_intrinsic_track_sizes(
                 AvailableSpace::MinContent | AvailableSpace::MaxContent
                     if !item.overflow.get(axis).is_scroll_container() =>
                 {
-                    let axis_minimum_size = item_sizer.minimum_contribution(item, axis_tracks);
+                    let axis_minimum_size = item_sizer.minimum_contribution(item, axis_tracks, axis_gutters);
                     let axis_min_content_size = item_sizer.min_content_contribution(item);
-                    let limit = item.spanned_track_limit(axis, axis_tracks, axis_inner_node_size);
+                    let limit = item.spanned_track_limit(axis, axis_tracks, axis_gutters, axis_inner_node_size);
                     axis_min_content_size.maybe_min(limit).max(axis_minimum_size)
                 }
-                _ => item_sizer.minimum_contribution(item, axis_tracks),
+                _ => item_sizer.minimum_contribution(item, axis_tracks, axis_gutters),
             };
-            let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+            let tracks = &mut axis_tracks[item.track_range(axis)];
+            let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
             if space > 0.0 {
                 if item.overflow.get(axis).is_scroll_container() {
                     let fit_content_limit = move |track: &GridTrack| track.fit_content_limit(axis_inner_node_size);
@@ -686,6 +732,7 @@ fn resolve_intrinsic_track_sizes(
                         use_flex_factor_for_distribution,
                         space,
                         tracks,
+                        gutters,
                         has_intrinsic_min_track_sizing_function,
                         fit_content_limit,
                         IntrinsicContributionType::Minimum,
@@ -696,6 +743,7 @@ fn resolve_intrinsic_track_sizes(
                         use_flex_factor_for_distribution,
                         space,
                         tracks,
+                        gutters,
                         has_intrinsic_min_track_sizing_function,
                         |_| f32::INFINITY,
                         IntrinsicContributionType::Minimum,
@@ -703,7 +751,7 @@ fn resolve_intrinsic_track_sizes(
                 }
             }
         }
-        flush_planned_base_size_increases(axis_tracks);
+        flush_planned_base_size_increases(axis_tracks, axis_gutters);
 
         // 2. For content-based minimums:
         // Next continue to increase the base size of tracks with a min track sizing function of min-content or max-content
@@ -714,7 +762,8 @@ fn resolve_intrinsic_track_sizes(
         };
         for item in batch.iter_mut() {
             let space = item_sizer.min_content_contribution(item);
-            let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+            let tracks = &mut axis_tracks[item.track_range(axis)];
+            let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
             if space > 0.0 {
                 if item.overflow.get(axis).is_scroll_container() {
                     let fit_content_limit = move |track: &GridTrack| track.fit_content_limit(axis_inner_node_size);
@@ -723,6 +772,7 @@ fn resolve_intrinsic_track_sizes(
                         use_flex_factor_for_distribution,
                         space,
                         tracks,
+                        gutters,
                         has_min_or_max_content_min_track_sizing_function,
                         fit_content_limit,
                         IntrinsicContributionType::Minimum,
@@ -733,6 +783,7 @@ fn resolve_intrinsic_track_sizes(
                         use_flex_factor_for_distribution,
                         space,
                         tracks,
+                        gutters,
                         has_min_or_max_content_min_track_sizing_function,
                         |_| f32::INFINITY,
                         IntrinsicContributionType::Minimum,
@@ -740,7 +791,7 @@ fn resolve_intrinsic_track_sizes(
                 }
             }
         }
-        flush_planned_base_size_increases(axis_tracks);
+        flush_planned_base_size_increases(axis_tracks, axis_gutters);
 
         // 3. For max-content minimums:
 
@@ -774,9 +825,10 @@ fn resolve_intrinsic_track_sizes(
 
             for item in batch.iter_mut() {
                 let axis_max_content_size = item_sizer.max_content_contribution(item);
-                let limit = item.spanned_track_limit(axis, axis_tracks, axis_inner_node_size);
+                let limit = item.spanned_track_limit(axis, axis_tracks, axis_gutters, axis_inner_node_size);
                 let space = axis_max_content_size.maybe_min(limit);
-                let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+                let tracks = &mut axis_tracks[item.track_range(axis)];
+                let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
                 if space > 0.0 {
                     // If any of the tracks spanned by the item have a MaxContent min track sizing function then
                     // distribute space only to those tracks. Otherwise distribute space to tracks with an Auto min
@@ -794,6 +846,7 @@ fn resolve_intrinsic_track_sizes(
                             use_flex_factor_for_distribution,
                             space,
                             tracks,
+                            gutters,
                             has_max_content_min_track_sizing_function,
                             |_| f32::INFINITY,
                             IntrinsicContributionType::Maximum,
@@ -806,6 +859,7 @@ fn resolve_intrinsic_track_sizes(
                             use_flex_factor_for_distribution,
                             space,
                             tracks,
+                            gutters,
                             has_auto_min_track_sizing_function,
                             fit_content_limited_growth_limit,
                             IntrinsicContributionType::Maximum,
@@ -813,7 +867,7 @@ fn resolve_intrinsic_track_sizes(
                     }
                 }
             }
-            flush_planned_base_size_increases(axis_tracks);
+            flush_planned_base_size_increases(axis_tracks, axis_gutters);
         }
 
         // In all cases, continue to increase the base size of tracks with a min track sizing function of max-content by distributing
@@ -823,23 +877,25 @@ fn resolve_intrinsic_track_sizes(
         for item in batch.iter_mut() {
             let axis_max_content_size = item_sizer.max_content_contribution(item);
             let space = axis_max_content_size;
-            let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+            let tracks = &mut axis_tracks[item.track_range(axis)];
+            let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
             if space > 0.0 {
                 distribute_item_space_to_base_size(
                     is_flex,
                     use_flex_factor_for_distribution,
                     space,
                     tracks,
+                    gutters,
                     has_max_content_min_track_sizing_function,
                     |_| f32::INFINITY,
                     IntrinsicContributionType::Maximum,
                 );
             }
         }
-        flush_planned_base_size_increases(axis_tracks);
+        flush_planned_base_size_increases(axis_tracks, axis_gutters);
 
         // 4. If at this point any track’s growth limit is now less than its base size, increase its growth limit to match its base size.
-        for track in axis_tracks.iter_mut() {
+        for track in axis_tracks.iter_mut().chain(axis_gutters.iter_mut()) {
             if track.growth_limit < track.base_size {
                 track.growth_limit = track.base_size;
             }
@@ -855,18 +911,20 @@ fn resolve_intrinsic_track_sizes(
             for item in batch.iter_mut() {
                 let axis_min_content_size = item_sizer.min_content_contribution(item);
                 let space = axis_min_content_size;
-                let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+                let tracks = &mut axis_tracks[item.track_range(axis)];
+                let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
                 if space > 0.0 {
                     distribute_item_space_to_growth_limit(
                         space,
                         tracks,
+                        gutters,
                         has_intrinsic_max_track_sizing_function,
                         inner_node_size.get(axis),
                     );
                 }
             }
             // Mark any tracks whose growth limit changed from infinite to finite in this step as infinitely growable for the next step.
-            flush_planned_growth_limit_increases(axis_tracks, true);
+            flush_planned_growth_limit_increases(axis_tracks, axis_gutters, true);
 
             // 6. For max-content maximums: Lastly continue to increase the growth limit of tracks with a max track sizing function of max-content
             // by distributing extra space as needed to account for these items' max-content contributions. However, limit the growth of any
@@ -878,18 +936,20 @@ fn resolve_intrinsic_track_sizes(
             for item in batch.iter_mut() {
                 let axis_max_content_size = item_sizer.max_content_contribution(item);
                 let space = axis_max_content_size;
-                let tracks = &mut axis_tracks[item.track_range_excluding_lines(axis)];
+                let tracks = &mut axis_tracks[item.track_range(axis)];
+                let gutters = &mut axis_gutters[item.inner_gutter_range(axis)];
                 if space > 0.0 {
                     distribute_item_space_to_growth_limit(
                         space,
                         tracks,
+                        gutters,
                         has_max_content_max_track_sizing_function,
                         inner_node_size.get(axis),
                     );
                 }
             }
             // Mark any tracks whose growth limit changed from infinite to finite in this step as infinitely growable for the next step.
-            flush_planned_growth_limit_increases(axis_tracks, false);
+            flush_planned_growth_limit_increases(axis_tracks, axis_gutters, false);
         }
     }
 
@@ -898,6 +958,7 @@ fn resolve_intrinsic_track_sizes(
     // NOTE: this step is super-important to ensure that the "Maximise Tracks" step doesn't affect flexible tracks
     axis_tracks
         .iter_mut()
+        .chain(axis_gutters.iter_mut())
         .filter(|track| track.growth_limit == f32::INFINITY)
         .for_each(|track| track.growth_limit = track.base_size);
 }
@@ -905,11 +966,13 @@ fn resolve_intrinsic_track_sizes(
 /// 11.5.1. Distributing Extra Space Across Spanned Tracks
 /// https://www.w3.org/TR/css-grid-1/#extra-space
 #[inline(always)]
+#[allow(clippy::too_many_arguments)]
 fn distribute_item_space_to_base_size(
     is_flex: bool,
     use_flex_factor_for_distribution: bool,
     space: f32,
     tracks: &mut [GridTrack],
+    gutters: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
     track_limit: impl Fn(&GridTrack) -> f32,
     intrinsic_contribution_type: IntrinsicContributionType,
@@ -920,6 +983,7 @@ fn distribute_item_space_to_base_size(
             distribute_item_space_to_base_size_inner(
                 space,
                 tracks,
+                gutters,
                 filter,
                 |track| track.flex_factor(),
                 track_limit,
@@ -929,6 +993,7 @@ fn distribute_item_space_to_base_size(
             distribute_item_space_to_base_size_inner(
                 space,
                 tracks,
+                gutters,
                 filter,
                 |_| 1.0,
                 track_limit,
@@ -939,6 +1004,7 @@ fn distribute_item_space_to_base_size(
         distribute_item_space_to_base_size_inner(
             space,
             tracks,
+            gutters,
             track_is_affected,
             |_| 1.0,
             track_limit,
@@ -951,6 +1017,7 @@ fn distribute_item_space_to_base_size(
     fn distribute_item_space_to_base_size_inner(
         space: f32,
         tracks: &mut [GridTrack],
+        gutters: &mut [GridTrack],
         track_is_affected: impl Fn(&GridTrack) -> bool,
         track_distribution_proportion: impl Fn(&GridTrack) -> f32,
         track_limit: impl Fn(&GridTrack) -> f32,
@@ -959,7 +1026,7 @@ fn distribute_item_space_to_base_size(
         // Skip this distribution if there is either
         //   - no space to distribute
         //   - no affected tracks to distribute space to
-        if space == 0.0 || !tracks.iter().any(&track_is_affected) {
+        if space == 0.0 || !tracks.iter().chain(gutters.iter()).any(&track_is_affected) {
             return;
         }
 
@@ -968,7 +1035,7 @@ fn distribute_item_space_to_base_size(
         let get_base_size = |track: &GridTrack| track.base_size;
 
         // 1. Find the space to distribute
-        let track_sizes: f32 = tracks.iter().map(|track| track.base_size).sum();
+        let track_sizes: f32 = tracks.iter().chain(gutters.iter()).map(|track| track.base_size).sum();
         let extra_space: f32 = f32_max(0.0, space - track_sizes);
 
         // 2. Distribute space up to limits:
@@ -983,6 +1050,7 @@ fn distribute_item_space_to_base_size(
         let extra_space = distribute_space_up_to_limits(
             extra_space,
             tracks,
+            gutters,
             &track_is_affected,
             &track_distribution_proportion,
             get_base_size,
@@ -1009,8 +1077,12 @@ fn distribute_item_space_to_base_size(
             };
 
             // If there are no such tracks (matching filter above), then use all affected tracks.
-            let number_of_tracks =
-                tracks.iter().filter(|track| track_is_affected(track)).filter(|track| filter(track)).count();
+            let number_of_tracks = tracks
+                .iter()
+                .chain(gutters.iter())
+                .filter(|track| track_is_affected(track))
+                .filter(|track| filter(track))
+                .count();
             if number_of_tracks == 0 {
                 filter = (|_| true) as fn(&GridTrack) -> bool;
             }
@@ -1018,6 +1090,7 @@ fn distribute_item_space_to_base_size(
             distribute_space_up_to_limits(
                 extra_space,
                 tracks,
+                gutters,
                 filter,
                 &track_distribution_proportion,
                 get_base_size,
@@ -1027,7 +1100,7 @@ fn distribute_item_space_to_base_size(
 
         // 4. For each affected track, if the track’s item-incurred increase is larger than the track’s planned increase
         // set the track’s planned increase to that value.
-        for track in tracks.iter_mut() {
+        for track in tracks.iter_mut().chain(gutters.iter_mut()) {
             if track.item_incurred_increase > track.base_size_planned_increase {
                 track.base_size_planned_increase = track.item_incurred_increase;
             }
@@ -1044,19 +1117,21 @@ fn distribute_item_space_to_base_size(
 fn distribute_item_space_to_growth_limit(
     space: f32,
     tracks: &mut [GridTrack],
+    gutters: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
     axis_inner_node_size: Option<f32>,
 ) {
     // Skip this distribution if there is either
     //   - no space to distribute
     //   - no affected tracks to distribute space to
-    if space == 0.0 || tracks.iter().filter(|track| track_is_affected(track)).count() == 0 {
+    if space == 0.0 || tracks.iter().chain(gutters.iter()).filter(|track| track_is_affected(track)).count() == 0 {
         return;
     }
 
     // 1. Find the space to distribute
     let track_sizes: f32 = tracks
         .iter()
+        .chain(gutters.iter())
         .map(|track| if track.growth_limit == f32::INFINITY { track.base_size } else { track.growth_limit })
         .sum();
     let extra_space: f32 = f32_max(0.0, space - track_sizes);
@@ -1067,6 +1142,7 @@ fn distribute_item_space_to_growth_limit(
     //   - Otherwise no space will be distributed as part of this step
     let number_of_growable_tracks = tracks
         .iter()
+        .chain(gutters.iter())
         .filter(|track| track_is_affected(track))
         .filter(|track| {
             track.infinitely_growable || track.fit_content_limited_growth_limit(axis_inner_node_size) == f32::INFINITY
@@ -1074,9 +1150,12 @@ fn distribute_item_space_to_growth_limit(
         .count();
     if number_of_growable_tracks > 0 {
         let item_incurred_increase = extra_space / number_of_growable_tracks as f32;
-        for track in tracks.iter_mut().filter(|track| track_is_affected(track)).filter(|track| {
-            track.infinitely_growable || track.fit_content_limited_growth_limit(axis_inner_node_size) == f32::INFINITY
-        }) {
+        for track in
+            tracks.iter_mut().chain(gutters.iter_mut()).filter(|track| track_is_affected(track)).filter(|track| {
+                track.infinitely_growable
+                    || track.fit_content_limited_growth_limit(axis_inner_node_size) == f32::INFINITY
+            })
+        {
             track.item_incurred_increase = item_incurred_increase;
         }
     } else {
@@ -1086,6 +1165,7 @@ fn distribute_item_space_to_growth_limit(
         distribute_space_up_to_limits(
             extra_space,
             tracks,
+            gutters,
             track_is_affected,
             |_| 1.0,
             |track| if track.growth_limit == f32::INFINITY { track.base_size } else { track.growth_limit },
@@ -1095,7 +1175,7 @@ fn distribute_item_space_to_growth_limit(
 
     // 4. For each affected track, if the track’s item-incurred increase is larger than the track’s planned increase
     // set the track’s planned increase to that value.
-    for track in tracks.iter_mut() {
+    for track in tracks.iter_mut().chain(gutters.iter_mut()) {
         if track.item_incurred_increase > track.growth_limit_planned_increase {
             track.growth_limit_planned_increase = track.item_incurred_increase;
         }
@@ -1110,23 +1190,25 @@ fn distribute_item_space_to_growth_limit(
 #[inline(always)]
 fn maximise_tracks(
     axis_tracks: &mut [GridTrack],
+    axis_gutters: &mut [GridTrack],
     axis_inner_node_size: Option<f32>,
     axis_available_grid_space: AvailableSpace,
 ) {
-    let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+    let used_space: f32 = axis_tracks.iter().chain(axis_gutters.iter()).map(|track| track.base_size).sum();
     let free_space = axis_available_grid_space.compute_free_space(used_space);
     if free_space == f32::INFINITY {
-        axis_tracks.iter_mut().for_each(|track| track.base_size = track.growth_limit);
+        axis_tracks.iter_mut().chain(axis_gutters.iter_mut()).for_each(|track| track.base_size = track.growth_limit);
     } else if free_space > 0.0 {
         distribute_space_up_to_limits(
             free_space,
             axis_tracks,
+            axis_gutters,
             |_| true,
             |_| 1.0,
             |track| track.base_size,
             move |track: &GridTrack| track.fit_content_limited_growth_limit(axis_inner_node_size),
         );
-        for track in axis_tracks.iter_mut() {
+        for track in axis_tracks.iter_mut().chain(axis_gutters.iter_mut()) {
             track.base_size += track.item_incurred_increase;
             track.item_incurred_increase = 0.0;
         }
@@ -1141,12 +1223,15 @@ fn expand_flexible_tracks(
     tree: &mut impl LayoutPartialTree,
     axis: AbstractAxis,
     axis_tracks: &mut [GridTrack],
+    axis_gutters: &[GridTrack],
     items: &mut [GridItem],
     axis_min_size: Option<f32>,
     axis_max_size: Option<f32>,
     axis_available_space_for_expansion: AvailableSpace,
     inner_node_size: Size<Option<f32>>,
 ) {
+    let gutter_size_sum = sum_gutter_sizes(axis_gutters);
+
     // First, find the grid’s used flex fraction:
     let flex_fraction = match axis_available_space_for_expansion {
         // If the free space is zero:
@@ -1155,12 +1240,12 @@ fn expand_flexible_tracks(
         //   The used flex fraction is the result of finding the size of an fr using all of the grid tracks and
         //   a space to fill of the available grid space.
         AvailableSpace::Definite(available_space) => {
-            let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+            let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum::<f32>() + gutter_size_sum;
             let free_space = available_space - used_space;
             if free_space <= 0.0 {
                 0.0
             } else {
-                find_size_of_fr(axis_tracks, available_space)
+                find_size_of_fr(axis_tracks, gutter_size_sum, available_space)
             }
         }
         // If ... sizing the grid container under a min-content constraint the used flex fraction is zero.
@@ -1190,11 +1275,15 @@ fn expand_flexible_tracks(
                     .iter_mut()
                     .filter(|item| item.crosses_flexible_track(axis))
                     .map(|item| {
-                        let tracks = &axis_tracks[item.track_range_excluding_lines(axis)];
+                        let tracks = &axis_tracks[item.track_range(axis)];
+                        let spanned_gutter_size = sum_gutter_sizes(&axis_gutters[item.inner_gutter_range(axis)]);
                         // TODO: plumb estimate of other axis size (known_dimensions) in here rather than just passing Size::NONE?
+                        let margin_axis_sum =
+                            item.margins_axis_sums_with_baseline_shims(inner_node_size.width).get(axis);
                         let max_content_contribution =
-                            item.max_content_contribution_cached(axis, tree, Size::NONE, inner_node_size);
-                        find_size_of_fr(tracks, max_content_contribution)
+                            item.max_content_contribution_cached(axis, tree, Size::NONE, inner_node_size)
+                                + margin_axis_sum;
+                        find_size_of_fr(tracks, spanned_gutter_size, max_content_contribution)
                     })
                     .max_by(|a, b| a.total_cmp(b))
                     .unwrap_or(0.0),
@@ -1212,13 +1301,14 @@ fn expand_flexible_tracks(
                     }
                     _ => track.base_size,
                 })
-                .sum();
+                .sum::<f32>()
+                + gutter_size_sum;
             let axis_min_size = axis_min_size.unwrap_or(0.0);
             let axis_max_size = axis_max_size.unwrap_or(f32::INFINITY);
             if hypothetical_grid_size < axis_min_size {
-                find_size_of_fr(axis_tracks, axis_min_size)
+                find_size_of_fr(axis_tracks, gutter_size_sum, axis_min_size)
             } else if hypothetical_grid_size > axis_max_size {
-                find_size_of_fr(axis_tracks, axis_max_size)
+                find_size_of_fr(axis_tracks, gutter_size_sum, axis_max_size)
             } else {
                 flex_fraction
             }
@@ -1236,9 +1326,10 @@ fn expand_flexible_tracks(
 
 /// 11.7.1. Find the Size of an fr
 /// This algorithm finds the largest size that an fr unit can be without exceeding the target size.
-/// It must be called with a set of grid tracks and some quantity of space to fill.
+/// It must be called with a set of grid tracks (along with the total size of the gutters between them)
+/// and some quantity of space to fill.
 #[inline(always)]
-fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
+fn find_size_of_fr(tracks: &[GridTrack], gutter_size: f32, space_to_fill: f32) -> f32 {
     // Handle the trivial case where there is no space to fill
     // Do not remove as otherwise the loop below will loop infinitely
     if space_to_fill == 0.0 {
@@ -1249,13 +1340,17 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
     // is less than the track’s base size, then we must restart this algorithm treating all such tracks as inflexible.
     // We therefore wrap the entire algorithm in a loop, with an hypotherical_fr_size of INFINITY such that the above
     // condition can never be true for the first iteration.
+    //
+    // Each restart treats at least one more track as inflexible, so the loop can run at most once per track (plus one).
+    // The iteration count is also capped explicitly so that non-finite values (which fail every comparison) can't hang layout.
     let mut hypothetical_fr_size = f32::INFINITY;
     let mut previous_iter_hypothetical_fr_size;
+    let mut remaining_iterations = tracks.len() + 1;
     loop {
         // Let leftover space be the space to fill minus the base sizes of the non-flexible grid tracks.
         // Let flex factor sum be the sum of the flex factors of the flexible tracks. If this value is less than 1, set it to 1 instead.
         // We compute both of these in a single loop to avoid iterating over the data twice
-        let mut used_space = 0.0;
+        let mut used_space = gutter_size;
         let mut naive_flex_factor_sum = 0.0;
         for track in tracks.iter() {
             match track.max_track_sizing_function {
@@ -1288,6 +1383,12 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
         if hypotherical_fr_size_is_valid {
             break;
         }
+
+        remaining_iterations -= 1;
+        if remaining_iterations == 0 {
+            debug_log!("Finding the size of an fr did not converge");
+            break;
+        }
     }
 
     // Return the hypothetical fr size.
@@ -1299,13 +1400,15 @@ fn find_size_of_fr(tracks: &[GridTrack], space_to_fill: f32) -> f32 {
 #[inline(always)]
 fn stretch_auto_tracks(
     axis_tracks: &mut [GridTrack],
+    axis_gutters: &[GridTrack],
     axis_min_size: Option<f32>,
     axis_available_space_for_expansion: AvailableSpace,
 ) {
     let num_auto_tracks =
         axis_tracks.iter().filter(|track| track.max_track_sizing_function == MaxTrackSizingFunction::Auto).count();
     if num_auto_tracks > 0 {
-        let used_space: f32 = axis_tracks.iter().map(|track| track.base_size).sum();
+        let used_space: f32 =
+            axis_tracks.iter().map(|track| track.base_size).sum::<f32>() + sum_gutter_sizes(axis_gutters);
 
         // If the free space is indefinite, but the grid container has a definite min-width/height
         // use that size to calculate the free space for this step instead.
@@ -1333,6 +1436,7 @@ fn stretch_auto_tracks(
 fn distribute_space_up_to_limits(
     space_to_distribute: f32,
     tracks: &mut [GridTrack],
+    gutters: &mut [GridTrack],
     track_is_affected: impl Fn(&GridTrack) -> bool,
     track_distribution_proportion: impl Fn(&GridTrack) -> f32,
     track_affected_property: impl Fn(&GridTrack) -> f32,
@@ -1342,10 +1446,22 @@ fn distribute_space_up_to_limits(
     /// extra space when it gets to exactly zero, we will stop when it falls below this amount
     const THRESHOLD: f32 = 0.000001;
 
+    // Each iteration brings at least one track up to its limit (or within rounding error of it, in which case the next
+    // iteration will finish the job), so at most two iterations per track are needed. We cap the iteration count
+    // explicitly so that non-finite values can't cause an infinite loop.
+    let mut remaining_iterations = 2 * (tracks.len() + gutters.len()) + 1;
+
     let mut space_to_distribute = space_to_distribute;
     while space_to_distribute > THRESHOLD {
+        if remaining_iterations == 0 {
+            debug_log!("Distributing space to grid tracks did not converge");
+            break;
+        }
+        remaining_iterations -= 1;
+
         let track_distribution_proportion_sum: f32 = tracks
             .iter()
+            .chain(gutters.iter())
             .filter(|track| track_affected_property(track) + track.item_incurred_increase < track_limit(track))
             .filter(|track| track_is_affected(track))
             .map(&track_distribution_proportion)
@@ -1358,6 +1474,7 @@ fn distribute_space_up_to_limits(
         // Compute item-incurred increase for this iteration
         let min_increase_limit = tracks
             .iter()
+            .chain(gutters.iter())
             .filter(|track| track_affected_property(track) + track.item_incurred_increase < track_limit(track))
             .filter(|track| track_is_affected(track))
             .map(|track| (track_limit(track) - track_affected_property(track)) / track_distribution_proportion(track))
@@ -1366,7 +1483,7 @@ fn distribute_space_up_to_limits(
         let iteration_item_incurred_increase =
             f32_min(min_increase_limit, space_to_distribute / track_distribution_proportion_sum);
 
-        for track in tracks.iter_mut().filter(|track| track_is_affected(track)) {
+        for track in tracks.iter_mut().chain(gutters.iter_mut()).filter(|track| track_is_affected(track)) {
             let increase = iteration_item_incurred_increase * track_distribution_proportion(track);
             if increase > 0.0 && track_affected_property(track) + increase <= track_limit(track) {
                 track.item_incurred_increase += increase;
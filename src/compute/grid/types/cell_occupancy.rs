@@ -4,10 +4,11 @@ use crate::compute::grid::OriginZeroLine;
 use crate::geometry::AbsoluteAxis;
 use crate::geometry::Line;
 use crate::util::sys::Vec;
-use core::cmp::{max, min};
+use core::cmp::max;
 use core::fmt::Debug;
+use core::iter;
 use core::ops::Range;
-use grid::Grid;
+use grid::{Grid, Order};
 
 /// The occupancy state of a single grid cell
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
@@ -23,6 +24,11 @@ pub(crate) enum CellOccupancyState {
 
 /// A dynamically sized matrix (2d grid) which tracks the occupancy of each grid cell during auto-placement
 /// It also keeps tabs on how many tracks there are and which tracks are implicit and which are explicit.
+///
+/// The cells of each track in the secondary axis of the auto-flow are stored contiguously, so that growing the
+/// implicit grid in that axis (which is what auto-placing many items typically does) is an amortized append. For
+/// each such track the matrix also maintains the index of its first unoccupied cell, which allows the auto-placement
+/// cursor to skip over runs of occupied cells (and entirely full tracks) rather than testing them one at a time.
 pub(crate) struct CellOccupancyMatrix {
     /// The grid of occupancy states
     inner: Grid<CellOccupancyState>,
@@ -30,6 +36,13 @@ pub(crate) struct CellOccupancyMatrix {
     columns: TrackCounts,
     /// The counts of implicit and explicit rows
     rows: TrackCounts,
+    /// The primary axis of the grid's auto-flow. This is the axis along which the auto-placement cursor moves.
+    primary_axis: AbsoluteAxis,
+    /// For each secondary axis track, the index of the first unoccupied cell within that track (or the number
+    /// of primary axis tracks if the track is full). Every cell before this index is occupied.
+    first_unoccupied: Vec<u16>,
+    /// The index of the first secondary axis track which is not full. Every track before this index is full.
+    first_non_full_track: u16,
 }
 
 /// Debug impl that represents the matrix in a compact 2d text format
@@ -66,8 +79,21 @@ impl Debug for CellOccupancyMatrix {
 impl CellOccupancyMatrix {
     /// Create a CellOccupancyMatrix given a set of provisional track counts. The grid can expand as needed to fit more tracks,
     /// the provisional track counts represent a best effort attempt to avoid the extra allocations this requires.
-    pub fn with_track_counts(columns: TrackCounts, rows: TrackCounts) -> Self {
-        Self { inner: Grid::new(rows.len(), columns.len()), rows, columns }
+    pub fn with_track_counts(columns: TrackCounts, rows: TrackCounts, primary_axis: AbsoluteAxis) -> Self {
+        let inner = Self::new_inner_grid(rows.len(), columns.len(), primary_axis);
+        let mut matrix =
+            Self { inner, rows, columns, primary_axis, first_unoccupied: Vec::new(), first_non_full_track: 0 };
+        matrix.reset_first_unoccupied_indexes();
+        matrix
+    }
+
+    /// Create an unoccupied grid with a memory layout in which the cells of each secondary axis track are contiguous
+    fn new_inner_grid(row_count: usize, col_count: usize, primary_axis: AbsoluteAxis) -> Grid<CellOccupancyState> {
+        let order = match primary_axis {
+            AbsoluteAxis::Horizontal => Order::RowMajor,
+            AbsoluteAxis::Vertical => Order::ColumnMajor,
+        };
+        Grid::new_with_order(row_count, col_count, order)
     }
 
     /// Determines whether the specified area fits within the tracks currently represented by the matrix
@@ -90,50 +116,53 @@ impl CellOccupancyMatrix {
     /// Expands the grid (potentially in all 4 directions) in order to ensure that the specified range fits within the allocated space
     fn expand_to_fit_range(&mut self, row_range: Range<i16>, col_range: Range<i16>) {
         // Calculate number of rows and columns missing to accomodate ranges (if any)
-        let req_negative_rows = min(row_range.start, 0);
-        let req_positive_rows = max(row_range.end - self.rows.len() as i16, 0);
-        let req_negative_cols = min(col_range.start, 0);
-        let req_positive_cols = max(col_range.end - self.columns.len() as i16, 0);
-
-        let old_row_count = self.rows.len();
-        let old_col_count = self.columns.len();
-        let new_row_count = old_row_count + (req_negative_rows + req_positive_rows) as usize;
-        let new_col_count = old_col_count + (req_negative_cols + req_positive_cols) as usize;
-
-        let mut data = Vec::with_capacity(new_row_count * new_col_count);
+        let req_negative_rows = max(-row_range.start, 0) as u16;
+        let req_positive_rows = max(row_range.end - self.rows.len() as i16, 0) as u16;
+        let req_negative_cols = max(-col_range.start, 0) as u16;
+        let req_positive_cols = max(col_range.end - self.columns.len() as i16, 0) as u16;
 
-        // Push new negative rows
-        for _ in 0..(req_negative_rows as usize * new_col_count) {
-            data.push(CellOccupancyState::Unoccupied);
-        }
-
-        // Push existing rows
-        for row in 0..old_row_count {
-            // Push new negative columns
-            for _ in 0..req_negative_cols {
-                data.push(CellOccupancyState::Unoccupied);
+        let (only_appending_secondary_tracks, req_positive_secondary) = match self.primary_axis {
+            AbsoluteAxis::Horizontal => {
+                (req_negative_rows + req_negative_cols + req_positive_cols == 0, req_positive_rows)
             }
-            // Push existing columns
-            for col in 0..old_col_count {
-                data.push(*self.inner.get(row, col).unwrap());
+            AbsoluteAxis::Vertical => {
+                (req_negative_cols + req_negative_rows + req_positive_rows == 0, req_positive_cols)
             }
-            // Push new positive columns
-            for _ in 0..req_positive_cols {
-                data.push(CellOccupancyState::Unoccupied);
+        };
+
+        if only_appending_secondary_tracks && !self.inner.is_empty() {
+            // Cells are stored secondary track by secondary track, so new tracks can simply be pushed onto the end
+            let primary_track_count = self.primary_track_count();
+            for _ in 0..req_positive_secondary {
+                let track: Vec<_> = iter::repeat(CellOccupancyState::Unoccupied).take(primary_track_count).collect();
+                match self.primary_axis {
+                    AbsoluteAxis::Horizontal => self.inner.push_row(track),
+                    AbsoluteAxis::Vertical => self.inner.push_col(track),
+                }
+            }
+            self.first_unoccupied.resize(self.first_unoccupied.len() + req_positive_secondary as usize, 0);
+        } else {
+            // Otherwise copy the occupied cells into a new grid, offset by the number of newly added negative tracks
+            let new_row_count = self.rows.len() + (req_negative_rows + req_positive_rows) as usize;
+            let new_col_count = self.columns.len() + (req_negative_cols + req_positive_cols) as usize;
+            let mut inner = Self::new_inner_grid(new_row_count, new_col_count, self.primary_axis);
+            for ((row, col), cell) in self.inner.indexed_iter() {
+                if *cell != CellOccupancyState::Unoccupied {
+                    *inner.get_mut(row + req_negative_rows as usize, col + req_negative_cols as usize).unwrap() = *cell;
+                }
             }
+            self.inner = inner;
         }
 
-        // Push new negative rows
-        for _ in 0..(req_positive_rows as usize * new_col_count) {
-            data.push(CellOccupancyState::Unoccupied);
-        }
+        // Update track counts
+        self.rows.negative_implicit += req_negative_rows;
+        self.rows.positive_implicit += req_positive_rows;
+        self.columns.negative_implicit += req_negative_cols;
+        self.columns.positive_implicit += req_positive_cols;
 
-        // Update self with new data
-        self.inner = Grid::from_vec(data, new_col_count);
-        self.rows.negative_implicit += req_negative_rows as u16;
-        self.rows.positive_implicit += req_positive_rows as u16;
-        self.columns.negative_implicit += req_negative_cols as u16;
-        self.columns.positive_implicit += req_positive_cols as u16;
+        if !only_appending_secondary_tracks {
+            self.reset_first_unoccupied_indexes();
+        }
     }
 
     /// Mark an area of the matrix as occupied, expanding the allocated space as necessary to accomodate the passed area.
@@ -161,11 +190,17 @@ impl CellOccupancyMatrix {
             row_range = self.rows.oz_line_range_to_track_range(row_span);
         }
 
-        for x in row_range {
+        for x in row_range.clone() {
             for y in col_range.clone() {
                 *self.inner.get_mut(x as usize, y as usize).unwrap() = value;
             }
         }
+
+        let secondary_range = match self.primary_axis {
+            AbsoluteAxis::Horizontal => row_range,
+            AbsoluteAxis::Vertical => col_range,
+        };
+        self.advance_first_unoccupied_indexes(secondary_range.start as usize..secondary_range.end as usize);
     }
 
     /// Determines whether a grid area specified by the bounding grid lines in OriginZero coordinates
@@ -208,13 +243,21 @@ impl CellOccupancyMatrix {
     }
 
     /// Determines whether the specified row contains any items
+    ///
+    /// Note: the matrix has no cells at all if the grid has no tracks in the other axis, in which case the row is
+    /// unoccupied.
     pub fn row_is_occupied(&self, row_index: usize) -> bool {
-        self.inner.iter_row(row_index).any(|cell| !matches!(cell, CellOccupancyState::Unoccupied))
+        row_index < self.inner.rows()
+            && self.inner.iter_row(row_index).any(|cell| !matches!(cell, CellOccupancyState::Unoccupied))
     }
 
     /// Determines whether the specified column contains any items
+    ///
+    /// Note: the matrix has no cells at all if the grid has no tracks in the other axis, in which case the column is
+    /// unoccupied.
     pub fn column_is_occupied(&self, column_index: usize) -> bool {
-        self.inner.iter_col(column_index).any(|cell| !matches!(cell, CellOccupancyState::Unoccupied))
+        column_index < self.inner.cols()
+            && self.inner.iter_col(column_index).any(|cell| !matches!(cell, CellOccupancyState::Unoccupied))
     }
 
     /// Returns the track counts of this CellOccunpancyMatrix in the relevant axis
@@ -248,4 +291,68 @@ impl CellOccupancyMatrix {
 
         maybe_index.map(|idx| track_counts.track_to_prev_oz_line(idx as u16))
     }
+
+    /// Returns the grid line (in the auto-flow's primary axis) that precedes the first unoccupied cell of the specified
+    /// secondary axis track, or the end line of the grid if the track is full. Any grid area in this track which starts
+    /// before the returned line is guaranteed to overlap an occupied cell.
+    ///
+    /// Returns None if the track is outside of the tracks currently represented by the matrix (and therefore entirely unoccupied).
+    pub fn first_unoccupied_primary_line(&self, secondary_track_start: OriginZeroLine) -> Option<OriginZeroLine> {
+        let secondary_index =
+            self.track_counts(self.primary_axis.other_axis()).oz_line_to_next_track(secondary_track_start);
+        if secondary_index < 0 {
+            return None;
+        }
+        let first_unoccupied = *self.first_unoccupied.get(secondary_index as usize)?;
+        Some(self.track_counts(self.primary_axis).track_to_prev_oz_line(first_unoccupied))
+    }
+
+    /// Returns the grid line (in the auto-flow's secondary axis) that precedes the first secondary axis track which
+    /// is not completely occupied. Any grid area which starts in a track before the returned line is guaranteed
+    /// to overlap an occupied cell.
+    pub fn first_non_full_secondary_line(&self) -> OriginZeroLine {
+        self.track_counts(self.primary_axis.other_axis()).track_to_prev_oz_line(self.first_non_full_track)
+    }
+
+    /// The number of tracks in the auto-flow's primary axis
+    fn primary_track_count(&self) -> usize {
+        self.track_counts(self.primary_axis).len()
+    }
+
+    /// Recompute the first unoccupied cell of every secondary axis track from scratch
+    fn reset_first_unoccupied_indexes(&mut self) {
+        let secondary_track_count = self.track_counts(self.primary_axis.other_axis()).len();
+        self.first_unoccupied.clear();
+        self.first_unoccupied.resize(secondary_track_count, 0);
+        self.first_non_full_track = 0;
+        self.advance_first_unoccupied_indexes(0..secondary_track_count);
+    }
+
+    /// Move the first unoccupied cell of the specified secondary axis tracks past any cells that have since been occupied.
+    /// As cells only ever go from unoccupied to occupied, the total work done by this method is linear in the number of cells.
+    fn advance_first_unoccupied_indexes(&mut self, secondary_range: Range<usize>) {
+        let primary_track_count = self.primary_track_count();
+        for secondary_index in secondary_range {
+            let first_unoccupied = self.first_unoccupied[secondary_index] as usize;
+            if first_unoccupied >= primary_track_count {
+                continue;
+            }
+            let cells = match self.primary_axis {
+                AbsoluteAxis::Horizontal => self.inner.iter_row(secondary_index),
+                AbsoluteAxis::Vertical => self.inner.iter_col(secondary_index),
+            };
+            let occupied_count = cells
+                .skip(first_unoccupied)
+                .position(|cell| *cell == CellOccupancyState::Unoccupied)
+                .unwrap_or(primary_track_count - first_unoccupied);
+            self.first_unoccupied[secondary_index] = (first_unoccupied + occupied_count) as u16;
+        }
+
+        while matches!(
+            self.first_unoccupied.get(self.first_non_full_track as usize),
+            Some(&first_unoccupied) if first_unoccupied as usize == primary_track_count
+        ) {
+            self.first_non_full_track += 1;
+        }
+    }
 }
@@ -30,7 +30,11 @@ impl GridLine {
     }
 
     /// Convert into OriginZero coordinates using the specified explicit track count
-    pub(crate) fn into_origin_zero_line(self, explicit_track_count: u16) -> OriginZeroLine {
+    ///
+    /// # Panics
+    ///
+    /// Panics if the line is `0`, which is not a valid CSS grid line
+    pub fn into_origin_zero_line(self, explicit_track_count: u16) -> OriginZeroLine {
         let explicit_line_count = explicit_track_count + 1;
         let oz_line = match self.0.cmp(&0) {
             Ordering::Greater => self.0 - 1,
@@ -85,8 +89,26 @@ impl Sub<u16> for OriginZeroLine {
 }
 
 impl OriginZeroLine {
-    /// Converts a grid line in OriginZero coordinates into the index of that same grid line in the GridTrackVec.
-    pub(crate) fn into_track_vec_index(self, track_counts: TrackCounts) -> usize {
+    /// Convert into "CSS Grid Line" coordinates using the specified explicit track count
+    ///
+    /// Lines within or after the explicit grid are converted into positive lines, and lines before the explicit grid
+    /// are converted into negative lines. This is the inverse of [`GridLine::into_origin_zero_line`] except that
+    /// a negative line within the explicit grid (such as `-1`) is converted back into its positive equivalent.
+    pub fn into_grid_line(self, explicit_track_count: u16) -> GridLine {
+        if self.0 >= 0 {
+            GridLine(self.0 + 1)
+        } else {
+            GridLine(self.0 - (explicit_track_count as i16 + 1))
+        }
+    }
+
+    /// Converts a grid line in OriginZero coordinates into the index of that same grid line in the grid's vector of
+    /// gutters. This is also the index (in the grid's vector of tracks) of the track that immediately follows the line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the line is outside of the implicit grid described by `track_counts`
+    pub fn into_track_vec_index(self, track_counts: TrackCounts) -> usize {
         assert!(
             self.0 >= -(track_counts.negative_implicit as i16),
             "OriginZero grid line cannot be less than the number of negative grid lines"
@@ -95,7 +117,7 @@ impl OriginZeroLine {
             self.0 <= (track_counts.explicit + track_counts.positive_implicit) as i16,
             "OriginZero grid line cannot be more than the number of positive grid lines"
         );
-        2 * ((self.0 + track_counts.negative_implicit as i16) as usize)
+        (self.0 + track_counts.negative_implicit as i16) as usize
     }
 
     /// The minimum number of negative implicit track there must be if a grid item starts at this line.
@@ -128,3 +150,52 @@ impl Line<OriginZeroLine> {
 pub trait GridCoordinate: Copy {}
 impl GridCoordinate for GridLine {}
 impl GridCoordinate for OriginZeroLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::{GridLine, OriginZeroLine};
+    use crate::compute::grid::TrackCounts;
+
+    #[test]
+    fn grid_line_to_origin_zero_line() {
+        // 3 explicit tracks have 4 explicit lines: 1, 2, 3, 4 (or -4, -3, -2, -1)
+        for (css_line, expected) in [(1, 0), (4, 3), (5, 4), (-1, 3), (-4, 0), (-5, -1), (-7, -3)] {
+            assert_eq!(GridLine::from(css_line).into_origin_zero_line(3), OriginZeroLine(expected), "{css_line}");
+        }
+    }
+
+    #[test]
+    fn origin_zero_line_to_grid_line() {
+        for (oz_line, expected) in [(0, 1), (3, 4), (4, 5), (-1, -5), (-3, -7)] {
+            assert_eq!(OriginZeroLine(oz_line).into_grid_line(3), GridLine::from(expected), "{oz_line}");
+        }
+
+        // Round trips through OriginZero coordinates preserve lines outside of the explicit grid, and positive lines
+        for css_line in [-7, -5, 1, 2, 4, 5, 10] {
+            let oz_line = GridLine::from(css_line).into_origin_zero_line(3);
+            assert_eq!(oz_line.into_grid_line(3), GridLine::from(css_line));
+        }
+    }
+
+    #[test]
+    fn origin_zero_line_to_track_vec_index() {
+        let track_counts = TrackCounts::from_raw(2, 3, 1);
+        assert_eq!(OriginZeroLine(-2).into_track_vec_index(track_counts), 0);
+        assert_eq!(OriginZeroLine(0).into_track_vec_index(track_counts), 2);
+        assert_eq!(OriginZeroLine(4).into_track_vec_index(track_counts), 6);
+        assert_eq!(track_counts.oz_line_to_next_track(OriginZeroLine(0)), 2);
+        assert_eq!(track_counts.track_to_prev_oz_line(2), OriginZeroLine(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn origin_zero_line_before_implicit_grid_has_no_track_vec_index() {
+        OriginZeroLine(-3).into_track_vec_index(TrackCounts::from_raw(2, 3, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_line_zero_is_invalid() {
+        GridLine::from(0).into_origin_zero_line(3);
+    }
+}
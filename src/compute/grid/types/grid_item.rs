@@ -8,6 +8,7 @@ use crate::style::{
     MinTrackSizingFunction, Overflow, Style,
 };
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::util::sys::f32_max;
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 use core::ops::Range;
 
@@ -53,10 +54,10 @@ pub(in super::super) struct GridItem {
     pub baseline_shim: f32,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
-    /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
+    /// (as indexes into the grid's vector of gutters. The start index is also the index of the item's first row)
     pub row_indexes: Line<u16>,
     /// The items definite column-start and column-end (same as `column` field, except in a different coordinate system)
-    /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
+    /// (as indexes into the grid's vector of gutters. The start index is also the index of the item's first column)
     pub column_indexes: Line<u16>,
 
     /// Whether the item crosses a flexible row
@@ -133,7 +134,7 @@ impl GridItem {
         }
     }
 
-    /// This item's placement in the specified axis as GridTrackVec indices
+    /// This item's placement in the specified axis as grid line indices
     pub fn placement_indexes(&self, axis: AbstractAxis) -> Line<u16> {
         match axis {
             AbstractAxis::Block => self.row_indexes,
@@ -141,14 +142,32 @@ impl GridItem {
         }
     }
 
-    /// Returns a range which can be used as an index into the GridTrackVec in the specified axis
-    /// which will produce a sub-slice of covering all the tracks and lines that this item spans
-    /// excluding the lines that bound it.
-    pub fn track_range_excluding_lines(&self, axis: AbstractAxis) -> Range<usize> {
+    /// Returns a range which can be used as an index into the Vec<GridTrack> in the specified axis
+    /// which will produce a sub-slice covering all the tracks that this item spans
+    pub fn track_range(&self, axis: AbstractAxis) -> Range<usize> {
+        let indexes = self.placement_indexes(axis);
+        (indexes.start as usize)..(indexes.end as usize)
+    }
+
+    /// Returns a range which can be used as an index into the vector of gutters in the specified axis
+    /// which will produce a sub-slice covering all the gutters that this item spans, excluding the
+    /// lines that bound it.
+    pub fn inner_gutter_range(&self, axis: AbstractAxis) -> Range<usize> {
         let indexes = self.placement_indexes(axis);
         (indexes.start as usize + 1)..(indexes.end as usize)
     }
 
+    /// Returns an iterator over the tracks that this item spans in the specified axis, followed by
+    /// the gutters between those tracks
+    pub fn spanned_tracks_and_gutters<'a>(
+        &self,
+        axis: AbstractAxis,
+        axis_tracks: &'a [GridTrack],
+        axis_gutters: &'a [GridTrack],
+    ) -> impl Iterator<Item = &'a GridTrack> {
+        axis_tracks[self.track_range(axis)].iter().chain(axis_gutters[self.inner_gutter_range(axis)].iter())
+    }
+
     /// Returns the number of tracks that this item spans in the specified axis
     pub fn span(&self, axis: AbstractAxis) -> u16 {
         match axis {
@@ -176,20 +195,21 @@ impl GridItem {
     }
 
     /// For an item spanning multiple tracks, the upper limit used to calculate its limited min-/max-content contribution is the
-    /// sum of the fixed max track sizing functions of any tracks it spans, and is applied if it only spans such tracks.
+    /// sum of the fixed max track sizing functions of any tracks (and gutters) it spans, and is applied if it only spans such
+    /// tracks.
     pub fn spanned_track_limit(
         &mut self,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
+        axis_gutters: &[GridTrack],
         axis_parent_size: Option<f32>,
     ) -> Option<f32> {
-        let spanned_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
-        let tracks_all_fixed = spanned_tracks
-            .iter()
+        let tracks_all_fixed = self
+            .spanned_tracks_and_gutters(axis, axis_tracks, axis_gutters)
             .all(|track| track.max_track_sizing_function.definite_limit(axis_parent_size).is_some());
         if tracks_all_fixed {
-            let limit: f32 = spanned_tracks
-                .iter()
+            let limit: f32 = self
+                .spanned_tracks_and_gutters(axis, axis_tracks, axis_gutters)
                 .map(|track| track.max_track_sizing_function.definite_limit(axis_parent_size).unwrap())
                 .sum();
             Some(limit)
@@ -204,15 +224,15 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
+        axis_gutters: &[GridTrack],
         axis_parent_size: Option<f32>,
     ) -> Option<f32> {
-        let spanned_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
-        let tracks_all_fixed = spanned_tracks
-            .iter()
+        let tracks_all_fixed = self
+            .spanned_tracks_and_gutters(axis, axis_tracks, axis_gutters)
             .all(|track| track.max_track_sizing_function.definite_value(axis_parent_size).is_some());
         if tracks_all_fixed {
-            let limit: f32 = spanned_tracks
-                .iter()
+            let limit: f32 = self
+                .spanned_tracks_and_gutters(axis, axis_tracks, axis_gutters)
                 .map(|track| track.max_track_sizing_function.definite_value(axis_parent_size).unwrap())
                 .sum();
             Some(limit)
@@ -232,9 +252,13 @@ impl GridItem {
         let margins = self.margins_axis_sums_with_baseline_shims(inner_node_size.width);
 
         let aspect_ratio = self.aspect_ratio;
-        let inherent_size = self.size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let min_size = self.min_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
-        let max_size = self.max_size.maybe_resolve(grid_area_size).maybe_apply_aspect_ratio(aspect_ratio);
+        let raw_size = self.size.maybe_resolve(grid_area_size);
+        let (min_size, max_size) = raw_size.maybe_resolve_min_max_with_aspect_ratio(
+            self.min_size.maybe_resolve(grid_area_size),
+            self.max_size.maybe_resolve(grid_area_size),
+            aspect_ratio,
+        );
+        let inherent_size = raw_size.maybe_apply_aspect_ratio(aspect_ratio);
 
         let grid_area_minus_item_margins_size = grid_area_size.maybe_sub(margins);
 
@@ -289,12 +313,12 @@ impl GridItem {
         &self,
         axis: AbstractAxis,
         other_axis_tracks: &[GridTrack],
+        other_axis_gutters: &[GridTrack],
         other_axis_available_space: Option<f32>,
         get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
     ) -> Size<Option<f32>> {
         let item_other_axis_size: Option<f32> = {
-            other_axis_tracks[self.track_range_excluding_lines(axis.other())]
-                .iter()
+            self.spanned_tracks_and_gutters(axis.other(), other_axis_tracks, other_axis_gutters)
                 .map(|track| {
                     get_track_size_estimate(track, other_axis_available_space)
                         .map(|size| size + track.content_alignment_adjustment)
@@ -312,12 +336,18 @@ impl GridItem {
         &mut self,
         axis: AbstractAxis,
         other_axis_tracks: &[GridTrack],
+        other_axis_gutters: &[GridTrack],
         other_axis_available_space: Option<f32>,
         get_track_size_estimate: impl Fn(&GridTrack, Option<f32>) -> Option<f32>,
     ) -> Size<Option<f32>> {
         self.available_space_cache.unwrap_or_else(|| {
-            let available_spaces =
-                self.available_space(axis, other_axis_tracks, other_axis_available_space, get_track_size_estimate);
+            let available_spaces = self.available_space(
+                axis,
+                other_axis_tracks,
+                other_axis_gutters,
+                other_axis_available_space,
+                get_track_size_estimate,
+            );
             self.available_space_cache = Some(available_spaces);
             available_spaces
         })
@@ -426,6 +456,7 @@ impl GridItem {
         tree: &mut impl LayoutPartialTree,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
+        axis_gutters: &[GridTrack],
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
@@ -443,20 +474,20 @@ impl GridItem {
 
                 // To provide a more reasonable default minimum size for grid items, the used value of its automatic minimum size
                 // in a given axis is the content-based minimum size if all of the following are true:
-                let item_axis_tracks = &axis_tracks[self.track_range_excluding_lines(axis)];
+                let item_axis_tracks = &axis_tracks[self.track_range(axis)];
 
                 // it is not a scroll container
                 // TODO: support overflow propety
 
                 // it spans at least one track in that axis whose min track sizing function is auto
-                let spans_auto_min_track = axis_tracks
+                let spans_auto_min_track = item_axis_tracks
                     .iter()
                     // TODO: should this be 'behaves as auto' rather than just literal auto?
                     .any(|track| track.min_track_sizing_function == MinTrackSizingFunction::Auto);
 
                 // if it spans more than one track in that axis, none of those tracks are flexible
                 let only_span_one_track = item_axis_tracks.len() == 1;
-                let spans_a_flexible_track = axis_tracks
+                let spans_a_flexible_track = item_axis_tracks
                     .iter()
                     .any(|track| matches!(track.max_track_sizing_function, MaxTrackSizingFunction::Fraction(_)));
 
@@ -472,9 +503,14 @@ impl GridItem {
             });
 
         // In all cases, the size suggestion is additionally clamped by the maximum size in the affected axis, if it’s definite.
+        // The limit is the stretch fit into the spanned tracks, so it excludes the item's margins (which are added to
+        // this size to give the item's outer contribution).
         // Note: The argument to fit-content() does not clamp the content-based minimum size in the same way as a fixed max track
         // sizing function.
-        let limit = self.spanned_fixed_track_limit(axis, axis_tracks, inner_node_size.get(axis));
+        let margin_axis_sum = self.margins_axis_sums_with_baseline_shims(inner_node_size.width).get(axis);
+        let limit = self
+            .spanned_fixed_track_limit(axis, axis_tracks, axis_gutters, inner_node_size.get(axis))
+            .map(|limit| f32_max(limit - margin_axis_sum, 0.0));
         size.maybe_min(limit)
     }
 
@@ -485,11 +521,13 @@ impl GridItem {
         tree: &mut impl LayoutPartialTree,
         axis: AbstractAxis,
         axis_tracks: &[GridTrack],
+        axis_gutters: &[GridTrack],
         known_dimensions: Size<Option<f32>>,
         inner_node_size: Size<Option<f32>>,
     ) -> f32 {
         self.minimum_contribution_cache.get(axis).unwrap_or_else(|| {
-            let size = self.minimum_contribution(tree, axis, axis_tracks, known_dimensions, inner_node_size);
+            let size =
+                self.minimum_contribution(tree, axis, axis_tracks, axis_gutters, known_dimensions, inner_node_size);
             self.minimum_contribution_cache.set(axis, Some(size));
             size
         })
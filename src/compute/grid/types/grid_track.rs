@@ -1,29 +1,26 @@
 //! Contains GridTrack used to represent a single grid track (row/column) during layout
 use crate::{
-    style::{LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction},
-    util::sys::f32_min,
+    style::{LengthPercentage, MaxTrackSizingFunction, MinTrackSizingFunction, MAX_SIZE},
+    util::{sanitize_factor, sys::f32_min},
 };
 
-/// Whether a GridTrack represents an actual track or a gutter.
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub(in super::super) enum GridTrackKind {
-    /// Track is an actual track
-    Track,
-    /// Track is a gutter (aka grid line) (aka gap)
-    Gutter, // { name: Option<u16> },
+/// Sanitize a length or percentage used in a track sizing function. Returns `None` if the value is `NaN`
+/// (in which case the sizing function is treated as `auto`), and clamps infinite values to [`MAX_SIZE`].
+fn sanitize_length_percentage(value: LengthPercentage) -> Option<LengthPercentage> {
+    let clamp = |value: f32| if value.is_nan() { None } else { Some(value.clamp(-MAX_SIZE, MAX_SIZE)) };
+    match value {
+        LengthPercentage::Length(length) => clamp(length).map(LengthPercentage::Length),
+        LengthPercentage::Percent(percent) => clamp(percent).map(LengthPercentage::Percent),
+    }
 }
 
 /// Internal sizing information for a single grid track (row/column)
-/// Gutters between tracks are sized similarly to actual tracks, so they
-/// are also represented by this struct
+/// Gutters between tracks are sized similarly to actual tracks, so they are also represented by this struct
+/// (although they are stored separately from the tracks)
 #[derive(Debug, Clone)]
 pub(in super::super) struct GridTrack {
-    #[allow(dead_code)] // Used in tests + may be useful in future
-    /// Whether the track is a full track, a gutter, or a placeholder that has not yet been initialised
-    pub kind: GridTrackKind,
-
     /// Whether the track is a collapsed track/gutter. Collapsed tracks are effectively treated as if
-    /// they don't exist for the purposes of grid sizing. Gutters between collapsed tracks are also collapsed.
+    /// they don't exist for the purposes of grid sizing. Gutters following collapsed tracks are also collapsed.
     pub is_collapsed: bool,
 
     /// The minimum track sizing function of the track
@@ -58,14 +55,44 @@ pub(in super::super) struct GridTrack {
 }
 
 impl GridTrack {
-    /// GridTrack constructor with all configuration parameters for the other constructors exposed
-    fn new_with_kind(
-        kind: GridTrackKind,
+    /// Create new GridTrack representing an actual track (not a gutter)
+    pub fn new(
+        min_track_sizing_function: MinTrackSizingFunction,
+        max_track_sizing_function: MaxTrackSizingFunction,
+    ) -> GridTrack {
+        let min_track_sizing_function = match min_track_sizing_function {
+            MinTrackSizingFunction::Fixed(value) => {
+                sanitize_length_percentage(value).map_or(MinTrackSizingFunction::Auto, MinTrackSizingFunction::Fixed)
+            }
+            other => other,
+        };
+        let max_track_sizing_function = match max_track_sizing_function {
+            MaxTrackSizingFunction::Fixed(value) => {
+                sanitize_length_percentage(value).map_or(MaxTrackSizingFunction::Auto, MaxTrackSizingFunction::Fixed)
+            }
+            MaxTrackSizingFunction::FitContent(value) => sanitize_length_percentage(value)
+                .map_or(MaxTrackSizingFunction::Auto, MaxTrackSizingFunction::FitContent),
+            MaxTrackSizingFunction::Fraction(flex_factor) => {
+                MaxTrackSizingFunction::Fraction(sanitize_factor(flex_factor))
+            }
+            other => other,
+        };
+
+        Self::new_unsanitized(min_track_sizing_function, max_track_sizing_function)
+    }
+
+    /// Create a new GridTrack representing a gutter. A `NaN` gap is treated as zero.
+    pub fn gutter(size: LengthPercentage) -> GridTrack {
+        let size = sanitize_length_percentage(size).unwrap_or(LengthPercentage::Length(0.0));
+        Self::new_unsanitized(MinTrackSizingFunction::Fixed(size), MaxTrackSizingFunction::Fixed(size))
+    }
+
+    /// GridTrack constructor which uses the passed sizing functions as-is
+    fn new_unsanitized(
         min_track_sizing_function: MinTrackSizingFunction,
         max_track_sizing_function: MaxTrackSizingFunction,
     ) -> GridTrack {
         GridTrack {
-            kind,
             is_collapsed: false,
             min_track_sizing_function,
             max_track_sizing_function,
@@ -80,23 +107,6 @@ impl GridTrack {
         }
     }
 
-    /// Create new GridTrack representing an actual track (not a gutter)
-    pub fn new(
-        min_track_sizing_function: MinTrackSizingFunction,
-        max_track_sizing_function: MaxTrackSizingFunction,
-    ) -> GridTrack {
-        Self::new_with_kind(GridTrackKind::Track, min_track_sizing_function, max_track_sizing_function)
-    }
-
-    /// Create a new GridTrack representing a gutter
-    pub fn gutter(size: LengthPercentage) -> GridTrack {
-        Self::new_with_kind(
-            GridTrackKind::Gutter,
-            MinTrackSizingFunction::Fixed(size),
-            MaxTrackSizingFunction::Fixed(size),
-        )
-    }
-
     /// Mark a GridTrack as collapsed. Also sets both of the track's sizing functions
     /// to fixed zero-sized sizing functions.
     pub fn collapse(&mut self) {
@@ -25,12 +25,10 @@
 //!       - 0 is the leftmost track of the implicit grid, and indexes count up there
 //!
 //!   "GridTrackVec track indices":
-//!       - The GridTrackVecs store both lines and tracks, so:
-//!           - even indices (0, 2, 4, etc) represent lines
-//!           - odd indices (1, 3, 5, etc) represent tracks
-//!           - These is always an odd number of
-//!       - Index 1 is the leftmost track of the implict grid. Index 3 is the second leftmost track, etc.
-//!       - Index 0 is the leftmost grid line. Index 2 is the second leftmost line, etc.
+//!       - Tracks and lines (gutters) are stored in separate vectors, with one more line than there are tracks
+//!       - Index 0 is the leftmost track of the implict grid. Index 1 is the second leftmost track, etc.
+//!       - Index 0 is the leftmost grid line. Index 1 is the second leftmost line, etc.
+//!       - So the line at index `i` immediately precedes the track at index `i`
 //!
 use crate::{compute::grid::OriginZeroLine, geometry::Line};
 use core::ops::Range;
@@ -38,7 +36,7 @@ use core::ops::Range;
 /// Stores the number of tracks in a given dimension.
 /// Stores seperately the number of tracks in the implicit and explicit grids
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
-pub(crate) struct TrackCounts {
+pub struct TrackCounts {
     /// The number of track in the implicit grid before the explicit grid
     pub negative_implicit: u16,
     /// The number of tracks in the explicit grid
@@ -58,6 +56,11 @@ impl TrackCounts {
         (self.negative_implicit + self.explicit + self.positive_implicit) as usize
     }
 
+    /// Whether there are no tracks in the axis
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// The OriginZeroLine representing the start of the implicit grid
     pub fn implicit_start_line(&self) -> OriginZeroLine {
         OriginZeroLine(-(self.negative_implicit as i16))
@@ -69,8 +72,8 @@ impl TrackCounts {
     }
 }
 
-/// Conversion functions between OriginZero coordinates and CellOccupancyMatrix track indexes
-#[allow(dead_code)]
+/// Conversion functions between OriginZero coordinates and track indexes (which are also the indexes of tracks in the
+/// CellOccupancyMatrix)
 impl TrackCounts {
     /// Converts a grid line in OriginZero coordinates into the track immediately
     /// following that grid line as an index into the CellOccupancyMatrix.
@@ -7,13 +7,11 @@ mod grid_track_counts;
 
 // Publish only locally in the grid module
 pub(super) use cell_occupancy::{CellOccupancyMatrix, CellOccupancyState};
-pub(crate) use coordinates::{GridCoordinate, GridLine, OriginZeroLine};
+pub(crate) use coordinates::GridCoordinate;
+pub use coordinates::{GridLine, OriginZeroLine};
 pub(super) use grid_item::GridItem;
 pub(super) use grid_track::GridTrack;
-pub(super) use grid_track_counts::TrackCounts;
-
-#[allow(unused_imports)]
-pub(super) use grid_track::GridTrackKind;
+pub use grid_track_counts::TrackCounts;
 
 // pub(super) enum GridPosition {
 //     Auto,
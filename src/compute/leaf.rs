@@ -4,13 +4,28 @@ use crate::geometry::{Point, Size};
 use crate::style::{AvailableSpace, Display, Overflow, Position, Style};
 use crate::tree::{CollapsibleMarginSet, RunMode};
 use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
-use crate::util::debug::debug_log;
+use crate::util::debug::{debug_log, profile_algorithm, profile_count};
 use crate::util::sys::f32_max;
 use crate::util::MaybeMath;
-use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::util::{sanitize_aspect_ratio, MaybeResolve, ResolveOrZero};
 use core::unreachable;
 
 /// Compute the size of a leaf node (node with no children)
+///
+/// The size of each axis is resolved independently, with the following precedence:
+///
+///   1. A dimension that is known to the parent (`known_dimensions`) is always used as-is
+///   2. Otherwise the node's style `size` is used. A missing style size is derived from the other axis's style size if
+///      the node has an `aspect_ratio`.
+///   3. Otherwise the size is the measured size of the node's content plus its padding, border and scrollbar gutter
+///
+/// The result is then clamped by the node's `min_size` and `max_size`, and floored at the size of its padding and
+/// border.
+///
+/// The measure function is passed content-box sizes: any dimensions known from steps 1 and 2 (clamped by the min and
+/// max sizes) with the padding, border and scrollbar gutter subtracted, so that it only has to fill in the unknown
+/// axes. When performing final layout no dimensions are passed as known, and the node's content is measured within
+/// the available space.
 pub fn compute_leaf_layout<MeasureFunction>(
     inputs: LayoutInput,
     style: &Style,
@@ -19,25 +34,35 @@ pub fn compute_leaf_layout<MeasureFunction>(
 where
     MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
 {
+    profile_algorithm!(Leaf);
     let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, run_mode, .. } = inputs;
 
     // Resolve node's preferred/min/max sizes (width/heights) against the available space (percentages resolve to pixel values)
     // For ContentSize mode, we pretend that the node has no size styles as these should be ignored.
-    let (node_size, node_min_size, node_max_size, aspect_ratio) = match sizing_mode {
+    //
+    // The node's own max height is kept separately from the max height transferred through the aspect ratio, as the
+    // latter should not truncate the node's content (the automatic minimum size of a box with an aspect ratio is its
+    // content size)
+    let (node_size, node_min_size, node_max_size, style_max_height, aspect_ratio) = match sizing_mode {
         SizingMode::ContentSize => {
             let node_size = known_dimensions;
             let node_min_size = Size::NONE;
             let node_max_size = Size::NONE;
-            (node_size, node_min_size, node_max_size, None)
+            (node_size, node_min_size, node_max_size, None, None)
         }
         SizingMode::InherentSize => {
-            let aspect_ratio = style.aspect_ratio;
-            let style_size = style.size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-            let style_min_size = style.min_size.maybe_resolve(parent_size).maybe_apply_aspect_ratio(aspect_ratio);
-            let style_max_size = style.max_size.maybe_resolve(parent_size);
+            let aspect_ratio = sanitize_aspect_ratio(style.aspect_ratio);
+            let raw_style_size = style.size.maybe_resolve(parent_size);
+            let raw_style_max_size = style.max_size.maybe_resolve(parent_size);
+            let (style_min_size, style_max_size) = raw_style_size.maybe_resolve_min_max_with_aspect_ratio(
+                style.min_size.maybe_resolve(parent_size),
+                raw_style_max_size,
+                aspect_ratio,
+            );
+            let style_size = raw_style_size.maybe_apply_aspect_ratio(aspect_ratio);
 
             let node_size = known_dimensions.or(style_size);
-            (node_size, style_min_size, style_max_size, aspect_ratio)
+            (node_size, style_min_size, style_max_size, raw_style_max_size.height, aspect_ratio)
         }
     };
 
@@ -74,6 +99,13 @@ where
         || border.top > 0.0
         || border.bottom > 0.0;
 
+    // The first baseline of the node, if it has line metrics
+    let first_baseline = style
+        .line_metrics
+        .map(|metrics| padding.top + border.top + metrics.baseline())
+        .filter(|baseline| baseline.is_finite());
+    let first_baselines = Point { x: None, y: first_baseline };
+
     debug_log!("LEAF");
     debug_log!("node_size", dbg:node_size);
     debug_log!("min_size ", dbg:node_min_size);
@@ -89,7 +121,7 @@ where
                 size,
                 #[cfg(feature = "content_size")]
                 content_size: Size::ZERO,
-                first_baselines: Point::NONE,
+                first_baselines,
                 top_margin: CollapsibleMarginSet::ZERO,
                 bottom_margin: CollapsibleMarginSet::ZERO,
                 margins_can_collapse_through: false,
@@ -108,7 +140,11 @@ where
             .maybe_set(node_size.width)
             .maybe_set(node_max_size.width)
             .map_definite_value(|size| {
-                size.maybe_clamp(node_min_size.width, node_max_size.width) - content_box_inset.horizontal_axis_sum()
+                f32_max(
+                    size.maybe_clamp(node_min_size.width, node_max_size.width)
+                        - content_box_inset.horizontal_axis_sum(),
+                    0.0,
+                )
             }),
         height: known_dimensions
             .height
@@ -119,26 +155,42 @@ where
             .maybe_set(node_size.height)
             .maybe_set(node_max_size.height)
             .map_definite_value(|size| {
-                size.maybe_clamp(node_min_size.height, node_max_size.height) - content_box_inset.vertical_axis_sum()
+                f32_max(
+                    size.maybe_clamp(node_min_size.height, node_max_size.height)
+                        - content_box_inset.vertical_axis_sum(),
+                    0.0,
+                )
             }),
     };
 
-    // Measure node
-    let measured_size = measure_function(
-        match run_mode {
-            RunMode::ComputeSize => known_dimensions,
+    // Measure node. A node with size containment is sized as if it had no content (or as if its content were the size
+    // specified by its `contain_intrinsic_size`), so it is not measured.
+    let measured_size = if style.contain_size {
+        style.contain_intrinsic_size.map(|size| size.unwrap_or(0.0))
+    } else {
+        profile_count!(measure_function_calls);
+        let content_box_known_dimensions = match run_mode {
+            RunMode::ComputeSize => node_size
+                .maybe_clamp(node_min_size, node_max_size)
+                .maybe_sub(content_box_inset.sum_axes())
+                .map(|size| size.map(|size| f32_max(size, 0.0))),
             RunMode::PerformLayout => Size::NONE,
             RunMode::PerformHiddenLayout => unreachable!(),
-        },
-        available_space,
-    );
+        };
+        measure_function(content_box_known_dimensions, available_space)
+    };
     let clamped_size = known_dimensions
         .or(node_size)
         .unwrap_or(measured_size + content_box_inset.sum_axes())
-        .maybe_clamp(node_min_size, node_max_size);
+        .maybe_clamp(node_min_size, Size { width: node_max_size.width, height: style_max_height });
     let size = Size {
         width: clamped_size.width,
-        height: f32_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
+        height: f32_max(
+            clamped_size.height,
+            aspect_ratio
+                .map(|ratio| (clamped_size.width / ratio).maybe_clamp(node_min_size.height, node_max_size.height))
+                .unwrap_or(0.0),
+        ),
     };
     let size = size.maybe_max(padding_border.sum_axes().map(Some));
 
@@ -146,7 +198,7 @@ where
         size,
         #[cfg(feature = "content_size")]
         content_size: measured_size + padding.sum_axes(),
-        first_baselines: Point::NONE,
+        first_baselines,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
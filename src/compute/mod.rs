@@ -25,26 +25,41 @@ pub use self::block::compute_block_layout;
 pub use self::flexbox::compute_flexbox_layout;
 
 #[cfg(feature = "grid")]
-pub use self::grid::compute_grid_layout;
+pub use self::grid::{compute_grid_layout, try_compute_grid_layout, GridTrackLimitExceeded};
 
-use crate::geometry::{Line, Point, Size};
+use crate::geometry::{AbsoluteAxis, Line, Point, Size};
 use crate::style::{AvailableSpace, Overflow};
 use crate::tree::{
     Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
 };
-use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node};
+use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node, profile_count};
 use crate::util::sys::round;
 use crate::util::ResolveOrZero;
 
 /// Compute layout for the root node in the tree
 pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, available_space: Size<AvailableSpace>) {
-    // Recursively compute node layout
-    let output = tree.perform_child_layout(
+    // Compute the width of the root node. If the root's width is determined by its content then percentages which
+    // resolve against it are treated as auto in this pass.
+    let width = tree.measure_child_size(
         root,
         Size::NONE,
         available_space.into_options(),
         available_space,
         SizingMode::InherentSize,
+        AbsoluteAxis::Horizontal,
+        Line::FALSE,
+    );
+
+    // Recursively compute node layout. As with any other node (whose width is passed down by its parent in the final
+    // layout pass) the root's width is now definite, so percentages resolve against it. The root's height is still
+    // indefinite if it is determined by its content, and percentages which resolve against it continue to be treated
+    // as auto (matching browsers).
+    let output = tree.perform_child_layout(
+        root,
+        Size { width: Some(width), height: None },
+        available_space.into_options(),
+        available_space,
+        SizingMode::InherentSize,
         Line::FALSE,
     );
 
@@ -90,12 +105,14 @@ where
     // First we check if we have a cached result for the given input
     let cache_entry = tree.get_cache_mut(node).get(known_dimensions, available_space, run_mode);
     if let Some(cached_size_and_baselines) = cache_entry {
+        profile_count!(cache_hits);
         debug_log!("CACHE", dbg:cached_size_and_baselines.size);
         debug_log_node!(known_dimensions, parent_size, available_space, run_mode, sizing_mode);
         debug_pop_node!();
         return cached_size_and_baselines;
     }
 
+    profile_count!(cache_misses);
     let computed_size_and_baselines = compute_uncached(tree, node, inputs);
 
     // Cache result
@@ -119,18 +136,28 @@ where
 /// In order to prevent innacuracies caused by rounding already-rounded values, we read from `unrounded_layout`
 /// and write to `final_layout`.
 pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
-    return round_layout_inner(tree, node_id, 0.0, 0.0);
+    round_subtree_layout(tree, node_id, Point::ZERO)
+}
+
+/// Rounds the calculated layout of `node_id` and its descendants in the same way as [`round_layout`], where `origin` is
+/// the unrounded position of `node_id`'s parent relative to the node that the layout was rounded from
+pub(crate) fn round_subtree_layout(tree: &mut impl RoundTree, node_id: NodeId, origin: Point<f32>) {
+    return round_layout_inner(tree, node_id, origin.x, origin.y);
 
     /// Recursive function to apply rounding to all descendents
     fn round_layout_inner(tree: &mut impl RoundTree, node_id: NodeId, cumulative_x: f32, cumulative_y: f32) {
         let unrounded_layout = *tree.get_unrounded_layout(node_id);
         let mut layout = unrounded_layout;
 
+        // The location is the difference between the rounded positions of the node and its parent, so that a node's
+        // rounded edges always line up with those of its parent (e.g. end-aligned content ends exactly at its
+        // container's end edge) and of its siblings
+        layout.location.x = round(cumulative_x + unrounded_layout.location.x) - round(cumulative_x);
+        layout.location.y = round(cumulative_y + unrounded_layout.location.y) - round(cumulative_y);
+
         let cumulative_x = cumulative_x + unrounded_layout.location.x;
         let cumulative_y = cumulative_y + unrounded_layout.location.y;
 
-        layout.location.x = round(unrounded_layout.location.x);
-        layout.location.y = round(unrounded_layout.location.y);
         layout.size.width = round(cumulative_x + unrounded_layout.size.width) - round(cumulative_x);
         layout.size.height = round(cumulative_y + unrounded_layout.size.height) - round(cumulative_y);
         layout.scrollbar_size.width = round(unrounded_layout.scrollbar_size.width);
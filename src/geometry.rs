@@ -1,6 +1,7 @@
 //! Geometric primitives useful for layout
 
 use crate::util::sys::f32_max;
+use crate::util::{sanitize_aspect_ratio, MaybeMath};
 use crate::{style::Dimension, util::sys::f32_min};
 use core::ops::{Add, Sub};
 
@@ -139,6 +140,36 @@ impl<U, T: Add<U>> Add<Rect<U>> for Rect<T> {
 }
 
 impl<T> Rect<T> {
+    /// Creates a `Rect` with the same value for all four sides
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let padding: Rect<LengthPercentage> = Rect::all(length(10.0));
+    /// assert_eq!(padding, Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) });
+    /// ```
+    pub const fn all(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Rect { left: value, right: value, top: value, bottom: value }
+    }
+
+    /// Creates a `Rect` with the value `horizontal` for the left and right sides, and the value `vertical` for the top
+    /// and bottom sides (like the two value form of the CSS `margin` and `padding` shorthands, but in the reverse
+    /// order)
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let margin: Rect<LengthPercentageAuto> = Rect::horizontal_vertical(auto(), length(8.0));
+    /// assert_eq!(margin, Rect { left: auto(), right: auto(), top: length(8.0), bottom: length(8.0) });
+    /// ```
+    pub const fn horizontal_vertical(horizontal: T, vertical: T) -> Self
+    where
+        T: Copy,
+    {
+        Rect { left: horizontal, right: horizontal, top: vertical, bottom: vertical }
+    }
+
     /// Applies the function `f` to all four sides of the rect
     ///
     /// When applied to the left and right sides, the width is used
@@ -161,6 +192,12 @@ impl<T> Rect<T> {
     /// Applies the function `f` to the left, right, top, and bottom properties
     ///
     /// This is used to transform a `Rect<T>` into a `Rect<R>`.
+    ///
+    /// ```rust
+    /// # use taffy::geometry::Rect;
+    /// let rect = Rect { left: 1.0, right: 2.0, top: 3.0, bottom: 4.0 };
+    /// assert_eq!(rect.map(|side| side * 2.0), Rect { left: 2.0, right: 4.0, top: 6.0, bottom: 8.0 });
+    /// ```
     pub fn map<R, F>(self, f: F) -> Rect<R>
     where
         F: Fn(T) -> R,
@@ -168,6 +205,21 @@ impl<T> Rect<T> {
         Rect { left: f(self.left), right: f(self.right), top: f(self.top), bottom: f(self.bottom) }
     }
 
+    /// Applies the function `f` to each side of this rect and the corresponding side of another rect
+    ///
+    /// This is used to combine a `Rect<T>` and a `Rect<Other>` into a `Rect<Ret>`.
+    pub fn zip_map<Other, Ret, Func>(self, other: Rect<Other>, f: Func) -> Rect<Ret>
+    where
+        Func: Fn(T, Other) -> Ret,
+    {
+        Rect {
+            left: f(self.left, other.left),
+            right: f(self.right, other.right),
+            top: f(self.top, other.top),
+            bottom: f(self.bottom, other.bottom),
+        }
+    }
+
     /// Returns a `Line<T>` representing the left and right properties of the Rect
     pub fn horizontal_components(self) -> Line<T> {
         Line { start: self.left, end: self.right }
@@ -366,9 +418,28 @@ impl<U, T: Sub<U>> Sub<Size<U>> for Size<T> {
 // but sometimes we only currently have a use for the helper in a single axis
 #[allow(dead_code)]
 impl<T> Size<T> {
+    /// Creates a `Size` with the same value for both the width and height
+    ///
+    /// ```rust
+    /// # use taffy::geometry::Size;
+    /// assert_eq!(Size::both(10.0), Size { width: 10.0, height: 10.0 });
+    /// ```
+    pub fn both(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Size { width: value.clone(), height: value }
+    }
+
     /// Applies the function `f` to both the width and height
     ///
     /// This is used to transform a `Size<T>` into a `Size<R>`.
+    ///
+    /// ```rust
+    /// # use taffy::geometry::Size;
+    /// let size = Size { width: 10.4, height: 20.6 };
+    /// assert_eq!(size.map(f32::round), Size { width: 10.0, height: 21.0 });
+    /// ```
     pub fn map<R, F>(self, f: F) -> Size<R>
     where
         F: Fn(T) -> R,
@@ -394,6 +465,14 @@ impl<T> Size<T> {
 
     /// Applies the function `f` to both the width and height
     /// of this value and another passed value
+    ///
+    /// ```rust
+    /// # use taffy::geometry::Size;
+    /// let known_dimensions = Size { width: Some(50.0), height: None };
+    /// let measured = Size { width: 80.0, height: 30.0 };
+    /// let size = known_dimensions.zip_map(measured, |known, measured| known.unwrap_or(measured));
+    /// assert_eq!(size, Size { width: 50.0, height: 30.0 });
+    /// ```
     pub fn zip_map<Other, Ret, Func>(self, other: Size<Other>, f: Func) -> Size<Ret>
     where
         Func: Fn(T, Other) -> Ret,
@@ -565,7 +644,7 @@ impl Size<Option<f32>> {
     ///
     /// If aspect_ratio is `None` then this function simply returns self.
     pub fn maybe_apply_aspect_ratio(self, aspect_ratio: Option<f32>) -> Size<Option<f32>> {
-        match aspect_ratio {
+        match sanitize_aspect_ratio(aspect_ratio) {
             Some(ratio) => match (self.width, self.height) {
                 (Some(width), None) => Size { width: Some(width), height: Some(width / ratio) },
                 (None, Some(height)) => Size { width: Some(height * ratio), height: Some(height) },
@@ -574,6 +653,61 @@ impl Size<Option<f32>> {
             None => self,
         }
     }
+
+    /// Resolves the effective min and max size constraints of a box whose preferred size is `self` and
+    /// which has the supplied aspect_ratio. Returns a `(min_size, max_size)` tuple.
+    ///
+    /// If the preferred size is `Some` in either axis then the constraints are transferred through the
+    /// aspect_ratio as with [`Size::maybe_apply_aspect_ratio`].
+    ///
+    /// If the preferred size is `None` in both axes then the size of the box is determined by its aspect_ratio,
+    /// so the constraints of each axis are combined with those transferred from the other axis. Clamping a size
+    /// that respects the aspect_ratio to the returned constraints then implements the
+    /// [CSS 2 table for violated constraints](https://www.w3.org/TR/CSS22/visudet.html#min-max-widths): the
+    /// aspect_ratio is preserved where possible, and a min size wins over a conflicting max size.
+    pub(crate) fn maybe_resolve_min_max_with_aspect_ratio(
+        self,
+        min_size: Size<Option<f32>>,
+        max_size: Size<Option<f32>>,
+        aspect_ratio: Option<f32>,
+    ) -> (Size<Option<f32>>, Size<Option<f32>>) {
+        let ratio = match sanitize_aspect_ratio(aspect_ratio) {
+            Some(ratio) if self.width.is_none() && self.height.is_none() => ratio,
+            _ => {
+                return (
+                    min_size.maybe_apply_aspect_ratio(aspect_ratio),
+                    max_size.maybe_apply_aspect_ratio(aspect_ratio),
+                )
+            }
+        };
+
+        // A max size is never smaller than the min size in the same axis
+        let max_size = Size {
+            width: max_size.width.map(|max| max.maybe_max(min_size.width)),
+            height: max_size.height.map(|max| max.maybe_max(min_size.height)),
+        };
+
+        let tightest_min = |own: Option<f32>, transferred: Option<f32>| match (own, transferred) {
+            (Some(own), Some(transferred)) => Some(f32_max(own, transferred)),
+            (own, transferred) => own.or(transferred),
+        };
+        let tightest_max = |own: Option<f32>, transferred: Option<f32>| match (own, transferred) {
+            (Some(own), Some(transferred)) => Some(f32_min(own, transferred)),
+            (own, transferred) => own.or(transferred),
+        };
+
+        // Constraints transferred from the other axis may not override the constraints of the axis itself
+        let min = Size {
+            width: tightest_min(min_size.width, min_size.height.map(|height| height * ratio)).maybe_min(max_size.width),
+            height: tightest_min(min_size.height, min_size.width.map(|width| width / ratio)).maybe_min(max_size.height),
+        };
+        let max = Size {
+            width: tightest_max(max_size.width, max_size.height.map(|height| height * ratio)).maybe_max(min_size.width),
+            height: tightest_max(max_size.height, max_size.width.map(|width| width / ratio)).maybe_max(min_size.height),
+        };
+
+        (min, max)
+    }
 }
 
 impl<T> Size<Option<T>> {
@@ -643,6 +777,12 @@ impl<T> Point<T> {
     /// Applies the function `f` to both the x and y
     ///
     /// This is used to transform a `Point<T>` into a `Point<R>`.
+    ///
+    /// ```rust
+    /// # use taffy::geometry::Point;
+    /// let point = Point { x: 1.5, y: -2.5 };
+    /// assert_eq!(point.map(f32::abs), Point { x: 1.5, y: 2.5 });
+    /// ```
     pub fn map<R, F>(self, f: F) -> Point<R>
     where
         F: Fn(T) -> R,
@@ -650,6 +790,14 @@ impl<T> Point<T> {
         Point { x: f(self.x), y: f(self.y) }
     }
 
+    /// Applies the function `f` to both the x and y of this point and another passed point
+    pub fn zip_map<Other, Ret, Func>(self, other: Point<Other>, f: Func) -> Point<Ret>
+    where
+        Func: Fn(T, Other) -> Ret,
+    {
+        Point { x: f(self.x, other.x), y: f(self.y, other.y) }
+    }
+
     /// Gets the extent of the specified layout axis
     /// Whether this is the width or height depends on the `GridAxis` provided
     #[cfg(feature = "grid")]
@@ -0,0 +1,29 @@
+//! Conversions between the coordinate systems that Taffy uses to refer to grid lines and tracks
+//!
+//! These are the same conversions that Taffy uses when placing grid items, so tooling that interprets grid placements
+//! (such as a CSS parser or a devtools overlay) can use them to agree with Taffy's interpretation exactly.
+//!
+//!   - [`GridLine`] is a line in "CSS Grid Line" coordinates, as used by the `grid-row` and `grid-column` properties.
+//!     The line at the start edge of the explicit grid is line `1`, and lines count up from there. The line at the end
+//!     edge of the explicit grid is line `-1`, and lines count down from there. `0` is not a valid line.
+//!   - [`OriginZeroLine`] is a line in "OriginZero" coordinates. The line at the start edge of the explicit grid is
+//!     line `0`. Lines after it count up from there, and lines before it (in the implicit grid) count down from there.
+//!   - Track indexes index the tracks of the entire implicit grid (as described by [`TrackCounts`]). The track at the
+//!     start edge of the implicit grid has index `0`, and the line at index `i` immediately precedes the track at index
+//!     `i`.
+//!
+//! ```rust
+//! use taffy::grid_lines::{GridLine, OriginZeroLine, TrackCounts};
+//!
+//! // A grid with 3 explicit tracks, 1 implicit track before them and 2 implicit tracks after them
+//! let track_counts = TrackCounts { negative_implicit: 1, explicit: 3, positive_implicit: 2 };
+//!
+//! // Line -1 is the line at the end edge of the explicit grid
+//! let line = GridLine::from(-1).into_origin_zero_line(track_counts.explicit);
+//! assert_eq!(line, OriginZeroLine(3));
+//! assert_eq!(line.into_grid_line(track_counts.explicit), GridLine::from(4));
+//!
+//! // It precedes the first implicit track after the explicit grid
+//! assert_eq!(line.into_track_vec_index(track_counts), 4);
+//! ```
+pub use crate::compute::grid::{GridLine, OriginZeroLine, TrackCounts};
@@ -51,10 +51,16 @@
 //! When using this API, you must handle node storage, caching, and dispatching to the correct layout algorithm for a given node yourself.
 //! See the [`crate::tree::traits`] module for more details on this API.
 //!
+//! If your nodes are already stored in another data structure (such as a `petgraph` graph) and you only need Taffy's
+//! default dispatch between layout algorithms, the `ClosureTree` adaptor (enabled by the `closure_tree` feature)
+//! implements these traits on top of closures which return the style and children of a node, and stores the caches
+//! and layouts itself.
+//!
 //! Examples which show usage of the high-level API are:
 //!
 //!   - [custom_layout_tree_vec](https://github.com/DioxusLabs/taffy/blob/main/examples/custom_layout_tree_vec.rs) which implements a custom Taffy tree using a `Vec` as an arena with NodeId's being index's into the Vec.
 //!   - [custom_layout_tree_owned](https://github.com/DioxusLabs/taffy/blob/main/examples/custom_layout_tree_owned.rs) which implements a custom Taffy tree using directly owned children with NodeId's being pointers.
+//!   - [closure_tree](https://github.com/DioxusLabs/taffy/blob/main/examples/closure_tree.rs) which lays out a `Vec`-backed tree owned by another library using the `ClosureTree` adaptor.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_code)]
@@ -68,7 +74,9 @@
 #[macro_use]
 extern crate std;
 
-#[cfg(all(not(feature = "std"), feature = "alloc"))]
+// The `TaffyTree` is backed by `slotmap` which always requires an allocator, so `alloc` is also used for its storage
+// when neither `std` nor `alloc` are enabled
+#[cfg(all(not(feature = "std"), any(feature = "alloc", feature = "taffy_tree")))]
 extern crate alloc;
 
 #[cfg_attr(feature = "serde", macro_use)]
@@ -77,6 +85,8 @@ extern crate serde;
 
 pub mod compute;
 pub mod geometry;
+#[cfg(feature = "grid")]
+pub mod grid_lines;
 pub mod prelude;
 pub mod style;
 pub mod style_helpers;
@@ -94,13 +104,13 @@ pub use crate::compute::compute_block_layout;
 #[cfg(feature = "flexbox")]
 #[doc(inline)]
 pub use crate::compute::compute_flexbox_layout;
-#[cfg(feature = "grid")]
-#[doc(inline)]
-pub use crate::compute::compute_grid_layout;
 #[doc(inline)]
 pub use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
 };
+#[cfg(feature = "grid")]
+#[doc(inline)]
+pub use crate::compute::{compute_grid_layout, try_compute_grid_layout};
 #[doc(inline)]
 pub use crate::style::Style;
 #[doc(inline)]
@@ -1,14 +1,14 @@
 //! Commonly used types
 
 pub use crate::{
-    geometry::{Line, Rect, Size},
+    geometry::{Line, MinMax, Rect, Size},
     style::{
         AlignContent, AlignItems, AlignSelf, AvailableSpace, Dimension, Display, JustifyContent, JustifyItems,
         JustifySelf, LengthPercentage, LengthPercentageAuto, Position, Style,
     },
     style_helpers::{
         auto, fit_content, length, max_content, min_content, percent, zero, FromFlex, FromLength, FromPercent,
-        TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
+        TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyNumber, TaffyZero,
     },
     tree::{Layout, LayoutPartialTree, NodeId, PrintTree, RoundTree, TraversePartialTree, TraverseTree},
 };
@@ -74,10 +74,28 @@ pub enum AlignContent {
     FlexEnd,
     /// Items are centered around the middle of the axis
     Center,
+    /// Items are packed toward the end of the axis, unless they overflow the container in which case they are packed
+    /// toward the start of the axis (the CSS `safe end` value)
+    ///
+    /// The other values are "unsafe": they align the items as specified even if this causes them to overflow the start
+    /// edge of the container (where they may be clipped or unreachable by scrolling).
+    SafeEnd,
+    /// Items are packed towards the flex-relative end of the axis, unless they overflow the container in which case they
+    /// are packed toward the start of the axis (the CSS `safe flex-end` value)
+    SafeFlexEnd,
+    /// Items are centered around the middle of the axis, unless they overflow the container in which case they are
+    /// packed toward the start of the axis (the CSS `safe center` value)
+    SafeCenter,
     /// Items are stretched to fill the container
+    ///
+    /// For flexbox, any free space in the cross axis is divided equally between the flex lines. For grid, it is
+    /// divided equally between the tracks with an `auto` max track sizing function.
     Stretch,
     /// The first and last items are aligned flush with the edges of the container (no gap)
     /// The gap between items is distributed evenly.
+    ///
+    /// Any free space is added to the `gap` between items, so this can be used in place of an "auto" gap
+    /// (which CSS does not support), with the `gap` style acting as the minimum gap between items.
     SpaceBetween,
     /// The gap between the first and last items is exactly THE SAME as the gap between items.
     /// The gaps are distributed evenly
@@ -1,9 +1,19 @@
 //! Style types for representing lengths / sizes
 
 use crate::geometry::{Rect, Size};
-use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyMaxContent, TaffyMinContent, TaffyZero};
+use crate::style_helpers::{
+    FromLength, FromPercent, TaffyAuto, TaffyMaxContent, TaffyMinContent, TaffyNumber, TaffyZero,
+};
+use crate::util::sanitize_length;
 use crate::util::sys::abs;
 
+/// The largest length that Taffy will use for a style value.
+///
+/// Infinite lengths, gaps and flex factors in a [`Style`](crate::style::Style) are clamped to (plus or minus) this
+/// value when they are resolved, and `NaN` lengths are treated as `auto` (or zero where `auto` is not allowed).
+/// This keeps a single bad input from propagating `NaN` through the rest of the tree or stalling layout.
+pub const MAX_SIZE: f32 = 1.0e9;
+
 /// A unit of linear measurement
 ///
 /// This is commonly combined with [`Rect`], [`Point`](crate::geometry::Point) and [`Size<T>`].
@@ -20,13 +30,72 @@ impl TaffyZero for LengthPercentage {
     const ZERO: Self = Self::Length(0.0);
 }
 impl FromLength for LengthPercentage {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Self::Length(value.into())
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Self::Length(value.into_f32())
     }
 }
 impl FromPercent for LengthPercentage {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Percent(percent.into())
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Self::Percent(percent.into_f32())
+    }
+}
+
+impl TryFrom<LengthPercentageAuto> for LengthPercentage {
+    /// The value that could not be converted (`LengthPercentageAuto::Auto`)
+    type Error = LengthPercentageAuto;
+
+    fn try_from(input: LengthPercentageAuto) -> Result<Self, Self::Error> {
+        match input {
+            LengthPercentageAuto::Length(value) => Ok(Self::Length(value)),
+            LengthPercentageAuto::Percent(value) => Ok(Self::Percent(value)),
+            LengthPercentageAuto::Auto => Err(input),
+        }
+    }
+}
+
+impl TryFrom<Dimension> for LengthPercentage {
+    /// The value that could not be converted (`Dimension::Auto` or `Dimension::Content`)
+    type Error = Dimension;
+
+    fn try_from(input: Dimension) -> Result<Self, Self::Error> {
+        match input {
+            Dimension::Length(value) => Ok(Self::Length(value)),
+            Dimension::Percent(value) => Ok(Self::Percent(value)),
+            Dimension::Auto | Dimension::Content => Err(input),
+        }
+    }
+}
+
+impl LengthPercentage {
+    /// An absolute length in some abstract units. Usable in `const` contexts.
+    #[must_use]
+    pub const fn length(value: f32) -> Self {
+        Self::Length(value)
+    }
+
+    /// A percentage length relative to the size of the containing block. Usable in `const` contexts.
+    ///
+    /// **NOTE: percentages are represented as a f32 value in the range [0.0, 1.0] NOT the range [0.0, 100.0]**
+    #[must_use]
+    pub const fn percent(value: f32) -> Self {
+        Self::Percent(value)
+    }
+
+    /// Returns true if the value is a zero length or a zero percentage
+    #[inline(always)]
+    pub fn is_zero(self) -> bool {
+        matches!(self, Self::Length(value) | Self::Percent(value) if value == 0.0)
+    }
+
+    /// Applies the function `f` to the value of `Length` variants, leaving other variants unchanged
+    ///
+    /// For example, `length.map_length(|value| value * scale_factor)` scales absolute lengths but not percentages.
+    #[must_use]
+    pub fn map_length(self, f: impl FnOnce(f32) -> f32) -> Self {
+        match self {
+            Self::Length(value) => Self::Length(f(value)),
+            _ => self,
+        }
     }
 }
 
@@ -51,13 +120,13 @@ impl TaffyAuto for LengthPercentageAuto {
     const AUTO: Self = Self::Auto;
 }
 impl FromLength for LengthPercentageAuto {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Self::Length(value.into())
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Self::Length(value.into_f32())
     }
 }
 impl FromPercent for LengthPercentageAuto {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Percent(percent.into())
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Self::Percent(percent.into_f32())
     }
 }
 
@@ -70,7 +139,41 @@ impl From<LengthPercentage> for LengthPercentageAuto {
     }
 }
 
+impl TryFrom<Dimension> for LengthPercentageAuto {
+    /// The value that could not be converted (`Dimension::Content`)
+    type Error = Dimension;
+
+    fn try_from(input: Dimension) -> Result<Self, Self::Error> {
+        match input {
+            Dimension::Length(value) => Ok(Self::Length(value)),
+            Dimension::Percent(value) => Ok(Self::Percent(value)),
+            Dimension::Auto => Ok(Self::Auto),
+            Dimension::Content => Err(input),
+        }
+    }
+}
+
 impl LengthPercentageAuto {
+    /// An absolute length in some abstract units. Usable in `const` contexts.
+    #[must_use]
+    pub const fn length(value: f32) -> Self {
+        Self::Length(value)
+    }
+
+    /// A percentage length relative to the size of the containing block. Usable in `const` contexts.
+    ///
+    /// **NOTE: percentages are represented as a f32 value in the range [0.0, 1.0] NOT the range [0.0, 100.0]**
+    #[must_use]
+    pub const fn percent(value: f32) -> Self {
+        Self::Percent(value)
+    }
+
+    /// The dimension should be automatically computed. Usable in `const` contexts.
+    #[must_use]
+    pub const fn auto() -> Self {
+        Self::Auto
+    }
+
     /// Returns:
     ///   - Some(length) for Length variants
     ///   - Some(resolved) using the provided context for Percent variants
@@ -78,8 +181,8 @@ impl LengthPercentageAuto {
     #[inline(always)]
     pub fn resolve_to_option(self, context: f32) -> Option<f32> {
         match self {
-            Self::Length(length) => Some(length),
-            Self::Percent(percent) => Some(context * percent),
+            Self::Length(length) => sanitize_length(length),
+            Self::Percent(percent) => sanitize_length(context * percent),
             Self::Auto => None,
         }
     }
@@ -89,6 +192,21 @@ impl LengthPercentageAuto {
     pub fn is_auto(self) -> bool {
         self == Self::Auto
     }
+
+    /// Returns true if the value is a zero length or a zero percentage
+    #[inline(always)]
+    pub fn is_zero(self) -> bool {
+        matches!(self, Self::Length(value) | Self::Percent(value) if value == 0.0)
+    }
+
+    /// Applies the function `f` to the value of `Length` variants, leaving other variants unchanged
+    #[must_use]
+    pub fn map_length(self, f: impl FnOnce(f32) -> f32) -> Self {
+        match self {
+            Self::Length(value) => Self::Length(f(value)),
+            _ => self,
+        }
+    }
 }
 
 /// A unit of linear measurement
@@ -104,6 +222,12 @@ pub enum Dimension {
     Percent(f32),
     /// The dimension should be automatically computed
     Auto,
+    /// The dimension should be computed from the node's content (its max-content size).
+    ///
+    /// This value is only meaningful for [`Style::flex_basis`](crate::style::Style::flex_basis), where it corresponds to
+    /// CSS `flex-basis: content`: the flex base size is computed from the item's content, ignoring its main axis `size`.
+    /// When used for any other property it behaves like [`Dimension::Auto`].
+    Content,
 }
 impl TaffyZero for Dimension {
     const ZERO: Self = Self::Length(0.0);
@@ -112,13 +236,13 @@ impl TaffyAuto for Dimension {
     const AUTO: Self = Self::Auto;
 }
 impl FromLength for Dimension {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Self::Length(value.into())
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Self::Length(value.into_f32())
     }
 }
 impl FromPercent for Dimension {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Self::Percent(percent.into())
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Self::Percent(percent.into_f32())
     }
 }
 
@@ -142,11 +266,52 @@ impl From<LengthPercentageAuto> for Dimension {
 }
 
 impl Dimension {
+    /// An absolute length in some abstract units. Usable in `const` contexts.
+    #[must_use]
+    pub const fn length(value: f32) -> Self {
+        Self::Length(value)
+    }
+
+    /// A percentage length relative to the size of the containing block. Usable in `const` contexts.
+    ///
+    /// **NOTE: percentages are represented as a f32 value in the range [0.0, 1.0] NOT the range [0.0, 100.0]**
+    #[must_use]
+    pub const fn percent(value: f32) -> Self {
+        Self::Percent(value)
+    }
+
+    /// The dimension should be automatically computed. Usable in `const` contexts.
+    #[must_use]
+    pub const fn auto() -> Self {
+        Self::Auto
+    }
+
+    /// Returns true if value is Dimension::Auto
+    #[inline(always)]
+    pub fn is_auto(self) -> bool {
+        self == Self::Auto
+    }
+
+    /// Returns true if the value is a zero length or a zero percentage
+    #[inline(always)]
+    pub fn is_zero(self) -> bool {
+        matches!(self, Self::Length(value) | Self::Percent(value) if value == 0.0)
+    }
+
+    /// Applies the function `f` to the value of `Length` variants, leaving other variants unchanged
+    #[must_use]
+    pub fn map_length(self, f: impl FnOnce(f32) -> f32) -> Self {
+        match self {
+            Self::Length(value) => Self::Length(f(value)),
+            _ => self,
+        }
+    }
+
     /// Get Length value if value is Length variant
     #[cfg(feature = "grid")]
     pub fn into_option(self) -> Option<f32> {
         match self {
-            Dimension::Length(value) => Some(value),
+            Dimension::Length(value) => sanitize_length(value),
             _ => None,
         }
     }
@@ -181,6 +346,10 @@ impl Rect<Dimension> {
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum AvailableSpace {
     /// The amount of space available is the specified number of pixels
+    ///
+    /// Nodes with an `auto` size that stretch to fill their container (such as a root node or a block-level box)
+    /// fill all of this space. See `TaffyTree::compute_layout` for how to size a root node to fit its
+    /// content up to a limit instead.
     Definite(f32),
     /// The amount of space available is indefinite and the node should be laid out under a min-content constraint
     MinContent,
@@ -197,8 +366,8 @@ impl TaffyMinContent for AvailableSpace {
     const MIN_CONTENT: Self = Self::MinContent;
 }
 impl FromLength for AvailableSpace {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Self::Definite(value.into())
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Self::Definite(value.into_f32())
     }
 }
 
@@ -313,3 +482,37 @@ impl Size<AvailableSpace> {
         Size { width: self.width.maybe_set(value.width), height: self.height.maybe_set(value.height) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Dimension, LengthPercentage, LengthPercentageAuto};
+
+    #[test]
+    fn lossy_conversions() {
+        assert_eq!(LengthPercentage::try_from(LengthPercentageAuto::Percent(0.5)), Ok(LengthPercentage::Percent(0.5)));
+        assert_eq!(LengthPercentage::try_from(LengthPercentageAuto::Auto), Err(LengthPercentageAuto::Auto));
+        assert_eq!(LengthPercentage::try_from(Dimension::Length(10.0)), Ok(LengthPercentage::Length(10.0)));
+        assert_eq!(LengthPercentage::try_from(Dimension::Auto), Err(Dimension::Auto));
+        assert_eq!(LengthPercentageAuto::try_from(Dimension::Auto), Ok(LengthPercentageAuto::Auto));
+        assert_eq!(LengthPercentageAuto::try_from(Dimension::Content), Err(Dimension::Content));
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(LengthPercentage::Percent(0.0).is_zero());
+        assert!(!LengthPercentage::Length(1.0).is_zero());
+        assert!(LengthPercentageAuto::Length(0.0).is_zero());
+        assert!(!LengthPercentageAuto::Auto.is_zero());
+        assert!(Dimension::Auto.is_auto());
+        assert!(!Dimension::Content.is_auto());
+        assert!(!Dimension::Auto.is_zero());
+    }
+
+    #[test]
+    fn map_length() {
+        assert_eq!(LengthPercentage::Length(10.0).map_length(|value| value * 2.0), LengthPercentage::Length(20.0));
+        assert_eq!(LengthPercentage::Percent(0.5).map_length(|value| value * 2.0), LengthPercentage::Percent(0.5));
+        assert_eq!(LengthPercentageAuto::Auto.map_length(|value| value * 2.0), LengthPercentageAuto::Auto);
+        assert_eq!(Dimension::Length(10.0).map_length(|value| value + 5.0), Dimension::Length(15.0));
+    }
+}
@@ -69,9 +69,15 @@ pub enum GenericGridPlacement<LineType: GridCoordinate> {
     /// Place item at specified line (column or row) index
     Line(LineType),
     /// Item should span specified number of tracks (columns or rows)
+    ///
+    /// Spans of `0` are invalid and are treated as spans of `1`. Spans are limited to [`MAX_GRID_SPAN`] tracks.
     Span(u16),
 }
 
+/// The maximum number of tracks that a [`GridPlacement::Span`] can cover. Larger spans are treated as spans of this
+/// many tracks, which keeps the grid lines that they resolve to in range.
+pub const MAX_GRID_SPAN: u16 = 1000;
+
 /// A grid line placement using the normalized OriginZero coordinates to specify line positions.
 pub(crate) type OriginZeroGridPlacement = GenericGridPlacement<OriginZeroLine>;
 
@@ -105,6 +111,20 @@ impl TaffyGridSpan for Line<GridPlacement> {
     }
 }
 
+/// A single [`GridPlacement`] is the start of a placement whose end is `auto` (as in the CSS `grid-row` and
+/// `grid-column` shorthands)
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// let placement: Line<GridPlacement> = GridPlacement::Span(2).into();
+/// assert_eq!(placement, Line { start: span(2), end: auto() });
+/// ```
+impl From<GridPlacement> for Line<GridPlacement> {
+    fn from(start: GridPlacement) -> Self {
+        Line { start, end: GridPlacement::Auto }
+    }
+}
+
 impl Default for GridPlacement {
     fn default() -> Self {
         Self::Auto
@@ -116,7 +136,8 @@ impl GridPlacement {
     pub fn into_origin_zero_placement(self, explicit_track_count: u16) -> OriginZeroGridPlacement {
         match self {
             Self::Auto => OriginZeroGridPlacement::Auto,
-            Self::Span(span) => OriginZeroGridPlacement::Span(span),
+            // A span must cover at least one track, so (as in browsers) invalid spans of zero are treated as one
+            Self::Span(span) => OriginZeroGridPlacement::Span(span.clamp(1, MAX_GRID_SPAN)),
             // Grid line zero is an invalid index, so it gets treated as Auto
             // See: https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start#values
             Self::Line(line) => match line.as_i16() {
@@ -136,10 +157,11 @@ impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
     }
 
     /// Resolves the span for an indefinite placement (a placement that does not consist of two `Track`s).
+    /// The span is normalised to be between `1` and [`MAX_GRID_SPAN`].
     /// Panics if called on a definite placement
     pub fn indefinite_span(&self) -> u16 {
         use GenericGridPlacement as GP;
-        match (self.start, self.end) {
+        let span = match (self.start, self.end) {
             (GP::Line(_), GP::Auto) => 1,
             (GP::Auto, GP::Line(_)) => 1,
             (GP::Auto, GP::Auto) => 1,
@@ -149,7 +171,8 @@ impl<T: GridCoordinate> Line<GenericGridPlacement<T>> {
             (GP::Auto, GP::Span(span)) => span,
             (GP::Span(span), GP::Span(_)) => span,
             (GP::Line(_), GP::Line(_)) => panic!("indefinite_span should only be called on indefinite grid tracks"),
-        }
+        };
+        span.clamp(1, MAX_GRID_SPAN)
     }
 }
 
@@ -232,6 +255,103 @@ impl Default for Line<GridPlacement> {
     }
 }
 
+/// Error returned when parsing a [`GridPlacement`] or a `Line<GridPlacement>` from a string fails
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GridPlacementParseError {
+    /// The string (or one side of the `/`) was empty
+    Empty,
+    /// The string was not `auto`, a line number, or `span` and a number (or it contained more than one `/`)
+    Invalid,
+    /// The line number was zero, which does not refer to a grid line
+    ZeroLine,
+    /// The span was zero. Spans must cover at least one track.
+    ZeroSpan,
+    /// Both the start and end of the placement were spans
+    DoubleSpan,
+}
+#[cfg(feature = "std")]
+impl std::error::Error for GridPlacementParseError {}
+impl core::fmt::Display for GridPlacementParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "grid placement is empty",
+            Self::Invalid => "grid placement must be 'auto', a line number or 'span' and a number",
+            Self::ZeroLine => "grid line 0 is not a valid grid line",
+            Self::ZeroSpan => "grid placement spans must be at least 1",
+            Self::DoubleSpan => "the start and end of a grid placement can't both be spans",
+        })
+    }
+}
+
+impl core::str::FromStr for GridPlacement {
+    type Err = GridPlacementParseError;
+
+    /// Parses a placement in the syntax of the CSS `grid-row-start` property (and similar): `auto`, a line number such
+    /// as `2` or `-1`, or a span such as `span 3`. Named lines are not supported.
+    fn from_str(value: &str) -> Result<Self, GridPlacementParseError> {
+        fn parse_line(token: &str) -> Result<i16, GridPlacementParseError> {
+            match token.parse::<i16>() {
+                Ok(0) => Err(GridPlacementParseError::ZeroLine),
+                Ok(line) => Ok(line),
+                Err(_) => Err(GridPlacementParseError::Invalid),
+            }
+        }
+        fn parse_span(token: &str) -> Result<u16, GridPlacementParseError> {
+            match token.parse::<u16>() {
+                Ok(0) => Err(GridPlacementParseError::ZeroSpan),
+                Ok(span) => Ok(span),
+                Err(_) => Err(GridPlacementParseError::Invalid),
+            }
+        }
+        let is_span = |token: &str| token.eq_ignore_ascii_case("span");
+
+        let mut tokens = value.split_whitespace();
+        match (tokens.next(), tokens.next(), tokens.next()) {
+            (None, _, _) => Err(GridPlacementParseError::Empty),
+            (Some(token), None, _) if token.eq_ignore_ascii_case("auto") => Ok(Self::Auto),
+            (Some(token), None, _) => Ok(line(parse_line(token)?)),
+            (Some(first), Some(count), None) if is_span(first) => Ok(span(parse_span(count)?)),
+            (Some(count), Some(second), None) if is_span(second) => Ok(span(parse_span(count)?)),
+            _ => Err(GridPlacementParseError::Invalid),
+        }
+    }
+}
+
+impl core::str::FromStr for Line<GridPlacement> {
+    type Err = GridPlacementParseError;
+
+    /// Parses a placement in the syntax of the CSS `grid-row` and `grid-column` shorthand properties: one or two
+    /// [`GridPlacement`]s separated by a `/` (such as `2 / span 3`). If only one placement is given then the end is
+    /// `auto`.
+    fn from_str(value: &str) -> Result<Self, GridPlacementParseError> {
+        let mut parts = value.split('/');
+        let start = parts.next().unwrap_or_default().parse()?;
+        let end = parts.next().map(str::parse).transpose()?.unwrap_or(GridPlacement::Auto);
+        if parts.next().is_some() {
+            return Err(GridPlacementParseError::Invalid);
+        }
+        if let (GridPlacement::Span(_), GridPlacement::Span(_)) = (start, end) {
+            return Err(GridPlacementParseError::DoubleSpan);
+        }
+
+        Ok(Line { start, end })
+    }
+}
+
+impl Line<GridPlacement> {
+    /// Parses a placement in the syntax of the CSS `grid-row` and `grid-column` shorthand properties
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// assert_eq!(Line::<GridPlacement>::parse("2 / span 3"), Ok(Line { start: line(2), end: span(3) }));
+    /// assert_eq!(Line::<GridPlacement>::parse("auto / -1"), Ok(Line { start: auto(), end: line(-1) }));
+    /// assert!(Line::<GridPlacement>::parse("span 2 / span 3").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, GridPlacementParseError> {
+        value.parse()
+    }
+}
+
 /// Maximum track sizing function
 ///
 /// Specifies the maximum size of a grid track. A grid track will automatically size between it's minimum and maximum size based
@@ -273,18 +393,18 @@ impl TaffyZero for MaxTrackSizingFunction {
     const ZERO: Self = Self::Fixed(LengthPercentage::ZERO);
 }
 impl FromLength for MaxTrackSizingFunction {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
         Self::Fixed(LengthPercentage::from_length(value))
     }
 }
 impl FromPercent for MaxTrackSizingFunction {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
         Self::Fixed(LengthPercentage::from_percent(percent))
     }
 }
 impl FromFlex for MaxTrackSizingFunction {
-    fn from_flex<Input: Into<f32> + Copy>(flex: Input) -> Self {
-        Self::Fraction(flex.into())
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Self::Fraction(flex.into_f32())
     }
 }
 
@@ -387,12 +507,12 @@ impl TaffyZero for MinTrackSizingFunction {
     const ZERO: Self = Self::Fixed(LengthPercentage::ZERO);
 }
 impl FromLength for MinTrackSizingFunction {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
         Self::Fixed(LengthPercentage::from_length(value))
     }
 }
 impl FromPercent for MinTrackSizingFunction {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
         Self::Fixed(LengthPercentage::from_percent(percent))
     }
 }
@@ -441,6 +561,16 @@ impl MinTrackSizingFunction {
 /// or a scalar value which applies to both track sizing functions.
 pub type NonRepeatedTrackSizingFunction = MinMax<MinTrackSizingFunction, MaxTrackSizingFunction>;
 impl NonRepeatedTrackSizingFunction {
+    /// A track whose minimum and maximum sizes are both the given length or percentage
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let track = MinMax::fixed(length(40.0));
+    /// assert_eq!(track, minmax(length(40.0), length(40.0)));
+    /// ```
+    pub const fn fixed(value: LengthPercentage) -> Self {
+        Self { min: MinTrackSizingFunction::Fixed(value), max: MaxTrackSizingFunction::Fixed(value) }
+    }
     /// Extract the min track sizing function
     pub fn min_sizing_function(&self) -> MinTrackSizingFunction {
         self.min
@@ -474,17 +604,17 @@ impl TaffyZero for NonRepeatedTrackSizingFunction {
     const ZERO: Self = Self { min: MinTrackSizingFunction::ZERO, max: MaxTrackSizingFunction::ZERO };
 }
 impl FromLength for NonRepeatedTrackSizingFunction {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
         Self { min: MinTrackSizingFunction::from_length(value), max: MaxTrackSizingFunction::from_length(value) }
     }
 }
 impl FromPercent for NonRepeatedTrackSizingFunction {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
         Self { min: MinTrackSizingFunction::from_percent(percent), max: MaxTrackSizingFunction::from_percent(percent) }
     }
 }
 impl FromFlex for NonRepeatedTrackSizingFunction {
-    fn from_flex<Input: Into<f32> + Copy>(flex: Input) -> Self {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
         Self { min: MinTrackSizingFunction::AUTO, max: MaxTrackSizingFunction::from_flex(flex) }
     }
 }
@@ -569,17 +699,17 @@ impl TaffyZero for TrackSizingFunction {
     const ZERO: Self = Self::Single(NonRepeatedTrackSizingFunction::ZERO);
 }
 impl FromLength for TrackSizingFunction {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
         Self::Single(NonRepeatedTrackSizingFunction::from_length(value))
     }
 }
 impl FromPercent for TrackSizingFunction {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
         Self::Single(NonRepeatedTrackSizingFunction::from_percent(percent))
     }
 }
 impl FromFlex for TrackSizingFunction {
-    fn from_flex<Input: Into<f32> + Copy>(flex: Input) -> Self {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
         Self::Single(NonRepeatedTrackSizingFunction::from_flex(flex))
     }
 }
@@ -615,3 +745,44 @@ impl Style {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GridPlacement, GridPlacementParseError};
+    use crate::geometry::Line;
+    use crate::style_helpers::{auto, line, span};
+
+    #[test]
+    fn parse_grid_placement() {
+        assert_eq!("auto".parse::<GridPlacement>(), Ok(auto()));
+        assert_eq!("AUTO".parse::<GridPlacement>(), Ok(auto()));
+        assert_eq!("3".parse::<GridPlacement>(), Ok(line(3)));
+        assert_eq!(" -1 ".parse::<GridPlacement>(), Ok(line(-1)));
+        assert_eq!("span 2".parse::<GridPlacement>(), Ok(span(2)));
+        assert_eq!("2 span".parse::<GridPlacement>(), Ok(span(2)));
+
+        assert_eq!("".parse::<GridPlacement>(), Err(GridPlacementParseError::Empty));
+        assert_eq!("0".parse::<GridPlacement>(), Err(GridPlacementParseError::ZeroLine));
+        assert_eq!("span 0".parse::<GridPlacement>(), Err(GridPlacementParseError::ZeroSpan));
+        assert_eq!("span".parse::<GridPlacement>(), Err(GridPlacementParseError::Invalid));
+        assert_eq!("span -1".parse::<GridPlacement>(), Err(GridPlacementParseError::Invalid));
+        assert_eq!("span 1 2".parse::<GridPlacement>(), Err(GridPlacementParseError::Invalid));
+        assert_eq!("header".parse::<GridPlacement>(), Err(GridPlacementParseError::Invalid));
+        assert_eq!("40000".parse::<GridPlacement>(), Err(GridPlacementParseError::Invalid));
+    }
+
+    #[test]
+    fn parse_grid_placement_line() {
+        assert_eq!(Line::<GridPlacement>::parse("2 / span 3"), Ok(Line { start: line(2), end: span(3) }));
+        assert_eq!(Line::<GridPlacement>::parse("span 2 / 4"), Ok(Line { start: span(2), end: line(4) }));
+        assert_eq!(Line::<GridPlacement>::parse("auto / -1"), Ok(Line { start: auto(), end: line(-1) }));
+        assert_eq!(Line::<GridPlacement>::parse("2/-1"), Ok(Line { start: line(2), end: line(-1) }));
+        assert_eq!(Line::<GridPlacement>::parse("auto"), Ok(Line { start: auto(), end: auto() }));
+        assert_eq!(Line::<GridPlacement>::parse("span 2"), Ok(Line { start: span(2), end: auto() }));
+
+        assert_eq!(Line::<GridPlacement>::parse("span 2 / span 3"), Err(GridPlacementParseError::DoubleSpan));
+        assert_eq!(Line::<GridPlacement>::parse("1 / 2 / 3"), Err(GridPlacementParseError::Invalid));
+        assert_eq!(Line::<GridPlacement>::parse("1 /"), Err(GridPlacementParseError::Empty));
+        assert_eq!(Line::<GridPlacement>::parse(""), Err(GridPlacementParseError::Empty));
+    }
+}
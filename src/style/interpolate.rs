@@ -0,0 +1,193 @@
+//! Interpolation between style values, for animating layout transitions
+use super::{Dimension, LengthPercentage, LengthPercentageAuto, Style};
+use crate::geometry::{Line, Point, Rect, Size};
+
+/// A value that can be interpolated with another value of the same type
+///
+/// Numeric values are interpolated linearly. Values that cannot be interpolated (such as enums, `auto`, or a length
+/// and a percentage) are discrete: the value switches from `self` to `other` when `t` reaches `0.5` (matching how CSS
+/// animates such values).
+pub trait Interpolate {
+    /// Interpolates between `self` (when `t` is `0.0`) and `other` (when `t` is `1.0`)
+    ///
+    /// Values of `t` outside of `0.0..=1.0` extrapolate beyond `self` and `other`
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+/// Returns `from` if `t` is less than `0.5`, and `to` otherwise
+fn discrete<T: Clone>(from: &T, to: &T, t: f32) -> T {
+    if t < 0.5 {
+        from.clone()
+    } else {
+        to.clone()
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Option<f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (self, other) {
+            (Some(from), Some(to)) => Some(from.interpolate(to, t)),
+            _ => discrete(self, other, t),
+        }
+    }
+}
+
+impl Interpolate for LengthPercentage {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (*self, *other) {
+            (Self::Length(from), Self::Length(to)) => Self::Length(from.interpolate(&to, t)),
+            (Self::Percent(from), Self::Percent(to)) => Self::Percent(from.interpolate(&to, t)),
+            _ => discrete(self, other, t),
+        }
+    }
+}
+
+impl Interpolate for LengthPercentageAuto {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (*self, *other) {
+            (Self::Length(from), Self::Length(to)) => Self::Length(from.interpolate(&to, t)),
+            (Self::Percent(from), Self::Percent(to)) => Self::Percent(from.interpolate(&to, t)),
+            _ => discrete(self, other, t),
+        }
+    }
+}
+
+impl Interpolate for Dimension {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        match (*self, *other) {
+            (Self::Length(from), Self::Length(to)) => Self::Length(from.interpolate(&to, t)),
+            (Self::Percent(from), Self::Percent(to)) => Self::Percent(from.interpolate(&to, t)),
+            _ => discrete(self, other, t),
+        }
+    }
+}
+
+impl<T: Interpolate> Interpolate for Rect<T> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Rect {
+            left: self.left.interpolate(&other.left, t),
+            right: self.right.interpolate(&other.right, t),
+            top: self.top.interpolate(&other.top, t),
+            bottom: self.bottom.interpolate(&other.bottom, t),
+        }
+    }
+}
+
+impl<T: Interpolate> Interpolate for Size<T> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size { width: self.width.interpolate(&other.width, t), height: self.height.interpolate(&other.height, t) }
+    }
+}
+
+impl<T: Interpolate> Interpolate for Point<T> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point { x: self.x.interpolate(&other.x, t), y: self.y.interpolate(&other.y, t) }
+    }
+}
+
+impl<T: Interpolate> Interpolate for Line<T> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Line { start: self.start.interpolate(&other.start, t), end: self.end.interpolate(&other.end, t) }
+    }
+}
+
+impl Interpolate for Style {
+    /// Interpolates the lengths, sizes, spacing and flex factors of two styles. All other properties (including grid
+    /// templates and placements) are discrete.
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Style {
+            scrollbar_width: self.scrollbar_width.interpolate(&other.scrollbar_width, t),
+            inset: self.inset.interpolate(&other.inset, t),
+            size: self.size.interpolate(&other.size, t),
+            min_size: self.min_size.interpolate(&other.min_size, t),
+            max_size: self.max_size.interpolate(&other.max_size, t),
+            aspect_ratio: self.aspect_ratio.interpolate(&other.aspect_ratio, t),
+            contain_intrinsic_size: self.contain_intrinsic_size.interpolate(&other.contain_intrinsic_size, t),
+            margin: self.margin.interpolate(&other.margin, t),
+            padding: self.padding.interpolate(&other.padding, t),
+            border: self.border.interpolate(&other.border, t),
+            #[cfg(any(feature = "flexbox", feature = "grid"))]
+            gap: self.gap.interpolate(&other.gap, t),
+            #[cfg(feature = "flexbox")]
+            flex_basis: self.flex_basis.interpolate(&other.flex_basis, t),
+            #[cfg(feature = "flexbox")]
+            flex_grow: self.flex_grow.interpolate(&other.flex_grow, t),
+            #[cfg(feature = "flexbox")]
+            flex_shrink: self.flex_shrink.interpolate(&other.flex_shrink, t),
+            ..discrete(self, other, t)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "flexbox", feature = "grid"))]
+mod tests {
+    use super::Interpolate;
+    use crate::prelude::*;
+
+    #[test]
+    fn interpolate_lengths() {
+        let from = Style {
+            size: Size { width: length(100.0), height: percent(0.5) },
+            padding: length(10.0),
+            gap: length(0.0),
+            flex_grow: 0.0,
+            ..Default::default()
+        };
+        let to = Style {
+            size: Size { width: length(200.0), height: percent(1.0) },
+            padding: length(20.0),
+            gap: Size { width: length(8.0), height: length(4.0) },
+            flex_grow: 1.0,
+            ..Default::default()
+        };
+
+        let style = from.interpolate(&to, 0.25);
+        assert_eq!(style.size, Size { width: length(125.0), height: percent(0.625) });
+        assert_eq!(style.padding, length(12.5));
+        assert_eq!(style.gap, Size { width: length(2.0), height: length(1.0) });
+        assert_eq!(style.flex_grow, 0.25);
+
+        assert_eq!(from.interpolate(&to, 0.0), from);
+        assert_eq!(from.interpolate(&to, 1.0), to);
+    }
+
+    #[test]
+    fn interpolate_incompatible_values_is_discrete() {
+        let from = Style {
+            display: Display::Flex,
+            size: Size { width: length(100.0), height: auto() },
+            margin: Rect { left: auto(), right: length(10.0), top: percent(0.1), bottom: zero() },
+            aspect_ratio: None,
+            ..Default::default()
+        };
+        let to = Style {
+            display: Display::Grid,
+            size: Size { width: percent(0.5), height: length(50.0) },
+            margin: Rect { left: length(10.0), right: auto(), top: length(10.0), bottom: zero() },
+            aspect_ratio: Some(2.0),
+            ..Default::default()
+        };
+
+        let before = from.interpolate(&to, 0.4);
+        assert_eq!(before.display, Display::Flex);
+        assert_eq!(before.size, from.size);
+        assert_eq!(before.margin, from.margin);
+        assert_eq!(before.aspect_ratio, None);
+
+        let after = from.interpolate(&to, 0.6);
+        assert_eq!(after.display, Display::Grid);
+        assert_eq!(after.size, to.size);
+        assert_eq!(after.margin, to.margin);
+        assert_eq!(after.aspect_ratio, Some(2.0));
+
+        // Non-finite interpolation factors don't panic
+        from.interpolate(&to, f32::NAN);
+        from.interpolate(&to, f32::INFINITY);
+    }
+}
@@ -4,12 +4,18 @@ mod dimension;
 
 #[cfg(feature = "flexbox")]
 mod flex;
+#[cfg(feature = "interpolate")]
+mod interpolate;
+mod validate;
 
 pub use self::alignment::{AlignContent, AlignItems, AlignSelf, JustifyContent, JustifyItems, JustifySelf};
-pub use self::dimension::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto};
+pub use self::dimension::{AvailableSpace, Dimension, LengthPercentage, LengthPercentageAuto, MAX_SIZE};
 
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap};
+#[cfg(feature = "interpolate")]
+pub use self::interpolate::Interpolate;
+pub use self::validate::StyleWarning;
 
 #[cfg(feature = "grid")]
 mod grid;
@@ -17,10 +23,12 @@ mod grid;
 pub(crate) use self::grid::{GenericGridPlacement, OriginZeroGridPlacement};
 #[cfg(feature = "grid")]
 pub use self::grid::{
-    GridAutoFlow, GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
-    NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    GridAutoFlow, GridPlacement, GridPlacementParseError, GridTrackRepetition, MaxTrackSizingFunction,
+    MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction, MAX_GRID_SPAN,
 };
 use crate::geometry::{Point, Rect, Size};
+#[cfg(feature = "fixed-capacity-grids")]
+pub use crate::util::sys::MAX_GRID_TRACKS;
 
 #[cfg(feature = "grid")]
 use crate::geometry::Line;
@@ -169,6 +177,43 @@ impl Overflow {
     }
 }
 
+/// Whether a node may be split between fragments (such as printed pages) when the content of one of its ancestors is
+/// fragmented using `TaffyTree::fragment_breaks`
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/break-inside>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BreakInside {
+    /// The node may be split between fragments
+    #[default]
+    Auto,
+    /// The node should not be split between fragments if it fits within a single fragment
+    Avoid,
+}
+
+/// Metrics of the first line of text in a leaf node, from which Taffy computes the node's first baseline
+///
+/// This allows a leaf whose text metrics are known in advance (such as a label with a fixed size) to take part in
+/// baseline alignment without a measure function. See [`Style::line_metrics`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineMetrics {
+    /// The height of the line box (`line-height`)
+    pub line_height: f32,
+    /// The distance from the middle of the line box down to its baseline
+    ///
+    /// The glyphs of a line are centred within its line box, so for a font with a given ascent and descent this is
+    /// `(ascent - descent) / 2`, whatever the line height.
+    pub baseline_offset: f32,
+}
+
+impl LineMetrics {
+    /// The distance from the top of the line box to its baseline
+    pub fn baseline(&self) -> f32 {
+        self.line_height / 2.0 + self.baseline_offset
+    }
+}
+
 /// A typed representation of the CSS style information for a single node.
 ///
 /// The most important idea in flexbox is the notion of a "main" and "cross" axis, which are always perpendicular to each other.
@@ -202,6 +247,10 @@ pub struct Style {
     /// How should the position of this element be tweaked relative to the layout defined?
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub inset: Rect<LengthPercentageAuto>,
+    /// The order in which this node is painted relative to its siblings (`z-index`), where `0` corresponds to `auto`
+    ///
+    /// This does not affect layout. See [`TaffyTree::paint_order_children`](crate::TaffyTree::paint_order_children).
+    pub z_index: i32,
 
     // Size properies
     /// Sets the initial size of the item
@@ -217,6 +266,28 @@ pub struct Style {
     ///
     /// The ratio is calculated as width divided by height.
     pub aspect_ratio: Option<f32>,
+    /// Whether the node has size containment (`contain: size`)
+    ///
+    /// A container with size containment is sized as if it had no children, and its children are then laid out within
+    /// that size. Its children are therefore never measured in order to determine its size (or its contribution to the
+    /// size of its parent), which makes this a useful optimisation for large subtrees whose size is known in advance
+    /// (for example, the items of a virtualised list). Likewise, a leaf node with size containment is not measured.
+    pub contain_size: bool,
+    /// The size of the node's content box used in place of the size of its contents when it has size containment
+    /// (`contain-intrinsic-size`)
+    ///
+    /// This has no effect unless [`Style::contain_size`] is set. In each axis, `None` means that the node is sized as
+    /// if it had no contents. This allows nodes whose contents are not laid out (such as the off-screen items of a
+    /// virtualised list) to be given a placeholder size.
+    pub contain_intrinsic_size: Size<Option<f32>>,
+    /// Whether the node may be split between fragments when its ancestor's content is fragmented (`break-inside`)
+    pub break_inside: BreakInside,
+    /// Metrics of the first line of text in a leaf node, from which its first baseline is computed
+    ///
+    /// The baseline is placed [`LineMetrics::baseline`] below the top of the node's content box, and is used when
+    /// aligning the node with its siblings in Flexbox and CSS Grid. This has no effect on the size of the node, and is
+    /// ignored for nodes with children.
+    pub line_metrics: Option<LineMetrics>,
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -238,11 +309,11 @@ pub struct Style {
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_self: Option<AlignSelf>,
     /// How this node's children should be aligned in the inline axis
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "block_layout", feature = "grid"))]
     pub justify_items: Option<AlignItems>,
     /// How this node should be aligned in the inline axis
     /// Falls back to the parents [`JustifyItems`] if not set
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "block_layout", feature = "grid"))]
     pub justify_self: Option<AlignSelf>,
     /// How should content contained within this item be aligned in the cross/block axis
     #[cfg(any(feature = "flexbox", feature = "grid"))]
@@ -251,6 +322,14 @@ pub struct Style {
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub justify_content: Option<JustifyContent>,
     /// How large should the gaps between items in a grid or flex container be?
+    ///
+    /// Gaps are never shrunk. If the gaps are larger than the space available in the container then items (or flexible
+    /// tracks) shrink no further than zero size (or their minimum size) and the content overflows the container
+    /// on the end side of the axis (the right or bottom edge, or the main-end edge for a reversed flex container).
+    ///
+    /// As in CSS, gaps cannot be `auto`. To distribute the remaining space in a container equally between its items, set
+    /// `justify_content` (or `align_content`) to [`JustifyContent::SpaceBetween`] instead. The gap then acts as a minimum,
+    /// and the resulting space between items can be read from the computed locations of the items.
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::zero"))]
     pub gap: Size<LengthPercentage>,
@@ -262,7 +341,7 @@ pub struct Style {
     /// Should elements wrap, or stay in a single line?
     #[cfg(feature = "flexbox")]
     pub flex_wrap: FlexWrap,
-    /// Sets the initial main axis size of the item
+    /// Sets the initial main axis size of the item. `Dimension::Content` sizes the item based on its content.
     #[cfg(feature = "flexbox")]
     pub flex_basis: Dimension,
     /// The relative rate at which this item grows when it is expanding to fill space
@@ -292,6 +371,27 @@ pub struct Style {
     /// Controls how items get placed into the grid for auto-placed items
     #[cfg(feature = "grid")]
     pub grid_auto_flow: GridAutoFlow,
+    /// Aligns the items in each row of the grid in the block (vertical) axis, overriding `align_items` (`align-tracks`)
+    ///
+    /// This is a non-standard property, enabled by the `grid_track_alignment` feature. The first value applies to the
+    /// first row of the grid (including any implicit rows created before the explicit grid), and the last value is
+    /// repeated for any remaining rows. An empty list has no effect. Items are aligned according to the row in which
+    /// their grid area starts, and an item's own `align_self` takes precedence.
+    ///
+    /// As each item is aligned on its own, the distributed alignments use their single-item fallbacks (`SpaceBetween`
+    /// behaves as `Start`, and `SpaceAround` and `SpaceEvenly` as `Center`), and the "safe" alignments behave as
+    /// their unsafe counterparts.
+    ///
+    /// Note that Firefox's experimental `align-tracks` property instead applies to the tracks of a masonry layout's
+    /// other axis.
+    #[cfg(feature = "grid_track_alignment")]
+    pub align_tracks: GridTrackVec<AlignContent>,
+    /// Aligns the items in each column of the grid in the inline (horizontal) axis, overriding `justify_items`
+    /// (`justify-tracks`)
+    ///
+    /// This is the inline axis counterpart of [`Style::align_tracks`], and is likewise non-standard.
+    #[cfg(feature = "grid_track_alignment")]
+    pub justify_tracks: GridTrackVec<AlignContent>,
 
     // Grid child properties
     /// Defines which row in the grid the item should start and end at
@@ -304,12 +404,27 @@ pub struct Style {
 
 impl Style {
     /// The [`Default`] layout, in a form that can be used in const functions
+    ///
+    /// As `Style` owns the (heap allocated) grid track lists, struct update syntax (`..Style::DEFAULT`) can't be used
+    /// in a `const` context. Instead, start from `Style::DEFAULT` and assign the fields that differ:
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// const CARD: Style = {
+    ///     let mut style = Style::DEFAULT;
+    ///     style.size = Size { width: Dimension::percent(1.0), height: Dimension::auto() };
+    ///     style.padding = Rect::all(LengthPercentage::length(8.0));
+    ///     style
+    /// };
+    /// assert_eq!(CARD.padding.left, length(8.0));
+    /// ```
     pub const DEFAULT: Style = Style {
         display: Display::DEFAULT,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
         scrollbar_width: 0.0,
         position: Position::Relative,
         inset: Rect::auto(),
+        z_index: 0,
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
@@ -317,6 +432,10 @@ impl Style {
         min_size: Size::auto(),
         max_size: Size::auto(),
         aspect_ratio: None,
+        contain_size: false,
+        contain_intrinsic_size: Size::NONE,
+        break_inside: BreakInside::Auto,
+        line_metrics: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         gap: Size::zero(),
         // Aligment
@@ -324,9 +443,9 @@ impl Style {
         align_items: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_self: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "block_layout", feature = "grid"))]
         justify_items: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "block_layout", feature = "grid"))]
         justify_self: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_content: None,
@@ -354,11 +473,134 @@ impl Style {
         grid_auto_columns: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_auto_flow: GridAutoFlow::Row,
+        #[cfg(feature = "grid_track_alignment")]
+        align_tracks: GridTrackVec::new(),
+        #[cfg(feature = "grid_track_alignment")]
+        justify_tracks: GridTrackVec::new(),
         #[cfg(feature = "grid")]
         grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
         #[cfg(feature = "grid")]
         grid_column: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
     };
+
+    /// Returns the default style for a flexbox container that lays out its children in a row
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style { gap: length(8.0), ..Style::flex_row() };
+    /// assert_eq!(style.display, Display::Flex);
+    /// assert_eq!(style.flex_direction, FlexDirection::Row);
+    /// ```
+    #[cfg(feature = "flexbox")]
+    pub const fn flex_row() -> Self {
+        let mut style = Style::DEFAULT;
+        style.display = Display::Flex;
+        style.flex_direction = FlexDirection::Row;
+        style
+    }
+
+    /// Returns the default style for a flexbox container that lays out its children in a column
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style::flex_column().with_size(Size::full());
+    /// assert_eq!(style.display, Display::Flex);
+    /// assert_eq!(style.flex_direction, FlexDirection::Column);
+    /// ```
+    #[cfg(feature = "flexbox")]
+    pub const fn flex_column() -> Self {
+        let mut style = Style::DEFAULT;
+        style.display = Display::Flex;
+        style.flex_direction = FlexDirection::Column;
+        style
+    }
+
+    /// Returns the default style for a CSS Grid container
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style { grid_template_columns: vec![fr(1.0), fr(2.0)], ..Style::grid() };
+    /// assert_eq!(style.display, Display::Grid);
+    /// ```
+    #[cfg(feature = "grid")]
+    pub const fn grid() -> Self {
+        let mut style = Style::DEFAULT;
+        style.display = Display::Grid;
+        style
+    }
+
+    /// Returns the default style for an absolutely positioned node
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style { inset: Rect::horizontal_vertical(auto(), length(0.0)), ..Style::absolute() };
+    /// assert_eq!(style.position, Position::Absolute);
+    /// ```
+    pub const fn absolute() -> Self {
+        let mut style = Style::DEFAULT;
+        style.position = Position::Absolute;
+        style
+    }
+
+    /// Returns this style with the [`size`](Style::size) set to the provided value
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let base_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+    /// let style = base_style.clone().with_size(length(100.0)).with_flex_grow(1.0);
+    /// assert_eq!(style.flex_direction, base_style.flex_direction);
+    /// ```
+    #[must_use]
+    pub const fn with_size(mut self, size: Size<Dimension>) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Returns this style with the [`padding`](Style::padding) set to the provided value
+    #[must_use]
+    pub const fn with_padding(mut self, padding: Rect<LengthPercentage>) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns this style with the [`margin`](Style::margin) set to the provided value
+    #[must_use]
+    pub const fn with_margin(mut self, margin: Rect<LengthPercentageAuto>) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Returns this style with the [`flex_grow`](Style::flex_grow) factor set to the provided value
+    #[cfg(feature = "flexbox")]
+    #[must_use]
+    pub const fn with_flex_grow(mut self, flex_grow: f32) -> Self {
+        self.flex_grow = flex_grow;
+        self
+    }
+
+    /// Returns this style with the [`grid_row`](Style::grid_row) and [`grid_column`](Style::grid_column) placements
+    /// set to the provided lines, in the same order as the CSS `grid-area` shorthand. Named grid areas and lines are
+    /// not supported.
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style::default().with_grid_area(line(1), line(2), span(2), auto());
+    /// assert_eq!(style.grid_row, Line { start: line(1), end: span(2) });
+    /// assert_eq!(style.grid_column, Line { start: line(2), end: auto() });
+    /// ```
+    #[cfg(feature = "grid")]
+    #[must_use]
+    pub const fn with_grid_area(
+        mut self,
+        row_start: GridPlacement,
+        column_start: GridPlacement,
+        row_end: GridPlacement,
+        column_end: GridPlacement,
+    ) -> Self {
+        self.grid_row = Line { start: row_start, end: row_end };
+        self.grid_column = Line { start: column_start, end: column_end };
+        self
+    }
 }
 
 impl Default for Style {
@@ -390,15 +632,16 @@ mod tests {
             align_items: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_self: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "block_layout", feature = "grid"))]
             justify_items: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "block_layout", feature = "grid"))]
             justify_self: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_content: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             justify_content: Default::default(),
             inset: Rect::auto(),
+            z_index: 0,
             margin: Rect::zero(),
             padding: Rect::zero(),
             border: Rect::zero(),
@@ -413,6 +656,10 @@ mod tests {
             min_size: Size::auto(),
             max_size: Size::auto(),
             aspect_ratio: Default::default(),
+            contain_size: false,
+            contain_intrinsic_size: Size::NONE,
+            break_inside: super::BreakInside::Auto,
+            line_metrics: None,
             #[cfg(feature = "grid")]
             grid_template_rows: Default::default(),
             #[cfg(feature = "grid")]
@@ -423,6 +670,10 @@ mod tests {
             grid_auto_columns: Default::default(),
             #[cfg(feature = "grid")]
             grid_auto_flow: Default::default(),
+            #[cfg(feature = "grid_track_alignment")]
+            align_tracks: Default::default(),
+            #[cfg(feature = "grid_track_alignment")]
+            justify_tracks: Default::default(),
             #[cfg(feature = "grid")]
             grid_row: Line { start: GridPlacement::Auto, end: GridPlacement::Auto },
             #[cfg(feature = "grid")]
@@ -433,6 +684,60 @@ mod tests {
         assert_eq!(Style::DEFAULT, old_defaults);
     }
 
+    #[test]
+    fn with_methods() {
+        const STYLE: Style = Style::DEFAULT.with_size(Size::from_lengths(10.0, 20.0));
+        assert_eq!(STYLE, Style { size: Size::from_lengths(10.0, 20.0), ..Default::default() });
+
+        let style = Style::default().with_padding(Rect::length(1.0)).with_margin(Rect::auto());
+        assert_eq!(style, Style { padding: Rect::length(1.0), margin: Rect::auto(), ..Default::default() });
+
+        #[cfg(feature = "flexbox")]
+        assert_eq!(Style::default().with_flex_grow(1.0), Style { flex_grow: 1.0, ..Default::default() });
+
+        #[cfg(feature = "grid")]
+        {
+            use crate::style_helpers::{auto, line, span};
+            let style = Style::default().with_grid_area(line(1), span(2), line(-1), auto());
+            assert_eq!(style.grid_row, Line { start: line(1), end: line(-1) });
+            assert_eq!(style.grid_column, Line { start: span(2), end: auto() });
+        }
+    }
+
+    #[test]
+    fn const_style() {
+        use super::{Dimension, LengthPercentage, LengthPercentageAuto};
+        use crate::style_helpers::{auto, length, percent};
+
+        const STYLE: Style = {
+            let mut style = Style::DEFAULT;
+            style.size = Size { width: Dimension::percent(1.0), height: Dimension::auto() };
+            style.min_size = Size::from_lengths(10.0, 20.0);
+            style.margin =
+                Rect::horizontal_vertical(LengthPercentageAuto::length(1.0), LengthPercentageAuto::length(2.0));
+            style.padding = Rect::all(LengthPercentage::percent(0.1));
+            style.inset = Rect {
+                left: LengthPercentageAuto::length(5.0),
+                right: LengthPercentageAuto::auto(),
+                top: LengthPercentageAuto::percent(0.5),
+                bottom: LengthPercentageAuto::auto(),
+            };
+            style.gap = Size { width: LengthPercentage::length(8.0), height: LengthPercentage::length(4.0) };
+            style
+        };
+
+        let style = Style {
+            size: Size { width: percent(1.0), height: auto() },
+            min_size: Size { width: length(10.0), height: length(20.0) },
+            margin: Rect { left: length(1.0), right: length(1.0), top: length(2.0), bottom: length(2.0) },
+            padding: Rect { left: percent(0.1), right: percent(0.1), top: percent(0.1), bottom: percent(0.1) },
+            inset: Rect { left: length(5.0), right: auto(), top: percent(0.5), bottom: auto() },
+            gap: Size { width: length(8.0), height: length(4.0) },
+            ..Default::default()
+        };
+        assert_eq!(STYLE, style);
+    }
+
     // NOTE: Please feel free the update the sizes in this test as required. This test is here to prevent unintentional size changes
     // and to serve as accurate up-to-date documentation on the sizes.
     #[test]
@@ -497,6 +802,9 @@ mod tests {
         assert_type_size::<Line<GridPlacement>>(8);
 
         // Overall
-        assert_type_size::<Style>(352);
+        #[cfg(not(feature = "grid_track_alignment"))]
+        assert_type_size::<Style>(384);
+        #[cfg(feature = "grid_track_alignment")]
+        assert_type_size::<Style>(432);
     }
 }
@@ -0,0 +1,307 @@
+//! Detection of style values that Taffy ignores or normalises, so that they can be reported to users
+use super::Style;
+use crate::util::sys::Vec;
+
+#[cfg(feature = "grid")]
+use super::{
+    GridPlacement, GridTrackRepetition, MaxTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
+};
+#[cfg(feature = "grid")]
+use crate::geometry::AbsoluteAxis;
+
+/// A style value (or combination of values) that is not laid out as written, as returned by [`Style::validate`]
+///
+/// The `axis` of grid warnings is the axis of the property concerned: horizontal for the `grid_*_columns` and
+/// `grid_column` properties, and vertical for the `grid_*_rows` and `grid_row` properties.
+/// Track indexes are indexes into the relevant `grid_template_*` or `grid_auto_*` vector.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum StyleWarning {
+    /// The `aspect_ratio` is not a finite number greater than zero, so it is ignored
+    InvalidAspectRatio(f32),
+    /// The `flex_grow` factor is negative (or NaN), so it is treated as zero
+    #[cfg(feature = "flexbox")]
+    NegativeFlexGrow(f32),
+    /// The `flex_shrink` factor is negative (or NaN), so it is treated as zero
+    #[cfg(feature = "flexbox")]
+    NegativeFlexShrink(f32),
+    /// The grid template contains more than one `auto-fill` or `auto-fit` repetition, so the whole template is ignored
+    #[cfg(feature = "grid")]
+    MultipleAutoRepetitions {
+        /// The axis of the grid template
+        axis: AbsoluteAxis,
+    },
+    /// The grid template contains an `auto-fill` or `auto-fit` repetition, but the track at `track_index` has neither
+    /// a fixed minimum nor a fixed maximum size, so the whole template is ignored
+    #[cfg(feature = "grid")]
+    AutoRepetitionWithoutFixedSize {
+        /// The axis of the grid template
+        axis: AbsoluteAxis,
+        /// The index of the track definition without a fixed size
+        track_index: usize,
+    },
+    /// The repetition at `track_index` of the grid template has a count of zero or no tracks, so it generates no tracks
+    #[cfg(feature = "grid")]
+    EmptyRepetition {
+        /// The axis of the grid template
+        axis: AbsoluteAxis,
+        /// The index of the repetition in the grid template
+        track_index: usize,
+    },
+    /// A track has a negative (or NaN) `fr` flex factor, so it is treated as zero
+    #[cfg(feature = "grid")]
+    NegativeTrackFlexFactor {
+        /// The axis of the track
+        axis: AbsoluteAxis,
+        /// Whether the track is an implicit (`grid_auto_*`) track rather than an explicit (`grid_template_*`) track
+        is_auto_track: bool,
+        /// The index of the track definition
+        track_index: usize,
+    },
+    /// The grid placement refers to line `0`, which does not exist, so that end of the placement is treated as `auto`
+    #[cfg(feature = "grid")]
+    ZeroLinePlacement {
+        /// The axis of the placement
+        axis: AbsoluteAxis,
+    },
+    /// The grid placement has a span of `0`, so it is treated as a span of `1`
+    #[cfg(feature = "grid")]
+    ZeroSpanPlacement {
+        /// The axis of the placement
+        axis: AbsoluteAxis,
+    },
+    /// Both the start and end of the grid placement are spans, so the end span is ignored
+    #[cfg(feature = "grid")]
+    DoubleSpanPlacement {
+        /// The axis of the placement
+        axis: AbsoluteAxis,
+    },
+}
+
+impl core::fmt::Display for StyleWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "grid")]
+        fn axis_name(axis: &AbsoluteAxis) -> &'static str {
+            match axis {
+                AbsoluteAxis::Horizontal => "column",
+                AbsoluteAxis::Vertical => "row",
+            }
+        }
+
+        match self {
+            Self::InvalidAspectRatio(ratio) => write!(f, "aspect ratio {ratio} is ignored as it is not positive"),
+            #[cfg(feature = "flexbox")]
+            Self::NegativeFlexGrow(factor) => write!(f, "flex-grow {factor} is treated as 0"),
+            #[cfg(feature = "flexbox")]
+            Self::NegativeFlexShrink(factor) => write!(f, "flex-shrink {factor} is treated as 0"),
+            #[cfg(feature = "grid")]
+            Self::MultipleAutoRepetitions { axis } => {
+                write!(f, "grid {} template is ignored as it has multiple auto repetitions", axis_name(axis))
+            }
+            #[cfg(feature = "grid")]
+            Self::AutoRepetitionWithoutFixedSize { axis, track_index } => write!(
+                f,
+                "grid {} template is ignored as it has an auto repetition and track {track_index} has no fixed size",
+                axis_name(axis)
+            ),
+            #[cfg(feature = "grid")]
+            Self::EmptyRepetition { axis, track_index } => {
+                write!(f, "repetition {track_index} of the grid {} template has no tracks", axis_name(axis))
+            }
+            #[cfg(feature = "grid")]
+            Self::NegativeTrackFlexFactor { axis, is_auto_track, track_index } => {
+                let kind = if *is_auto_track { "auto" } else { "template" };
+                write!(f, "fr factor of grid {} {kind} track {track_index} is treated as 0", axis_name(axis))
+            }
+            #[cfg(feature = "grid")]
+            Self::ZeroLinePlacement { axis } => {
+                write!(f, "grid {} line 0 does not exist and is treated as auto", axis_name(axis))
+            }
+            #[cfg(feature = "grid")]
+            Self::ZeroSpanPlacement { axis } => write!(f, "grid {} span 0 is treated as span 1", axis_name(axis)),
+            #[cfg(feature = "grid")]
+            Self::DoubleSpanPlacement { axis } => {
+                write!(f, "the end span of the grid {} placement is ignored", axis_name(axis))
+            }
+        }
+    }
+}
+
+impl Style {
+    /// Checks this style for values that Taffy ignores or normalises, returning a warning for each of them
+    ///
+    /// An empty result means that every value will be laid out as written. This check is not run as part of layout.
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// # use taffy::style::StyleWarning;
+    /// let style = Style { flex_grow: -1.0, ..Default::default() };
+    /// assert_eq!(style.validate(), vec![StyleWarning::NegativeFlexGrow(-1.0)]);
+    /// assert!(Style::default().validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> Vec<StyleWarning> {
+        let mut warnings = Vec::new();
+
+        if let Some(ratio) = self.aspect_ratio {
+            if !(ratio.is_finite() && ratio > 0.0) {
+                warnings.push(StyleWarning::InvalidAspectRatio(ratio));
+            }
+        }
+
+        #[cfg(feature = "flexbox")]
+        {
+            if self.flex_grow.is_nan() || self.flex_grow < 0.0 {
+                warnings.push(StyleWarning::NegativeFlexGrow(self.flex_grow));
+            }
+            if self.flex_shrink.is_nan() || self.flex_shrink < 0.0 {
+                warnings.push(StyleWarning::NegativeFlexShrink(self.flex_shrink));
+            }
+        }
+
+        #[cfg(feature = "grid")]
+        for axis in [AbsoluteAxis::Horizontal, AbsoluteAxis::Vertical] {
+            self.validate_grid_axis(axis, &mut warnings);
+        }
+
+        warnings
+    }
+
+    /// Checks the grid templates, auto tracks and placement of this style in the specified axis
+    #[cfg(feature = "grid")]
+    fn validate_grid_axis(&self, axis: AbsoluteAxis, warnings: &mut Vec<StyleWarning>) {
+        let template = self.grid_template_tracks(axis);
+
+        let auto_repetition_count = template.iter().filter(|track_def| track_def.is_auto_repetition()).count();
+        if auto_repetition_count > 1 {
+            warnings.push(StyleWarning::MultipleAutoRepetitions { axis });
+        } else if auto_repetition_count == 1 {
+            let track_index = template.iter().position(|track_def| match track_def {
+                TrackSizingFunction::Single(sizing_function) => !sizing_function.has_fixed_component(),
+                TrackSizingFunction::Repeat(_, tracks) => tracks.iter().any(|track| !track.has_fixed_component()),
+            });
+            if let Some(track_index) = track_index {
+                warnings.push(StyleWarning::AutoRepetitionWithoutFixedSize { axis, track_index });
+            }
+        }
+
+        fn has_negative_flex_factor(track: &NonRepeatedTrackSizingFunction) -> bool {
+            matches!(track.max, MaxTrackSizingFunction::Fraction(factor) if factor.is_nan() || factor < 0.0)
+        }
+        for (track_index, track_def) in template.iter().enumerate() {
+            let has_negative_flex_factor = match track_def {
+                TrackSizingFunction::Single(track) => has_negative_flex_factor(track),
+                TrackSizingFunction::Repeat(repetition, tracks) => {
+                    if tracks.is_empty() || *repetition == GridTrackRepetition::Count(0) {
+                        warnings.push(StyleWarning::EmptyRepetition { axis, track_index });
+                    }
+                    tracks.iter().any(has_negative_flex_factor)
+                }
+            };
+            if has_negative_flex_factor {
+                warnings.push(StyleWarning::NegativeTrackFlexFactor { axis, is_auto_track: false, track_index });
+            }
+        }
+
+        let auto_tracks = match axis {
+            AbsoluteAxis::Horizontal => &self.grid_auto_columns,
+            AbsoluteAxis::Vertical => &self.grid_auto_rows,
+        };
+        for (track_index, track) in auto_tracks.iter().enumerate() {
+            if has_negative_flex_factor(track) {
+                warnings.push(StyleWarning::NegativeTrackFlexFactor { axis, is_auto_track: true, track_index });
+            }
+        }
+
+        let placement = self.grid_placement(axis);
+        let is_line_zero = |end: GridPlacement| matches!(end, GridPlacement::Line(line) if line.as_i16() == 0);
+        if is_line_zero(placement.start) || is_line_zero(placement.end) {
+            warnings.push(StyleWarning::ZeroLinePlacement { axis });
+        }
+        if [placement.start, placement.end].contains(&GridPlacement::Span(0)) {
+            warnings.push(StyleWarning::ZeroSpanPlacement { axis });
+        }
+        if matches!((placement.start, placement.end), (GridPlacement::Span(_), GridPlacement::Span(_))) {
+            warnings.push(StyleWarning::DoubleSpanPlacement { axis });
+        }
+    }
+}
+
+#[cfg(all(test, feature = "flexbox", feature = "grid"))]
+mod tests {
+    use super::StyleWarning;
+    use crate::geometry::{AbsoluteAxis, Line};
+    use crate::prelude::*;
+    use crate::style::{GridTrackRepetition, NonRepeatedTrackSizingFunction};
+
+    #[test]
+    fn valid_styles_have_no_warnings() {
+        assert_eq!(Style::default().validate(), vec![]);
+
+        let style = Style {
+            display: Display::Grid,
+            aspect_ratio: Some(2.0),
+            flex_grow: 1.0,
+            grid_template_columns: vec![length(10.0), repeat(GridTrackRepetition::AutoFill, vec![length(20.0)])],
+            grid_template_rows: vec![fr(1.0), repeat(2, vec![minmax(length(10.0), fr(2.0))])],
+            grid_auto_rows: vec![fr(1.0)],
+            grid_row: Line { start: line(-1), end: span(2) },
+            ..Default::default()
+        };
+        assert_eq!(style.validate(), vec![]);
+    }
+
+    #[test]
+    fn invalid_factors_and_ratios() {
+        let style = Style { aspect_ratio: Some(0.0), flex_grow: -1.0, flex_shrink: f32::NAN, ..Default::default() };
+        let warnings = style.validate();
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(warnings[0], StyleWarning::InvalidAspectRatio(0.0));
+        assert_eq!(warnings[1], StyleWarning::NegativeFlexGrow(-1.0));
+        assert!(matches!(warnings[2], StyleWarning::NegativeFlexShrink(factor) if factor.is_nan()));
+    }
+
+    #[test]
+    fn invalid_grid_templates() {
+        let auto_fill = |tracks: Vec<NonRepeatedTrackSizingFunction>| repeat(GridTrackRepetition::AutoFill, tracks);
+        let style = Style {
+            grid_template_columns: vec![auto_fill(vec![length(10.0)]), auto_fill(vec![length(20.0)])],
+            grid_template_rows: vec![length(10.0), auto_fill(vec![length(20.0), fr(-1.0)]), repeat(0, vec![auto()])],
+            grid_auto_columns: vec![auto(), fr(-2.0)],
+            ..Default::default()
+        };
+        assert_eq!(
+            style.validate(),
+            vec![
+                StyleWarning::MultipleAutoRepetitions { axis: AbsoluteAxis::Horizontal },
+                StyleWarning::NegativeTrackFlexFactor {
+                    axis: AbsoluteAxis::Horizontal,
+                    is_auto_track: true,
+                    track_index: 1
+                },
+                StyleWarning::AutoRepetitionWithoutFixedSize { axis: AbsoluteAxis::Vertical, track_index: 1 },
+                StyleWarning::NegativeTrackFlexFactor {
+                    axis: AbsoluteAxis::Vertical,
+                    is_auto_track: false,
+                    track_index: 1
+                },
+                StyleWarning::EmptyRepetition { axis: AbsoluteAxis::Vertical, track_index: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_grid_placements() {
+        let style = Style {
+            grid_column: Line { start: line(0), end: GridPlacement::Span(0) },
+            grid_row: Line { start: span(1), end: span(2) },
+            ..Default::default()
+        };
+        assert_eq!(
+            style.validate(),
+            vec![
+                StyleWarning::ZeroLinePlacement { axis: AbsoluteAxis::Horizontal },
+                StyleWarning::ZeroSpanPlacement { axis: AbsoluteAxis::Horizontal },
+                StyleWarning::DoubleSpanPlacement { axis: AbsoluteAxis::Vertical },
+            ]
+        );
+    }
+}
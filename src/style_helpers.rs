@@ -65,6 +65,15 @@ pub fn evenly_sized_tracks(count: u16) -> Vec<TrackSizingFunction> {
 ///  - Positive indicies count upwards from the start (top or left) of the explicit grid
 ///  - Negative indicies count downwards from the end (bottom or right) of the explicit grid
 ///  - ZERO IS INVALID index, and will be treated as a GridPlacement::Auto.
+///
+/// Returns either a [`GridPlacement`](crate::style::GridPlacement) or a `Line<GridPlacement>` starting at the line
+/// (and ending at `auto`), so it can be used to set both `grid_row_start` and `grid_row`:
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// let style = Style { grid_row: line(3), grid_column: Line { start: line(2), end: line(-1) }, ..Default::default() };
+/// assert_eq!(style.grid_row, Line { start: line(3), end: auto() });
+/// ```
 pub fn line<T: TaffyGridLine>(index: i16) -> T {
     T::from_line_index(index)
 }
@@ -75,8 +84,18 @@ pub trait TaffyGridLine {
 }
 
 /// Returns a GridPlacement::Span
+///
+/// A span must cover at least one track, so a `span` of `0` is treated as `1`.
+///
+/// As with [`line`], the result can be a `Line<GridPlacement>` (ending at `auto`) as well as a single placement:
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// let style = Style { grid_column: span(2), ..Default::default() };
+/// assert_eq!(style.grid_column, Line { start: span(2), end: auto() });
+/// ```
 pub fn span<T: TaffyGridSpan>(span: u16) -> T {
-    T::from_span(span)
+    T::from_span(span.max(1))
 }
 /// Trait to abstract over grid span values
 pub trait TaffyGridSpan {
@@ -97,10 +116,10 @@ where
 #[cfg(feature = "grid")]
 pub fn flex<Input, Output>(flex_fraction: Input) -> Output
 where
-    Input: Into<f32> + Copy,
+    Input: TaffyNumber,
     Output: From<MinMax<MinTrackSizingFunction, MaxTrackSizingFunction>>,
 {
-    MinMax { min: zero(), max: fr(flex_fraction.into()) }.into()
+    MinMax { min: zero(), max: fr(flex_fraction) }.into()
 }
 
 /// Returns the zero value for that type
@@ -335,6 +354,11 @@ pub trait TaffyFitContent {
     /// Converts a LengthPercentage into Self
     fn fit_content(argument: LengthPercentage) -> Self;
 }
+impl<T: TaffyFitContent> TaffyFitContent for Option<T> {
+    fn fit_content(argument: LengthPercentage) -> Self {
+        Some(T::fit_content(argument))
+    }
+}
 impl<T: TaffyFitContent> TaffyFitContent for Point<T> {
     fn fit_content(argument: LengthPercentage) -> Self {
         Point { x: T::fit_content(argument), y: T::fit_content(argument) }
@@ -389,158 +413,350 @@ impl<T: TaffyFitContent> Rect<T> {
     }
 }
 
+/// A primitive numeric type which can be passed to the helper functions in this module (such as [`length`],
+/// [`percent`] and [`fr`])
+///
+/// This allows integer values to be used directly (e.g. `length(8)`) without first being converted into an `f32`.
+/// This trait is sealed, and so cannot be implemented outside of Taffy.
+pub trait TaffyNumber: sealed::Sealed + Copy {
+    /// Converts the number into an `f32`
+    fn into_f32(self) -> f32;
+}
+/// Private module used to prevent [`TaffyNumber`] from being implemented outside of Taffy
+mod sealed {
+    /// Supertrait of [`TaffyNumber`](super::TaffyNumber) which cannot be named outside of Taffy
+    pub trait Sealed {}
+}
+/// Implements [`TaffyNumber`] for primitive numeric types by casting them to an `f32`
+macro_rules! impl_taffy_number {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl TaffyNumber for $ty {
+                #[inline(always)]
+                fn into_f32(self) -> f32 {
+                    self as f32
+                }
+            }
+        )*
+    };
+}
+impl_taffy_number!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
 /// Returns a value of the inferred type which represent an absolute length
-pub fn length<Input: Into<f32> + Copy, T: FromLength>(value: Input) -> T {
+pub fn length<Input: TaffyNumber, T: FromLength>(value: Input) -> T {
     T::from_length(value)
 }
 
 /// Trait to create absolute length values from plain numbers
 pub trait FromLength {
-    /// Converts into an `Into<f32>` into Self
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self;
+    /// Converts a number into Self
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self;
 }
 impl FromLength for f32 {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        value.into()
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        value.into_f32()
     }
 }
-impl FromLength for Option<f32> {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Some(value.into())
+impl<T: FromLength> FromLength for Option<T> {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Some(T::from_length(value))
     }
 }
 impl<T: FromLength> FromLength for Point<T> {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Point { x: T::from_length(value.into()), y: T::from_length(value.into()) }
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Point { x: T::from_length(value), y: T::from_length(value) }
     }
 }
 impl<T: FromLength> Point<T> {
     /// Returns a Point where x and y values are the same given absolute length
-    pub fn length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    pub fn length<Input: TaffyNumber>(value: Input) -> Self {
         length::<Input, Self>(value)
     }
 }
 impl<T: FromLength> FromLength for Line<T> {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Line { start: T::from_length(value.into()), end: T::from_length(value.into()) }
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Line { start: T::from_length(value), end: T::from_length(value) }
     }
 }
 impl<T: FromLength> Line<T> {
     /// Returns a Line where both the start and end values are the same given absolute length
-    pub fn length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    pub fn length<Input: TaffyNumber>(value: Input) -> Self {
         length::<Input, Self>(value)
     }
 }
 impl<T: FromLength> FromLength for Size<T> {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
-        Size { width: T::from_length(value.into()), height: T::from_length(value.into()) }
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
+        Size { width: T::from_length(value), height: T::from_length(value) }
     }
 }
 impl<T: FromLength> Size<T> {
     /// Returns a Size where both the width and height values the same given absolute length
-    pub fn length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    pub fn length<Input: TaffyNumber>(value: Input) -> Self {
         length::<Input, Self>(value)
     }
 }
 impl<T: FromLength> FromLength for Rect<T> {
-    fn from_length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    fn from_length<Input: TaffyNumber>(value: Input) -> Self {
         Rect {
-            left: T::from_length(value.into()),
-            right: T::from_length(value.into()),
-            top: T::from_length(value.into()),
-            bottom: T::from_length(value.into()),
+            left: T::from_length(value),
+            right: T::from_length(value),
+            top: T::from_length(value),
+            bottom: T::from_length(value),
         }
     }
 }
 impl<T: FromLength> Rect<T> {
     /// Returns a Rect where the left, right, top and bottom values are all the same given absolute length
-    pub fn length<Input: Into<f32> + Copy>(value: Input) -> Self {
+    pub fn length<Input: TaffyNumber>(value: Input) -> Self {
         length::<Input, Self>(value)
     }
 }
 
 /// Returns a value of the inferred type which represent a percentage
-pub fn percent<Input: Into<f32> + Copy, T: FromPercent>(percent: Input) -> T {
+pub fn percent<Input: TaffyNumber, T: FromPercent>(percent: Input) -> T {
     T::from_percent(percent)
 }
 
 /// Trait to create constant percent values from plain numbers
 pub trait FromPercent {
-    /// Converts into an `Into<f32>` into Self
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self;
+    /// Converts a number into Self
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self;
 }
 impl FromPercent for f32 {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        percent.into()
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        percent.into_f32()
     }
 }
-impl FromPercent for Option<f32> {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Some(percent.into())
+impl<T: FromPercent> FromPercent for Option<T> {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Some(T::from_percent(percent))
     }
 }
 impl<T: FromPercent> FromPercent for Point<T> {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Point { x: T::from_percent(percent.into()), y: T::from_percent(percent.into()) }
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Point { x: T::from_percent(percent), y: T::from_percent(percent) }
     }
 }
 impl<T: FromPercent> Point<T> {
     /// Returns a Point where both the x and y values are the constant percent value of the contained type
     /// (e.g. 2.1, Some(2.1), or Dimension::Length(2.1))
-    pub fn percent<Input: Into<f32> + Copy>(percent_value: Input) -> Self {
+    pub fn percent<Input: TaffyNumber>(percent_value: Input) -> Self {
         percent::<Input, Self>(percent_value)
     }
 }
 impl<T: FromPercent> FromPercent for Line<T> {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Line { start: T::from_percent(percent.into()), end: T::from_percent(percent.into()) }
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Line { start: T::from_percent(percent), end: T::from_percent(percent) }
     }
 }
 impl<T: FromPercent> Line<T> {
     /// Returns a Line where both the start and end values are the constant percent value of the contained type
     /// (e.g. 2.1, Some(2.1), or Dimension::Length(2.1))
-    pub fn percent<Input: Into<f32> + Copy>(percent_value: Input) -> Self {
+    pub fn percent<Input: TaffyNumber>(percent_value: Input) -> Self {
         percent::<Input, Self>(percent_value)
     }
 }
 impl<T: FromPercent> FromPercent for Size<T> {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
-        Size { width: T::from_percent(percent.into()), height: T::from_percent(percent.into()) }
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
+        Size { width: T::from_percent(percent), height: T::from_percent(percent) }
     }
 }
 impl<T: FromPercent> Size<T> {
     /// Returns a Size where both the width and height values are the constant percent value of the contained type
     /// (e.g. 2.1, Some(2.1), or Dimension::Length(2.1))
-    pub fn percent<Input: Into<f32> + Copy>(percent_value: Input) -> Self {
+    pub fn percent<Input: TaffyNumber>(percent_value: Input) -> Self {
         percent::<Input, Self>(percent_value)
     }
+
+    /// Returns a Size where both the width and height are 100% (e.g. to fill the parent)
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let style = Style { size: Size::full(), ..Default::default() };
+    /// assert_eq!(style.size, Size { width: percent(1.0), height: percent(1.0) });
+    /// ```
+    pub fn full() -> Self {
+        percent::<f32, Self>(1.0)
+    }
 }
 impl<T: FromPercent> FromPercent for Rect<T> {
-    fn from_percent<Input: Into<f32> + Copy>(percent: Input) -> Self {
+    fn from_percent<Input: TaffyNumber>(percent: Input) -> Self {
         Rect {
-            left: T::from_percent(percent.into()),
-            right: T::from_percent(percent.into()),
-            top: T::from_percent(percent.into()),
-            bottom: T::from_percent(percent.into()),
+            left: T::from_percent(percent),
+            right: T::from_percent(percent),
+            top: T::from_percent(percent),
+            bottom: T::from_percent(percent),
         }
     }
 }
 impl<T: FromPercent> Rect<T> {
     /// Returns a Rect where the left, right, top and bottom values are all constant percent value of the contained type
     /// (e.g. 2.1, Some(2.1), or Dimension::Length(2.1))
-    pub fn percent<Input: Into<f32> + Copy>(percent_value: Input) -> Self {
+    pub fn percent<Input: TaffyNumber>(percent_value: Input) -> Self {
         percent::<Input, Self>(percent_value)
     }
 }
 
 /// Create a `Fraction` track sizing function (`fr` in CSS)
+///
+/// When used as a full track sizing function, the track has an `auto` minimum (like `1fr` in CSS), so it will never be
+/// smaller than the min-content size of its items. Use [`flex`] for a track with a zero minimum (`minmax(0, 1fr)`).
 #[cfg(feature = "grid")]
-pub fn fr<Input: Into<f32> + Copy, T: FromFlex>(flex: Input) -> T {
+pub fn fr<Input: TaffyNumber, T: FromFlex>(flex: Input) -> T {
     T::from_flex(flex)
 }
 
 /// Trait to create constant percent values from plain numbers
 pub trait FromFlex {
-    /// Converts into an `Into<f32>` into Self
-    fn from_flex<Input: Into<f32> + Copy>(flex: Input) -> Self;
+    /// Converts a number into Self
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self;
+}
+impl<T: FromFlex> FromFlex for Option<T> {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Some(T::from_flex(flex))
+    }
+}
+impl<T: FromFlex> FromFlex for Point<T> {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Point { x: T::from_flex(flex), y: T::from_flex(flex) }
+    }
+}
+impl<T: FromFlex> FromFlex for Line<T> {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Line { start: T::from_flex(flex), end: T::from_flex(flex) }
+    }
+}
+impl<T: FromFlex> FromFlex for Size<T> {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Size { width: T::from_flex(flex), height: T::from_flex(flex) }
+    }
+}
+impl<T: FromFlex> FromFlex for Rect<T> {
+    fn from_flex<Input: TaffyNumber>(flex: Input) -> Self {
+        Rect {
+            left: T::from_flex(flex),
+            right: T::from_flex(flex),
+            top: T::from_flex(flex),
+            bottom: T::from_flex(flex),
+        }
+    }
+}
+
+#[cfg(test)]
+mod numeric_input_tests {
+    use super::*;
+    use crate::style::{Dimension, LengthPercentageAuto, Style};
+
+    #[test]
+    fn helpers_accept_any_primitive_number() {
+        let expected: Dimension = length(8.0f32);
+        assert_eq!(length::<_, Dimension>(8), expected);
+        assert_eq!(length::<_, Dimension>(8u32), expected);
+        assert_eq!(length::<_, Dimension>(8i64), expected);
+        assert_eq!(length::<_, Dimension>(8.0f64), expected);
+        assert_eq!(percent::<_, LengthPercentage>(1), LengthPercentage::Percent(1.0));
+        assert_eq!(Size::<Dimension>::length(10u16), Size { width: length(10.0), height: length(10.0) });
+        assert_eq!(Rect::<LengthPercentageAuto>::percent(0.5f64), Rect::percent(0.5));
+
+        let dpi_scale = 2;
+        let padding: Rect<LengthPercentage> = length(4 * dpi_scale);
+        assert_eq!(padding, Rect::length(8.0));
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn track_helpers_accept_any_primitive_number() {
+        let expected: TrackSizingFunction = fr(2.0f32);
+        assert_eq!(fr::<_, TrackSizingFunction>(2u8), expected);
+        assert_eq!(flex::<_, TrackSizingFunction>(1), flex(1.0f32));
+    }
+
+    #[test]
+    fn float_literals_infer_without_annotations() {
+        let style = Style {
+            size: Size { width: length(100.0), height: percent(0.5) },
+            padding: length(10.0),
+            margin: Rect { left: auto(), right: auto(), top: length(5.0), bottom: zero() },
+            ..Default::default()
+        };
+        assert_eq!(style.size.width, Dimension::Length(100.0));
+        assert_eq!(style.size.height, Dimension::Percent(0.5));
+        assert_eq!(style.padding.left, LengthPercentage::Length(10.0));
+    }
+}
+
+#[cfg(test)]
+mod helper_coverage_tests {
+    use super::*;
+    use crate::style::{AvailableSpace, Dimension, LengthPercentageAuto};
+
+    #[test]
+    fn zero_is_implemented_for_all_numeric_style_types() {
+        assert_eq!(zero::<f32>(), 0.0);
+        assert_eq!(zero::<Option<f32>>(), Some(0.0));
+        assert_eq!(zero::<LengthPercentage>(), LengthPercentage::Length(0.0));
+        assert_eq!(zero::<LengthPercentageAuto>(), LengthPercentageAuto::Length(0.0));
+        assert_eq!(zero::<Dimension>(), Dimension::Length(0.0));
+        assert_eq!(zero::<AvailableSpace>(), AvailableSpace::Definite(0.0));
+        assert_eq!(zero::<Option<Dimension>>(), Some(Dimension::Length(0.0)));
+        assert_eq!(zero::<Point<Option<f32>>>(), Point { x: Some(0.0), y: Some(0.0) });
+        assert_eq!(zero::<Line<LengthPercentageAuto>>(), Line::length(0.0));
+        assert_eq!(zero::<Size<Dimension>>(), Size::length(0.0));
+        assert_eq!(zero::<Rect<LengthPercentage>>(), Rect::length(0.0));
+        assert_eq!(Rect::<LengthPercentageAuto>::zero(), Rect::length(0.0));
+    }
+
+    #[test]
+    fn auto_is_implemented_for_all_style_types_with_an_auto_value() {
+        assert_eq!(auto::<LengthPercentageAuto>(), LengthPercentageAuto::Auto);
+        assert_eq!(auto::<Dimension>(), Dimension::Auto);
+        assert_eq!(auto::<Option<Dimension>>(), Some(Dimension::Auto));
+        assert_eq!(auto::<Point<Dimension>>(), Point { x: Dimension::Auto, y: Dimension::Auto });
+        assert_eq!(auto::<Line<LengthPercentageAuto>>(), Line { start: auto(), end: auto() });
+        assert_eq!(auto::<Size<Dimension>>(), Size { width: Dimension::Auto, height: Dimension::Auto });
+        assert_eq!(
+            Rect::<LengthPercentageAuto>::auto(),
+            Rect { left: auto(), right: auto(), top: auto(), bottom: auto() }
+        );
+    }
+
+    #[test]
+    fn length_and_percent_are_implemented_for_options_of_style_types() {
+        assert_eq!(length::<_, Option<f32>>(10.0), Some(10.0));
+        assert_eq!(length::<_, Option<Dimension>>(10.0), Some(Dimension::Length(10.0)));
+        assert_eq!(percent::<_, Option<LengthPercentage>>(0.5), Some(LengthPercentage::Percent(0.5)));
+        assert_eq!(length::<_, Size<Option<AvailableSpace>>>(10.0).width, Some(AvailableSpace::Definite(10.0)));
+        assert_eq!(percent::<_, Point<Option<LengthPercentageAuto>>>(0.5).y, Some(LengthPercentageAuto::Percent(0.5)));
+    }
+
+    #[test]
+    fn content_sizes_are_implemented_for_available_space() {
+        assert_eq!(min_content::<AvailableSpace>(), AvailableSpace::MinContent);
+        assert_eq!(max_content::<Option<AvailableSpace>>(), Some(AvailableSpace::MaxContent));
+        assert_eq!(Size::<AvailableSpace>::min_content(), Size::MIN_CONTENT);
+        assert_eq!(max_content::<Line<AvailableSpace>>().end, AvailableSpace::MaxContent);
+    }
+
+    #[cfg(feature = "grid")]
+    #[test]
+    fn helpers_are_implemented_for_all_grid_sizing_functions() {
+        use crate::style::{MaxTrackSizingFunction, MinTrackSizingFunction};
+
+        assert_eq!(zero::<MinTrackSizingFunction>(), MinTrackSizingFunction::Fixed(zero()));
+        assert_eq!(auto::<MaxTrackSizingFunction>(), MaxTrackSizingFunction::Auto);
+        assert_eq!(min_content::<NonRepeatedTrackSizingFunction>(), minmax(min_content(), min_content()));
+        assert_eq!(max_content::<TrackSizingFunction>(), TrackSizingFunction::Single(max_content()));
+        assert_eq!(length::<_, MinTrackSizingFunction>(10.0), MinTrackSizingFunction::Fixed(length(10.0)));
+        assert_eq!(percent::<_, MaxTrackSizingFunction>(0.5), MaxTrackSizingFunction::Fixed(percent(0.5)));
+
+        let fit_content_track: Option<TrackSizingFunction> = fit_content(length(10.0));
+        assert_eq!(fit_content_track, Some(TrackSizingFunction::Single(fit_content(length(10.0)))));
+        assert_eq!(fit_content::<Line<MaxTrackSizingFunction>>(length(10.0)).start, fit_content(length(10.0)));
+
+        let fr_tracks: Size<Option<NonRepeatedTrackSizingFunction>> = fr(1.0);
+        assert_eq!(fr_tracks.height, Some(minmax(auto(), MaxTrackSizingFunction::Fraction(1.0))));
+        assert_eq!(fr::<_, Rect<MaxTrackSizingFunction>>(2.0).left, MaxTrackSizingFunction::Fraction(2.0));
+        assert_eq!(fr::<_, Point<TrackSizingFunction>>(1.0).x, fr(1.0));
+    }
 }
@@ -18,6 +18,7 @@ pub(crate) struct CacheEntry<T> {
 }
 
 /// A cache for caching the results of a sizing a Grid Item or Flexbox Item
+#[derive(Clone)]
 pub struct Cache {
     /// The cache entry for the node's final layout
     final_layout_entry: Option<CacheEntry<LayoutOutput>>,
@@ -162,6 +163,29 @@ impl Cache {
         }
     }
 
+    /// Returns the cached min-content size of the node: the size it was measured at when neither dimension was
+    /// known and its width was sized under a min-content constraint. Returns `None` if the node has not been measured
+    /// this way.
+    pub fn min_content_size(&self) -> Option<Size<f32>> {
+        self.find_intrinsic_size(AvailableSpace::MinContent)
+    }
+
+    /// Returns the cached max-content size of the node: the size it was measured at when neither dimension was
+    /// known and its width was sized under a max-content constraint. Returns `None` if the node has not been measured
+    /// this way.
+    pub fn max_content_size(&self) -> Option<Size<f32>> {
+        self.find_intrinsic_size(AvailableSpace::MaxContent)
+    }
+
+    /// Returns the first measurement with no known dimensions that was made with the specified available width
+    fn find_intrinsic_size(&self, available_width: AvailableSpace) -> Option<Size<f32>> {
+        self.measure_entries
+            .iter()
+            .flatten()
+            .find(|entry| entry.known_dimensions == Size::NONE && entry.available_space.width == available_width)
+            .map(|entry| entry.content)
+    }
+
     /// Clear all cache entries
     pub fn clear(&mut self) {
         self.final_layout_entry = None;
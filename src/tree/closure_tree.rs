@@ -0,0 +1,449 @@
+//! An adaptor which allows Taffy to lay out a foreign tree that is accessed through closures
+//!
+//! See [`ClosureTree`] for details.
+use super::{Cache, Layout, LayoutInput, LayoutOutput, NodeId, PrintTree, RoundTree, RunMode, TraverseTree};
+use super::{LayoutPartialTree, TraversePartialTree};
+use crate::compute::{compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout};
+use crate::geometry::Size;
+use crate::style::{AvailableSpace, Display, Style};
+use crate::util::sys::Vec;
+
+#[cfg(feature = "block_layout")]
+use crate::compute::compute_block_layout;
+#[cfg(feature = "flexbox")]
+use crate::compute::compute_flexbox_layout;
+#[cfg(feature = "grid")]
+use crate::compute::compute_grid_layout;
+use crate::compute::round_layout;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as NodeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap as NodeMap;
+
+/// The type of the measure function used by a [`ClosureTree`] which has not been given one with
+/// [`ClosureTree::with_measure`]. It sizes every leaf node as zero-sized.
+pub type ZeroMeasure = fn(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>;
+
+/// The layout state that a [`ClosureTree`] stores on behalf of each node of the foreign tree
+struct NodeState {
+    /// The ids of the node's children, collected from the `get_children` closure
+    children: Vec<NodeId>,
+    /// The cache of the node's computed sizes and layouts
+    cache: Cache,
+    /// The node's layout before rounding
+    unrounded_layout: Layout,
+    /// The node's layout after rounding (or the unrounded layout if rounding is disabled)
+    final_layout: Layout,
+}
+
+/// Lays out a foreign tree (such as a `petgraph` graph, or an arena in a UI framework) which Taffy can only access
+/// through closures, without copying the tree into a [`TaffyTree`](crate::TaffyTree).
+///
+/// The foreign tree is borrowed immutably for the lifetime of the adaptor, and is accessed through:
+///   - A `get_style` closure which returns a reference to the [`Style`] of a node
+///   - A `get_children` closure which returns the ids of a node's children (in order)
+///   - An optional measure function (see [`with_measure`](ClosureTree::with_measure)) which sizes leaf nodes
+///
+/// The adaptor itself stores everything else that Taffy needs: a copy of each node's list of children, a layout
+/// cache, and the computed layouts. Results can be read back with [`layout`](ClosureTree::layout) or passed to a
+/// closure that stores them in the foreign tree with [`compute_layout`](ClosureTree::compute_layout).
+///
+/// As the foreign tree cannot change while it is borrowed, cached results remain valid between calls to
+/// `compute_layout` (for example with different available space). To lay out a tree that has been modified, create a
+/// new adaptor.
+///
+/// ```rust
+/// # use taffy::prelude::*;
+/// # use taffy::ClosureTree;
+/// struct Widget {
+///     style: Style,
+///     children: Vec<usize>,
+/// }
+///
+/// let widgets = vec![
+///     Widget { style: Style { size: length(100.0), padding: length(10.0), ..Default::default() }, children: vec![1] },
+///     Widget { style: Style { flex_grow: 1.0, ..Default::default() }, children: vec![] },
+/// ];
+///
+/// let mut tree = ClosureTree::new(
+///     &widgets,
+///     |widgets, node| &widgets[usize::from(node)].style,
+///     |widgets, node| widgets[usize::from(node)].children.iter().map(|&child| NodeId::from(child)),
+/// );
+///
+/// let mut sizes = vec![Size::ZERO; widgets.len()];
+/// tree.compute_layout(NodeId::from(0usize), Size::MAX_CONTENT, |node, layout| sizes[usize::from(node)] = layout.size);
+/// assert_eq!(sizes[1], Size { width: 80.0, height: 80.0 });
+/// ```
+pub struct ClosureTree<'t, Tree: ?Sized, GetStyle, GetChildren, Measure = ZeroMeasure> {
+    /// The foreign tree
+    tree: &'t Tree,
+    /// Returns a reference to the style of a node of the foreign tree
+    get_style: GetStyle,
+    /// Returns the children of a node of the foreign tree
+    get_children: GetChildren,
+    /// Computes the size of leaf nodes
+    measure: Measure,
+    /// The layout state of each node that has been reached from a root node
+    nodes: NodeMap<NodeId, NodeState>,
+    /// Whether layouts are rounded to whole pixels
+    use_rounding: bool,
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children> ClosureTree<'t, Tree, GetStyle, GetChildren>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+{
+    /// Creates an adaptor over `tree`, accessing the style and children of each node with the provided closures.
+    ///
+    /// Leaf nodes are zero-sized unless a measure function is provided with [`with_measure`](ClosureTree::with_measure).
+    pub fn new(tree: &'t Tree, get_style: GetStyle, get_children: GetChildren) -> Self {
+        Self {
+            tree,
+            get_style,
+            get_children,
+            measure: |_, _, _, _| Size::ZERO,
+            nodes: NodeMap::new(),
+            use_rounding: true,
+        }
+    }
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+    /// Sets the function used to compute the size of leaf nodes (nodes without children)
+    ///
+    /// The function is passed the known dimensions and available space of the node (as with
+    /// [`TaffyTree::compute_layout_with_measure`](crate::TaffyTree::compute_layout_with_measure)), along with the id and
+    /// style of the node.
+    pub fn with_measure<NewMeasure>(
+        self,
+        measure: NewMeasure,
+    ) -> ClosureTree<'t, Tree, GetStyle, GetChildren, NewMeasure>
+    where
+        NewMeasure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+    {
+        ClosureTree {
+            tree: self.tree,
+            get_style: self.get_style,
+            get_children: self.get_children,
+            measure,
+            nodes: self.nodes,
+            use_rounding: self.use_rounding,
+        }
+    }
+
+    /// Enable rounding of layout values. Rounding is enabled by default.
+    pub fn enable_rounding(&mut self) {
+        self.use_rounding = true;
+    }
+
+    /// Disable rounding of layout values. Rounding is enabled by default.
+    pub fn disable_rounding(&mut self) {
+        self.use_rounding = false;
+    }
+
+    /// Computes the layout of `root` and its descendants, then calls `store_layout` with the id and final layout of
+    /// each of them (parents before their children).
+    pub fn compute_layout(
+        &mut self,
+        root: NodeId,
+        available_space: Size<AvailableSpace>,
+        mut store_layout: impl FnMut(NodeId, &Layout),
+    ) {
+        self.collect_children(root);
+
+        compute_root_layout(self, root, available_space);
+        if self.use_rounding {
+            round_layout(self, root);
+        } else {
+            let mut stack = Vec::from([root]);
+            while let Some(node) = stack.pop() {
+                let state = self.nodes.get_mut(&node).unwrap();
+                state.final_layout = state.unrounded_layout;
+                stack.extend(state.children.iter().copied());
+            }
+        }
+
+        let mut stack = Vec::from([root]);
+        while let Some(node) = stack.pop() {
+            let state = &self.nodes[&node];
+            store_layout(node, &state.final_layout);
+            stack.extend(state.children.iter().rev().copied());
+        }
+    }
+
+    /// Returns the final layout of `node` from the most recent call to [`compute_layout`](ClosureTree::compute_layout)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` has not been laid out by this adaptor.
+    pub fn layout(&self, node: NodeId) -> &Layout {
+        &self.nodes[&node].final_layout
+    }
+
+    /// Collects the children of `root` and its descendants from the `get_children` closure, for any nodes which have
+    /// not been reached by a previous layout
+    fn collect_children(&mut self, root: NodeId) {
+        let mut stack = Vec::from([root]);
+        while let Some(node) = stack.pop() {
+            if self.nodes.contains_key(&node) {
+                continue;
+            }
+            let children: Vec<NodeId> = (self.get_children)(self.tree, node).into_iter().collect();
+            stack.extend(children.iter().copied());
+            self.nodes.insert(
+                node,
+                NodeState {
+                    children,
+                    cache: Cache::new(),
+                    unrounded_layout: Layout::with_order(0),
+                    final_layout: Layout::with_order(0),
+                },
+            );
+        }
+    }
+
+    /// Returns the layout state of a node that has been reached from a root node
+    #[inline(always)]
+    fn node_state(&self, node: NodeId) -> &NodeState {
+        &self.nodes[&node]
+    }
+
+    /// Returns the layout state of a node that has been reached from a root node
+    #[inline(always)]
+    fn node_state_mut(&mut self, node: NodeId) -> &mut NodeState {
+        self.nodes.get_mut(&node).unwrap()
+    }
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> TraversePartialTree
+    for ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+    type ChildIter<'a>
+        = core::iter::Copied<core::slice::Iter<'a, NodeId>>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
+        self.node_state(parent_node_id).children.iter().copied()
+    }
+
+    #[inline(always)]
+    fn child_count(&self, parent_node_id: NodeId) -> usize {
+        self.node_state(parent_node_id).children.len()
+    }
+
+    #[inline(always)]
+    fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
+        self.node_state(parent_node_id).children[child_index]
+    }
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> TraverseTree
+    for ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> LayoutPartialTree
+    for ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+    #[inline(always)]
+    fn get_style(&self, node_id: NodeId) -> &Style {
+        (self.get_style)(self.tree, node_id)
+    }
+
+    #[inline(always)]
+    fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_state_mut(node_id).unrounded_layout = *layout;
+    }
+
+    #[inline(always)]
+    fn get_cache_mut(&mut self, node_id: NodeId) -> &mut Cache {
+        &mut self.node_state_mut(node_id).cache
+    }
+
+    fn compute_child_layout(&mut self, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+        if inputs.run_mode == RunMode::PerformHiddenLayout {
+            return compute_hidden_layout(self, node);
+        }
+
+        compute_cached_layout(self, node, inputs, |tree, node, inputs| {
+            let style = (tree.get_style)(tree.tree, node);
+            let has_children = tree.child_count(node) > 0;
+
+            // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
+            match (style.display, has_children) {
+                (Display::None, _) => compute_hidden_layout(tree, node),
+                #[cfg(feature = "block_layout")]
+                (Display::Block, true) => compute_block_layout(tree, node, inputs),
+                #[cfg(feature = "flexbox")]
+                (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
+                #[cfg(feature = "grid")]
+                (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
+                (_, false) => {
+                    let measure = &mut tree.measure;
+                    compute_leaf_layout(inputs, style, |known_dimensions, available_space| {
+                        measure(known_dimensions, available_space, node, style)
+                    })
+                }
+            }
+        })
+    }
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> RoundTree
+    for ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+    #[inline(always)]
+    fn get_unrounded_layout(&self, node_id: NodeId) -> &Layout {
+        &self.node_state(node_id).unrounded_layout
+    }
+
+    #[inline(always)]
+    fn set_final_layout(&mut self, node_id: NodeId, layout: &Layout) {
+        self.node_state_mut(node_id).final_layout = *layout;
+    }
+}
+
+impl<'t, Tree, GetStyle, GetChildren, Children, Measure> PrintTree
+    for ClosureTree<'t, Tree, GetStyle, GetChildren, Measure>
+where
+    Tree: ?Sized,
+    GetStyle: Fn(&'t Tree, NodeId) -> &'t Style,
+    GetChildren: FnMut(&'t Tree, NodeId) -> Children,
+    Children: IntoIterator<Item = NodeId>,
+    Measure: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, &Style) -> Size<f32>,
+{
+    fn get_debug_label(&self, node_id: NodeId) -> &'static str {
+        let style = self.get_style(node_id);
+        match (self.child_count(node_id), style.display) {
+            (_, Display::None) => "NONE",
+            (0, _) => "LEAF",
+            #[cfg(feature = "block_layout")]
+            (_, Display::Block) => "BLOCK",
+            #[cfg(feature = "flexbox")]
+            (_, Display::Flex) => "FLEX",
+            #[cfg(feature = "grid")]
+            (_, Display::Grid) => "GRID",
+        }
+    }
+
+    #[inline(always)]
+    fn get_final_layout(&self, node_id: NodeId) -> &Layout {
+        &self.node_state(node_id).final_layout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ClosureTree;
+    use crate::prelude::*;
+
+    /// A minimal foreign tree: a list of nodes, each with a style, a list of children, and some text
+    struct Node {
+        style: Style,
+        children: Vec<usize>,
+        text_width: f32,
+    }
+
+    fn node(style: Style, children: Vec<usize>) -> Node {
+        Node { style, children, text_width: 0.0 }
+    }
+
+    #[test]
+    fn lays_out_foreign_tree_with_measure_function() {
+        let nodes = vec![
+            node(
+                Style {
+                    display: Display::Flex,
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                vec![1, 2],
+            ),
+            node(Style { flex_grow: 1.0, ..Default::default() }, vec![]),
+            Node { style: Style::default(), children: vec![], text_width: 50.0 },
+        ];
+
+        let mut tree = ClosureTree::new(
+            &nodes,
+            |nodes, node| &nodes[usize::from(node)].style,
+            |nodes, node| nodes[usize::from(node)].children.iter().map(|&child| NodeId::from(child)),
+        )
+        .with_measure(|known_dimensions, _, node, _| Size {
+            width: known_dimensions.width.unwrap_or(nodes[usize::from(node)].text_width),
+            height: known_dimensions.height.unwrap_or(20.0),
+        });
+
+        let mut stored = Vec::new();
+        tree.compute_layout(NodeId::from(0usize), Size::MAX_CONTENT, |node, layout| stored.push((node, *layout)));
+
+        let ids: Vec<usize> = stored.iter().map(|(node, _)| usize::from(*node)).collect();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert_eq!(stored[0].1.size, Size { width: 200.0, height: 20.0 });
+        assert_eq!(stored[1].1.size, Size { width: 150.0, height: 20.0 });
+        assert_eq!(stored[2].1.location.x, 150.0);
+        assert_eq!(tree.layout(NodeId::from(2usize)).size, Size { width: 50.0, height: 20.0 });
+    }
+
+    #[test]
+    fn rounding_can_be_disabled() {
+        let nodes = vec![
+            node(
+                Style { size: Size { width: length(100.0), height: length(10.0) }, ..Default::default() },
+                vec![1, 2, 3],
+            ),
+            node(Style { flex_grow: 1.0, ..Default::default() }, vec![]),
+            node(Style { flex_grow: 1.0, ..Default::default() }, vec![]),
+            node(Style { flex_grow: 1.0, ..Default::default() }, vec![]),
+        ];
+        let mut tree = ClosureTree::new(
+            &nodes,
+            |nodes, node| &nodes[usize::from(node)].style,
+            |nodes, node| nodes[usize::from(node)].children.iter().map(|&child| NodeId::from(child)),
+        );
+
+        tree.compute_layout(NodeId::from(0usize), Size::MAX_CONTENT, |_, _| {});
+        assert_eq!(tree.layout(NodeId::from(1usize)).size.width, 33.0);
+
+        tree.disable_rounding();
+        tree.compute_layout(NodeId::from(0usize), Size::MAX_CONTENT, |_, _| {});
+        assert!((tree.layout(NodeId::from(1usize)).size.width - 100.0 / 3.0).abs() < 0.001);
+    }
+}
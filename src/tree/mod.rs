@@ -15,6 +15,16 @@ pub use node::NodeId;
 pub(crate) use traits::LayoutPartialTreeExt;
 pub use traits::{LayoutPartialTree, PrintTree, RoundTree, TraversePartialTree, TraverseTree};
 
+#[cfg(feature = "profile")]
+pub(crate) mod stats;
+#[cfg(feature = "profile")]
+pub use stats::{AlgorithmStats, LayoutStats};
+
+#[cfg(feature = "closure_tree")]
+mod closure_tree;
+#[cfg(feature = "closure_tree")]
+pub use closure_tree::{ClosureTree, ZeroMeasure};
+
 #[cfg(feature = "taffy_tree")]
 mod taffy_tree;
 #[cfg(feature = "taffy_tree")]
@@ -0,0 +1,155 @@
+//! Statistics about the work performed during a layout run. Only collected if the `profile` feature is enabled.
+use core::fmt;
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+use std::vec::Vec;
+
+/// Statistics about the work performed by a single layout algorithm during a layout run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AlgorithmStats {
+    /// The number of times that the algorithm was run on a node. A node may be laid out more than once during a single
+    /// layout run (for example, once to determine its size and again to perform its final layout), but runs that are
+    /// satisfied by the node's cache are not counted.
+    pub nodes_visited: usize,
+    /// The time spent running the algorithm. This excludes time spent laying out the node's children, which is instead
+    /// attributed to the algorithm used for each child.
+    pub time: Duration,
+}
+
+/// Statistics about the work performed during a layout run
+///
+/// See [`TaffyTree::last_layout_stats`](crate::TaffyTree::last_layout_stats)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LayoutStats {
+    /// Statistics for leaf nodes (nodes without children). Time spent in measure functions is included.
+    pub leaf: AlgorithmStats,
+    /// Statistics for the Block layout algorithm
+    pub block: AlgorithmStats,
+    /// Statistics for the Flexbox layout algorithm
+    pub flexbox: AlgorithmStats,
+    /// Statistics for the CSS Grid layout algorithm
+    pub grid: AlgorithmStats,
+    /// The number of times that a measure function was called
+    pub measure_function_calls: usize,
+    /// The number of times that a node's layout was retrieved from its cache
+    pub cache_hits: usize,
+    /// The number of times that a node's layout was not present in its cache and had to be computed
+    pub cache_misses: usize,
+    /// The number of candidate positions tried by the CSS Grid auto-placement algorithm
+    pub grid_placement_iterations: usize,
+}
+
+impl LayoutStats {
+    /// Get the statistics for the specified algorithm
+    fn algorithm_mut(&mut self, algorithm: Algorithm) -> &mut AlgorithmStats {
+        match algorithm {
+            Algorithm::Leaf => &mut self.leaf,
+            Algorithm::Block => &mut self.block,
+            Algorithm::Flexbox => &mut self.flexbox,
+            Algorithm::Grid => &mut self.grid,
+        }
+    }
+}
+
+impl fmt::Display for LayoutStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "STATS")?;
+        for (name, stats) in
+            [("leaf", self.leaf), ("block", self.block), ("flexbox", self.flexbox), ("grid", self.grid)]
+        {
+            writeln!(
+                f,
+                "{name:<8} nodes visited: {nodes:<6} time: {time:?}",
+                nodes = stats.nodes_visited,
+                time = stats.time
+            )?;
+        }
+        writeln!(f, "measure function calls: {}", self.measure_function_calls)?;
+        writeln!(f, "cache hits: {} misses: {}", self.cache_hits, self.cache_misses)?;
+        write!(f, "grid placement iterations: {}", self.grid_placement_iterations)
+    }
+}
+
+/// The layout algorithms for which statistics are collected
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Algorithm {
+    /// [`compute_leaf_layout`](crate::compute_leaf_layout)
+    Leaf,
+    /// `compute_block_layout`
+    Block,
+    /// `compute_flexbox_layout`
+    Flexbox,
+    /// `compute_grid_layout`
+    Grid,
+}
+
+/// A running algorithm timer
+struct TimerFrame {
+    /// When the algorithm started running
+    start: Instant,
+    /// The time spent laying out children (which is excluded from the algorithm's own time)
+    child_time: Duration,
+}
+
+/// Statistics collected so far for the layout run in progress on the current thread
+#[derive(Default)]
+struct Collector {
+    /// The statistics collected so far
+    stats: LayoutStats,
+    /// A stack of the algorithms that are currently running
+    timers: Vec<TimerFrame>,
+}
+
+std::thread_local! {
+    static COLLECTOR: RefCell<Collector> = RefCell::new(Collector::default());
+}
+
+/// Start collecting statistics for a new layout run
+///
+/// Returns the statistics of any layout run that is already in progress on this thread (for example, if a measure
+/// function itself computes a layout). These should be passed to [`finish_run`] to resume collecting them.
+pub(crate) fn start_run() -> LayoutStats {
+    COLLECTOR.with(|collector| core::mem::take(&mut collector.borrow_mut().stats))
+}
+
+/// Finish collecting statistics for a layout run started with [`start_run`], returning them
+pub(crate) fn finish_run(outer_stats: LayoutStats) -> LayoutStats {
+    COLLECTOR.with(|collector| core::mem::replace(&mut collector.borrow_mut().stats, outer_stats))
+}
+
+/// Update the statistics for the layout run in progress
+pub(crate) fn record(update: impl FnOnce(&mut LayoutStats)) {
+    COLLECTOR.with(|collector| update(&mut collector.borrow_mut().stats))
+}
+
+/// Records a visit to a node by a layout algorithm, and the time taken until it is dropped
+pub(crate) struct AlgorithmTimer {
+    /// The algorithm being timed
+    algorithm: Algorithm,
+}
+
+impl AlgorithmTimer {
+    /// Record a visit by the specified algorithm and start timing it
+    pub(crate) fn start(algorithm: Algorithm) -> Self {
+        COLLECTOR.with(|collector| {
+            let mut collector = collector.borrow_mut();
+            collector.stats.algorithm_mut(algorithm).nodes_visited += 1;
+            collector.timers.push(TimerFrame { start: Instant::now(), child_time: Duration::ZERO });
+        });
+        Self { algorithm }
+    }
+}
+
+impl Drop for AlgorithmTimer {
+    fn drop(&mut self) {
+        COLLECTOR.with(|collector| {
+            let mut collector = collector.borrow_mut();
+            let frame = collector.timers.pop().unwrap();
+            let elapsed = frame.start.elapsed();
+            collector.stats.algorithm_mut(self.algorithm).time += elapsed.saturating_sub(frame.child_time);
+            if let Some(parent_frame) = collector.timers.last_mut() {
+                parent_frame.child_time += elapsed;
+            }
+        });
+    }
+}
@@ -5,14 +5,21 @@ use slotmap::SecondaryMap;
 use slotmap::SparseSecondaryMap as SecondaryMap;
 use slotmap::{DefaultKey, SlotMap};
 
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as KeyMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap as KeyMap;
+
+use crate::geometry::{AbsoluteAxis, Line, Point, Size};
+use crate::style::{AvailableSpace, BreakInside, Display, Position, Style};
 use crate::tree::{
-    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
-    TraversePartialTree, TraverseTree,
+    Cache, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, PrintTree, RoundTree,
+    RunMode, SizingMode, TraversePartialTree, TraverseTree,
 };
+#[cfg(feature = "profile")]
+use crate::tree::LayoutStats;
 use crate::util::debug::{debug_log, debug_log_node};
-use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
+use crate::util::sys::{new_vec_with_capacity, round, ChildrenVec, Vec};
 
 #[cfg(feature = "block_layout")]
 use crate::compute::compute_block_layout;
@@ -22,7 +29,10 @@ use crate::compute::compute_flexbox_layout;
 use crate::compute::compute_grid_layout;
 use crate::compute::{
     compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    round_subtree_layout,
 };
+#[cfg(feature = "fixed-capacity-grids")]
+use crate::compute::{try_compute_grid_layout, GridTrackLimitExceeded};
 
 /// The error Taffy generates on invalid operations
 pub type TaffyResult<T> = Result<T, TaffyError>;
@@ -45,6 +55,28 @@ pub enum TaffyError {
     InvalidChildNode(NodeId),
     /// The supplied node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
     InvalidInputNode(NodeId),
+    /// The child node was supplied more than once in the new children of the parent node
+    DuplicateChildNode {
+        /// The parent node whose children were being set
+        parent: NodeId,
+        /// The child node that was supplied more than once
+        child: NodeId,
+    },
+    /// The child node cannot be made a child of the parent node because it is the parent node itself or one of its ancestors
+    CyclicChildNode {
+        /// The parent node whose children were being set
+        parent: NodeId,
+        /// The child node that would have created a cycle
+        child: NodeId,
+    },
+    /// The grid container needed more tracks (rows or columns) in an axis than can be stored. Only returned when the
+    /// `fixed-capacity-grids` feature is enabled (which limits grids to `MAX_GRID_TRACKS` tracks in each axis).
+    GridTrackLimitExceeded {
+        /// The grid container that could not be laid out
+        node: NodeId,
+        /// The number of tracks that the grid container needed in its largest axis
+        track_count: usize,
+    },
 }
 
 impl core::fmt::Display for TaffyError {
@@ -58,6 +90,15 @@ impl core::fmt::Display for TaffyError {
             }
             TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the TaffyTree instance"),
             TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the TaffyTree instance"),
+            TaffyError::DuplicateChildNode { parent, child } => {
+                write!(f, "Child Node {child:?} was supplied more than once as a child of parent node {parent:?}")
+            }
+            TaffyError::CyclicChildNode { parent, child } => {
+                write!(f, "Child Node {child:?} is parent node {parent:?} or one of its ancestors")
+            }
+            TaffyError::GridTrackLimitExceeded { node, track_count } => {
+                write!(f, "Grid Node {node:?} needs {track_count} tracks, which is more than can be stored")
+            }
         }
     }
 }
@@ -92,11 +133,22 @@ struct NodeData {
     /// These may be rounded or unrounded depending on what the `use_rounding` config setting is set to.
     pub(crate) final_layout: Layout,
 
+    /// An offset added to the location of the node in both the unrounded and final layouts.
+    /// See [`TaffyTree::set_layout_offset`].
+    pub(crate) layout_offset: Point<f32>,
+
+    /// The first baseline of the node computed by the most recent layout, if any. See [`TaffyTree::baseline`].
+    pub(crate) first_baseline: Option<f32>,
+
     /// Whether the node has context data associated with it or not
     pub(crate) has_context: bool,
 
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
+
+    /// The layout generation in which this node's layout was last computed (rather than retrieved from its cache).
+    /// See [`TaffyTree::was_recomputed`].
+    pub(crate) computed_generation: u32,
 }
 
 impl NodeData {
@@ -108,7 +160,10 @@ impl NodeData {
             cache: Cache::new(),
             unrounded_layout: Layout::new(),
             final_layout: Layout::new(),
+            layout_offset: Point::ZERO,
+            first_baseline: None,
             has_context: false,
+            computed_generation: 0,
         }
     }
 
@@ -141,8 +196,25 @@ pub struct TaffyTree<NodeContext = ()> {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// The node associated with each user-provided key
+    key_nodes: KeyMap<u64, NodeId>,
+
+    /// The user-provided key associated with each keyed node
+    node_keys: SecondaryMap<DefaultKey, u64>,
+
     /// Layout mode configuration
     config: TaffyConfig,
+
+    /// The generation of the most recent layout run. This is incremented each time that layout is computed.
+    layout_generation: u32,
+
+    /// Statistics about the work performed by the most recent layout run
+    #[cfg(feature = "profile")]
+    last_layout_stats: LayoutStats,
+
+    /// The first grid container found to need more tracks than can be stored during the current layout run
+    #[cfg(feature = "fixed-capacity-grids")]
+    grid_track_limit_exceeded: Option<GridTrackLimitExceeded>,
 }
 
 impl Default for TaffyTree {
@@ -161,6 +233,34 @@ impl<'a> Iterator for TaffyTreeChildIter<'a> {
     }
 }
 
+/// Iterator over the descendants of a node in depth-first order. See [`TaffyTree::descendants`]
+struct TaffyTreeDescendantsIter<'a> {
+    /// The children of each node in the tree
+    children: &'a SlotMap<DefaultKey, ChildrenVec<NodeId>>,
+    /// The not-yet-visited children of each node on the path from the root to the most recently yielded node
+    stack: Vec<core::slice::Iter<'a, NodeId>>,
+}
+impl<'a> Iterator for TaffyTreeDescendantsIter<'a> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.stack.last_mut()?.next() {
+                Some(&node) => {
+                    let children = &self.children[node.into()];
+                    if !children.is_empty() {
+                        self.stack.push(children.iter());
+                    }
+                    return Some(node);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
 // TraversePartialTree impl for TaffyTree
 impl<NodeContext> TraversePartialTree for TaffyTree<NodeContext> {
     type ChildIter<'a> = TaffyTreeChildIter<'a> where Self: 'a;
@@ -275,7 +375,9 @@ where
 
     #[inline(always)]
     fn set_unrounded_layout(&mut self, node_id: NodeId, layout: &Layout) {
-        self.taffy.nodes[node_id.into()].unrounded_layout = *layout;
+        let node_data = &mut self.taffy.nodes[node_id.into()];
+        node_data.unrounded_layout = *layout;
+        node_data.unrounded_layout.location = layout.location + node_data.layout_offset;
     }
 
     #[inline(always)]
@@ -284,6 +386,8 @@ where
         // and thus that we should lay out this node using hidden layout regardless of it's own display style.
         if inputs.run_mode == RunMode::PerformHiddenLayout {
             debug_log!("HIDDEN");
+            self.taffy.nodes[node.into()].computed_generation = self.taffy.layout_generation;
+            self.taffy.nodes[node.into()].first_baseline = None;
             return compute_hidden_layout(self, node);
         }
 
@@ -292,9 +396,18 @@ where
         //   - Else call the passed closure (below) to compute the result
         //
         // If there was no cache match and a new result needs to be computed then that result will be added to the cache
-        compute_cached_layout(self, node, inputs, |tree, node, inputs| {
+        let output = compute_cached_layout(self, node, inputs, |tree, node, inputs| {
+            tree.taffy.nodes[node.into()].computed_generation = tree.taffy.layout_generation;
             let display_mode = tree.get_style(node).display;
             let has_children = tree.child_count(node) > 0;
+            let has_context = tree.taffy.nodes[node.into()].has_context;
+
+            // Grid containers without children are still laid out as grids (as their explicit tracks have a size)
+            // unless they have a context to be measured with
+            #[cfg(feature = "grid")]
+            let is_container = has_children || (display_mode == Display::Grid && !has_context);
+            #[cfg(not(feature = "grid"))]
+            let is_container = has_children;
 
             debug_log!(display_mode);
             debug_log_node!(
@@ -305,19 +418,26 @@ where
                 inputs.sizing_mode
             );
 
-            // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
-            match (display_mode, has_children) {
+            // Dispatch to a layout algorithm based on the node's display style and whether it is laid out as a container.
+            match (display_mode, is_container) {
                 (Display::None, _) => compute_hidden_layout(tree, node),
                 #[cfg(feature = "block_layout")]
                 (Display::Block, true) => compute_block_layout(tree, node, inputs),
                 #[cfg(feature = "flexbox")]
                 (Display::Flex, true) => compute_flexbox_layout(tree, node, inputs),
-                #[cfg(feature = "grid")]
+                #[cfg(all(feature = "grid", not(feature = "fixed-capacity-grids")))]
                 (Display::Grid, true) => compute_grid_layout(tree, node, inputs),
+                #[cfg(feature = "fixed-capacity-grids")]
+                (Display::Grid, true) => match try_compute_grid_layout(tree, node, inputs) {
+                    Ok(output) => output,
+                    Err(error) => {
+                        tree.taffy.grid_track_limit_exceeded.get_or_insert(error);
+                        compute_grid_layout(tree, node, inputs)
+                    }
+                },
                 (_, false) => {
                     let node_key = node.into();
                     let style = &tree.taffy.nodes[node_key].style;
-                    let has_context = tree.taffy.nodes[node_key].has_context;
                     let node_context = has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
                     let measure_function = |known_dimensions, available_space| {
                         (tree.measure_function)(known_dimensions, available_space, node, node_context)
@@ -325,7 +445,14 @@ where
                     compute_leaf_layout(inputs, style, measure_function)
                 }
             }
-        })
+        });
+
+        // Record the baseline from the final layout of the node (rather than from measuring it) for TaffyTree::baseline
+        if inputs.run_mode == RunMode::PerformLayout {
+            self.taffy.nodes[node.into()].first_baseline = output.first_baselines.y;
+        }
+
+        output
     }
 }
 
@@ -356,6 +483,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Creates a new [`TaffyTree`] that can store `capacity` nodes before reallocation
+    ///
+    /// Storage for the nodes (including their layout caches, which are stored inline), their children lists, their
+    /// parents and their contexts is reserved up front. Storage for node keys is not reserved, as most trees don't
+    /// use them. Each node's list of children is allocated separately when the node is created.
     #[must_use]
     pub fn with_capacity(capacity: usize) -> Self {
         TaffyTree {
@@ -365,7 +496,14 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            key_nodes: KeyMap::new(),
+            node_keys: SecondaryMap::new(),
             config: TaffyConfig::default(),
+            layout_generation: 1,
+            #[cfg(feature = "profile")]
+            last_layout_stats: LayoutStats::default(),
+            #[cfg(feature = "fixed-capacity-grids")]
+            grid_track_limit_exceeded: None,
         }
     }
 
@@ -423,11 +561,14 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.key_nodes.clear();
+        self.node_keys.clear();
     }
 
     /// Remove a specific node from the tree and drop it
     ///
-    /// Returns the id of the node removed.
+    /// Returns the id of the node removed. The node's children are not removed: they are detached and remain in the
+    /// tree as roots. Use [`TaffyTree::remove_subtree`] to remove a node along with all of its descendants.
     pub fn remove(&mut self, node: NodeId) -> TaffyResult<NodeId> {
         let key = node.into();
         if let Some(parent) = self.parents[key] {
@@ -443,6 +584,10 @@ impl<NodeContext> TaffyTree<NodeContext> {
             }
         }
 
+        if let Some(node_key) = self.node_keys.remove(key) {
+            self.key_nodes.remove(&node_key);
+        }
+
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
         let _ = self.nodes.remove(key);
@@ -450,6 +595,81 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(node)
     }
 
+    /// Remove a node and all of its descendants from the tree and drop them
+    ///
+    /// Returns the ids of all of the removed nodes: `node` itself followed by its descendants in depth-first
+    /// pre-order. The ids are no longer valid once this returns, so they can be used to clean up any data that is
+    /// stored alongside the tree.
+    pub fn remove_subtree(&mut self, node: NodeId) -> TaffyResult<Vec<NodeId>> {
+        let key = node.into();
+        if !self.nodes.contains_key(key) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+
+        // Detach the subtree from its parent
+        if let Some(parent) = self.parents[key] {
+            if let Some(children) = self.children.get_mut(parent.into()) {
+                children.retain(|f| *f != node);
+            }
+            self.mark_dirty(parent)?;
+        }
+
+        let mut removed = Vec::new();
+        let mut stack = Vec::new();
+        stack.push(node);
+        while let Some(node) = stack.pop() {
+            let key = node.into();
+            if let Some(node_key) = self.node_keys.remove(key) {
+                self.key_nodes.remove(&node_key);
+            }
+            if let Some(children) = self.children.remove(key) {
+                // Push in reverse so that children are visited (and returned) in order
+                stack.extend(children.into_iter().rev());
+            }
+            let _ = self.node_context_data.remove(key);
+            let _ = self.parents.remove(key);
+            let _ = self.nodes.remove(key);
+            removed.push(node);
+        }
+
+        Ok(removed)
+    }
+
+    /// Associates a user-provided `key` with the node, or removes the node's key if `key` is `None`.
+    ///
+    /// The node can then be looked up by its key using [`TaffyTree::node_for_key`]. This allows a UI that rebuilds
+    /// its tree description every frame to reuse the same node (and therefore its cached layout) across frames.
+    /// Keys are unique: if `key` is already associated with another node then it is moved to this node.
+    pub fn set_node_key(&mut self, node: NodeId, key: Option<u64>) -> TaffyResult<()> {
+        let slot_key = node.into();
+        if !self.nodes.contains_key(slot_key) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+
+        if let Some(old_key) = self.node_keys.remove(slot_key) {
+            self.key_nodes.remove(&old_key);
+        }
+
+        if let Some(key) = key {
+            if let Some(previous_node) = self.key_nodes.insert(key, node) {
+                self.node_keys.remove(previous_node.into());
+            }
+            self.node_keys.insert(slot_key, key);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the user-provided key associated with the node (if any)
+    pub fn node_key(&self, node: NodeId) -> Option<u64> {
+        self.node_keys.get(node.into()).copied()
+    }
+
+    /// Gets the node associated with the user-provided `key` (if any). See [`TaffyTree::set_node_key`]
+    pub fn node_for_key(&self, key: u64) -> Option<NodeId> {
+        self.key_nodes.get(&key).copied()
+    }
+
     /// Sets the context data associated with the node
     pub fn set_node_context(&mut self, node: NodeId, measure: Option<NodeContext>) -> TaffyResult<()> {
         let key = node.into();
@@ -466,12 +686,21 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
+    /// Indicates whether the node has context data associated with it (and so will be passed to the measure function)
+    pub fn has_node_context(&self, node: NodeId) -> bool {
+        self.nodes.get(node.into()).map_or(false, |data| data.has_context)
+    }
+
     /// Gets a reference to the the context data associated with the node
     pub fn get_node_context(&self, node: NodeId) -> Option<&NodeContext> {
         self.node_context_data.get(node.into())
     }
 
     /// Gets a mutable reference to the the context data associated with the node
+    ///
+    /// Unlike [`TaffyTree::set_node_context`], this does not invalidate the node's cached layout. If the change affects
+    /// the size that the measure function returns (for example because a text node's content changed), call
+    /// [`TaffyTree::mark_dirty`] on the node afterwards.
     pub fn get_node_context_mut(&mut self, node: NodeId) -> Option<&mut NodeContext> {
         self.node_context_data.get_mut(node.into())
     }
@@ -495,6 +724,44 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
+    /// Creates a new leaf node for each of the supplied `styles` and appends them to the children of the supplied
+    /// `parent`, returning the [`NodeId`]s of the new nodes (in the same order as the `styles`)
+    ///
+    /// Unlike creating each node with [`TaffyTree::new_leaf`] and then adding it with [`TaffyTree::add_child`], storage
+    /// for all of the new nodes is reserved up front, and the parent's children are written (and the parent marked as
+    /// dirty) only once. This allows large trees to be constructed from the top down without building intermediate
+    /// lists of children. Note that the cost of tree construction is dominated by writing each node's style and layout
+    /// storage, so for the best performance the tree should also be created with [`TaffyTree::with_capacity`].
+    pub fn add_children_batch(
+        &mut self,
+        parent: NodeId,
+        styles: impl IntoIterator<Item = Style>,
+    ) -> TaffyResult<Vec<NodeId>> {
+        let parent_key = parent.into();
+        if !self.nodes.contains_key(parent_key) {
+            return Err(TaffyError::InvalidParentNode(parent));
+        }
+
+        let styles = styles.into_iter();
+        let (count, _) = styles.size_hint();
+        self.nodes.reserve(count);
+        self.children.reserve(count);
+        self.parents.reserve(count);
+
+        let mut new_children: Vec<NodeId> = new_vec_with_capacity(count);
+        for style in styles {
+            let id = NodeId::from(self.nodes.insert(NodeData::new(style)));
+            let _ = self.children.insert(new_vec_with_capacity(0));
+            let _ = self.parents.insert(Some(parent));
+            new_children.push(id);
+        }
+
+        self.children[parent_key].extend(new_children.iter().copied());
+        self.mark_dirty(parent)?;
+
+        Ok(new_children)
+    }
+
     /// Inserts a `child` node at the given `child_index` under the supplied `parent`, shifting all children after it to the right.
     pub fn insert_child_at_index(&mut self, parent: NodeId, child_index: usize, child: NodeId) -> TaffyResult<()> {
         let parent_key = parent.into();
@@ -511,9 +778,48 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
-    /// Directly sets the `children` of the supplied `parent`
+    /// Directly sets the `children` of the supplied `parent`, replacing its existing children
+    ///
+    /// This can be used to add, remove and reorder children in a single operation which marks the parent as dirty only once.
+    /// Any of the `children` which are currently attached to a different parent are first removed from that parent.
+    ///
+    /// Returns an error (without modifying the tree) if `parent` or any of the `children` is not in the tree, if a node is
+    /// supplied more than once in `children`, or if any of the `children` is `parent` itself or one of its ancestors.
     pub fn set_children(&mut self, parent: NodeId, children: &[NodeId]) -> TaffyResult<()> {
         let parent_key = parent.into();
+        if !self.nodes.contains_key(parent_key) {
+            return Err(TaffyError::InvalidParentNode(parent));
+        }
+
+        // Validate the new children before making any changes
+        if let Some(&child) = children.iter().find(|child| !self.nodes.contains_key((**child).into())) {
+            return Err(TaffyError::InvalidChildNode(child));
+        }
+        let mut sorted_children: Vec<NodeId> = children.iter().copied().collect();
+        sorted_children.sort_unstable_by_key(|child| u64::from(*child));
+        if let Some(pair) = sorted_children.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(TaffyError::DuplicateChildNode { parent, child: pair[0] });
+        }
+        let mut ancestor = Some(parent);
+        while let Some(node) = ancestor {
+            if sorted_children.binary_search_by_key(&u64::from(node), |child| u64::from(*child)).is_ok() {
+                return Err(TaffyError::CyclicChildNode { parent, child: node });
+            }
+            ancestor = self.parents[node.into()];
+        }
+
+        // Remove each child from its previous parent (if any), updating each previous parent's children only once
+        let mut previous_parents: Vec<NodeId> =
+            children.iter().filter_map(|child| self.parents[(*child).into()]).filter(|p| *p != parent).collect();
+        previous_parents.sort_unstable_by_key(|previous_parent| u64::from(*previous_parent));
+        previous_parents.dedup();
+        for previous_parent in &previous_parents {
+            if let Some(previous_siblings) = self.children.get_mut((*previous_parent).into()) {
+                previous_siblings.retain(|sibling| {
+                    sorted_children.binary_search_by_key(&u64::from(*sibling), |child| u64::from(*child)).is_err()
+                });
+            }
+        }
 
         // Remove node as parent from all its current children.
         for child in &self.children[parent_key] {
@@ -521,14 +827,17 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
 
         // Build up relation node <-> child
-        for child in children {
-            self.parents[(*child).into()] = Some(parent);
+        for &child in children {
+            self.parents[child.into()] = Some(parent);
         }
 
         let parent_children = &mut self.children[parent_key];
         parent_children.clear();
         children.iter().for_each(|child| parent_children.push(*child));
 
+        for previous_parent in previous_parents {
+            self.mark_dirty(previous_parent)?;
+        }
         self.mark_dirty(parent)?;
 
         Ok(())
@@ -585,6 +894,27 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(old_child)
     }
 
+    /// Swaps the positions of the children at `child_index_a` and `child_index_b` of the `parent` node
+    ///
+    /// Only the `parent` (and its ancestors) are marked dirty: the layouts cached by the swapped children (and their
+    /// descendants) remain valid, as reordering the children does not change their styles or contents.
+    pub fn swap_children(&mut self, parent: NodeId, child_index_a: usize, child_index_b: usize) -> TaffyResult<()> {
+        let parent_key = parent.into();
+        let child_count = self.children[parent_key].len();
+        for child_index in [child_index_a, child_index_b] {
+            if child_index >= child_count {
+                return Err(TaffyError::ChildIndexOutOfBounds { parent, child_index, child_count });
+            }
+        }
+
+        if child_index_a != child_index_b {
+            self.children[parent_key].swap(child_index_a, child_index_b);
+            self.mark_dirty(parent)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the child node of the parent `node` at the provided `child_index`
     pub fn child_at_index(&self, parent: NodeId, child_index: usize) -> TaffyResult<NodeId> {
         let parent_key = parent.into();
@@ -606,13 +936,82 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(self.children[parent.into()].iter().copied().collect::<_>())
     }
 
+    /// Returns the children of `parent` in the order in which they should be painted (back to front)
+    ///
+    /// Children are sorted by their [`Style::z_index`]. Children with the same `z_index` are painted in the order
+    /// in which they appear in `parent`, except that absolutely positioned children are painted after the in-flow
+    /// children. Children with `Display::None` are not painted, so they are omitted.
+    pub fn paint_order_children(&self, parent: NodeId) -> TaffyResult<Vec<NodeId>> {
+        let mut children: Vec<NodeId> = self.children[parent.into()]
+            .iter()
+            .copied()
+            .filter(|child| self.nodes[(*child).into()].style.display != Display::None)
+            .collect();
+        // The sort is stable, so children that compare equal remain in source order
+        children.sort_by_key(|child| {
+            let style = &self.nodes[(*child).into()].style;
+            (style.z_index, style.position == Position::Absolute)
+        });
+        Ok(children)
+    }
+
+    /// Returns an iterator over all of the descendants of `node` (not including `node` itself) in depth-first order,
+    /// with each node's children visited in order. The iterator is lazy: it only stores the path to the current node.
+    pub fn descendants(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let stack = core::iter::once(self.children[node.into()].iter()).collect();
+        TaffyTreeDescendantsIter { children: &self.children, stack }
+    }
+
     /// Sets the [`Style`] of the provided `node`
+    ///
+    /// In debug builds with the `debug` feature enabled, any warnings returned by [`Style::validate`] are logged.
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
+        #[cfg(all(debug_assertions, feature = "debug"))]
+        for warning in style.validate() {
+            debug_log!("style warning:", warning);
+        }
         self.nodes[node.into()].style = style;
         self.mark_dirty(node)?;
         Ok(())
     }
 
+    /// Sets the [`Style`] of each of the provided nodes
+    ///
+    /// This is equivalent to calling [`TaffyTree::set_style`] for each node, except that ancestors shared by several
+    /// of the nodes are only marked dirty once. If a node appears more than once then the last of its styles is used.
+    /// If any of the nodes is not in the tree then an error is returned and no styles are changed.
+    pub fn set_styles(&mut self, styles: &[(NodeId, Style)]) -> TaffyResult<()> {
+        if let Some((node, _)) = styles.iter().find(|(node, _)| !self.nodes.contains_key((*node).into())) {
+            return Err(TaffyError::InvalidInputNode(*node));
+        }
+
+        for (node, style) in styles {
+            self.nodes[(*node).into()].style = style.clone();
+        }
+        self.mark_dirty_many(styles.iter().map(|(node, _)| *node));
+
+        Ok(())
+    }
+
+    /// Modifies the [`Style`] of the provided `node` in place, which avoids cloning the whole style (including its grid
+    /// track lists) in order to change a single property
+    ///
+    /// The closure must return whether it changed the style, and the node (and its ancestors) are only marked dirty if
+    /// it returns `true`. This makes it cheap to update many nodes (for example, in an animation system) when only some
+    /// of them have actually changed. If the closure changes the style but returns `false` then the node's cached
+    /// layout will be out of date: call [`TaffyTree::mark_dirty`] to correct this.
+    pub fn with_style_mut(&mut self, node: NodeId, f: impl FnOnce(&mut Style) -> bool) -> TaffyResult<()> {
+        let node_data = self.nodes.get_mut(node.into()).ok_or(TaffyError::InvalidInputNode(node))?;
+        if f(&mut node_data.style) {
+            #[cfg(all(debug_assertions, feature = "debug"))]
+            for warning in node_data.style.validate() {
+                debug_log!("style warning:", warning);
+            }
+            self.mark_dirty(node)?;
+        }
+        Ok(())
+    }
+
     /// Gets the [`Style`] of the provided `node`
     pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
         Ok(&self.nodes[node.into()].style)
@@ -627,6 +1026,154 @@ impl<NodeContext> TaffyTree<NodeContext> {
         }
     }
 
+    /// Returns the first baseline of `node` computed by the most recent layout, as an offset from the top of its border
+    /// box. This can be used to align a node with surrounding text.
+    ///
+    /// Flexbox and CSS Grid containers take their baseline from their first row of items (synthesizing it from the
+    /// bottom edge of an item that has no baseline of its own), and leaf nodes from their [`Style::line_metrics`].
+    /// Returns `None` if the node has no baseline, which is the case for block containers and for leaf nodes without
+    /// line metrics. The baseline is never rounded, even if rounding is enabled.
+    pub fn baseline(&self, node: NodeId) -> TaffyResult<Option<f32>> {
+        match self.nodes.get(node.into()) {
+            Some(node_data) => Ok(node_data.first_baseline),
+            None => Err(TaffyError::InvalidInputNode(node)),
+        }
+    }
+
+    /// Returns the min-content and max-content sizes of `node` (in that order) that were computed by the most recent
+    /// layout, for example to snap a resizable panel to the size of its content
+    ///
+    /// Intrinsic sizes are only computed when a node's parent needs them to size it (for example to compute the flex
+    /// basis of a flex item with an `auto` size, or to size an `auto` grid track), so this returns `None` if either
+    /// size was not computed. This is always the case for the root node. The sizes are never rounded, even if rounding
+    /// is enabled.
+    pub fn intrinsic_sizes(&self, node: NodeId) -> TaffyResult<Option<(Size<f32>, Size<f32>)>> {
+        match self.nodes.get(node.into()) {
+            Some(node_data) => Ok(node_data.cache.min_content_size().zip(node_data.cache.max_content_size())),
+            None => Err(TaffyError::InvalidInputNode(node)),
+        }
+    }
+
+    /// Sets an offset that is added to the location of `node` in its computed [`Layout`]
+    ///
+    /// The offset does not affect the layout of any node: it only changes the location that is reported for `node`
+    /// (and thus the absolute position of its descendants). This makes it suitable for animating a node's position on
+    /// top of a computed layout, as it takes effect immediately without needing to recompute the layout.
+    ///
+    /// If rounding is enabled then the rounded layouts of `node` and its descendants are updated to match its new
+    /// position, as rounding depends on the absolute position of each node.
+    pub fn set_layout_offset(&mut self, node: NodeId, offset: Point<f32>) -> TaffyResult<()> {
+        let use_rounding = self.config.use_rounding;
+        let node_data = self.nodes.get_mut(node.into()).ok_or(TaffyError::InvalidInputNode(node))?;
+        let location = node_data.unrounded_layout.location;
+        let unrounded_location = Point {
+            x: location.x - node_data.layout_offset.x + offset.x,
+            y: location.y - node_data.layout_offset.y + offset.y,
+        };
+        node_data.layout_offset = offset;
+        node_data.unrounded_layout.location = unrounded_location;
+        if !use_rounding {
+            node_data.final_layout.location = unrounded_location;
+            return Ok(());
+        }
+
+        // Rounded positions depend on the cumulative position of each node, so the node's whole subtree is re-rounded
+        // relative to the (unrounded) position of its parent, exactly as `round_layout` would have rounded it
+        let mut parent_origin = Point::ZERO;
+        let mut ancestor = self.parents[node.into()];
+        while let Some(parent) = ancestor {
+            parent_origin = parent_origin + self.nodes[parent.into()].unrounded_layout.location;
+            ancestor = self.parents[parent.into()];
+        }
+        let mut taffy_view = TaffyView { taffy: self, measure_function: |_, _, _, _| Size::ZERO };
+        round_subtree_layout(&mut taffy_view, node, parent_origin);
+        Ok(())
+    }
+
+    /// Gets the offset that is added to the location of `node` in its computed [`Layout`].
+    /// See [`TaffyTree::set_layout_offset`].
+    pub fn layout_offset(&self, node: NodeId) -> TaffyResult<Point<f32>> {
+        match self.nodes.get(node.into()) {
+            Some(node_data) => Ok(node_data.layout_offset),
+            None => Err(TaffyError::InvalidInputNode(node)),
+        }
+    }
+
+    /// Computes where the content of `node` should be broken in order to split it into fragments (such as printed
+    /// pages) of the given height, using the most recently computed layout
+    ///
+    /// Returns the offset of each break from the top of `node`'s border box, in ascending order. Content is broken at
+    /// the end of each fragment, except that where a descendant with [`BreakInside::Avoid`] would be split between two
+    /// fragments (and it fits within a single fragment) the break is moved up to the top of that descendant instead.
+    /// Descendants are considered in order of their vertical position, so this is intended for nodes whose content is
+    /// laid out vertically (such as block containers and column flex containers).
+    pub fn fragment_breaks(&self, node: NodeId, fragment_height: f32) -> TaffyResult<Vec<f32>> {
+        /// Breaks at the end of each fragment until `offset` is within the current fragment
+        fn break_until(offset: f32, fragment_height: f32, fragment_end: &mut f32, breaks: &mut Vec<f32>) {
+            while offset > *fragment_end {
+                breaks.push(*fragment_end);
+                *fragment_end += fragment_height;
+            }
+        }
+
+        fn collect_breaks(
+            tree: &TaffyTree<impl Sized>,
+            node: NodeId,
+            node_top: f32,
+            fragment_height: f32,
+            fragment_end: &mut f32,
+            breaks: &mut Vec<f32>,
+        ) {
+            let mut children: Vec<(NodeId, Layout)> = tree.children[node.into()]
+                .iter()
+                .copied()
+                .filter(|&child| {
+                    let style = &tree.nodes[child.into()].style;
+                    style.display != Display::None && style.position != Position::Absolute
+                })
+                .map(|child| (child, *tree.layout(child).unwrap()))
+                .collect();
+            children.sort_by(|(_, a), (_, b)| a.location.y.total_cmp(&b.location.y));
+
+            for (child, layout) in children {
+                let top = node_top + layout.location.y;
+                let bottom = top + layout.size.height;
+                if bottom <= *fragment_end {
+                    continue;
+                }
+
+                // Content that starts after the end of the current fragment is pushed into a later fragment
+                while top >= *fragment_end {
+                    breaks.push(*fragment_end);
+                    *fragment_end += fragment_height;
+                }
+
+                let fragment_start = *fragment_end - fragment_height;
+                let avoid_break = tree.nodes[child.into()].style.break_inside == BreakInside::Avoid;
+                if avoid_break && layout.size.height <= fragment_height && top > fragment_start {
+                    breaks.push(top);
+                    *fragment_end = top + fragment_height;
+                } else {
+                    collect_breaks(tree, child, top, fragment_height, fragment_end, breaks);
+                    break_until(bottom, fragment_height, fragment_end, breaks);
+                }
+            }
+        }
+
+        if !self.nodes.contains_key(node.into()) {
+            return Err(TaffyError::InvalidInputNode(node));
+        }
+        let height = self.layout(node)?.size.height;
+        let mut breaks = Vec::new();
+        if fragment_height.is_nan() || fragment_height <= 0.0 {
+            return Ok(breaks);
+        }
+        let mut fragment_end = fragment_height;
+        collect_breaks(self, node, 0.0, fragment_height, &mut fragment_end, &mut breaks);
+        break_until(height, fragment_height, &mut fragment_end, &mut breaks);
+        Ok(breaks)
+    }
+
     /// Marks the layout computation of this node and its children as outdated
     ///
     /// Performs a recursive depth-first search up the tree until the root node is reached
@@ -651,12 +1198,88 @@ impl<NodeContext> TaffyTree<NodeContext> {
         Ok(())
     }
 
+    /// Marks the layout computation of each of the provided nodes (and their ancestors) as outdated, visiting each node
+    /// at most once. Returns the number of nodes that were marked.
+    fn mark_dirty_many(&mut self, nodes: impl Iterator<Item = NodeId>) -> usize {
+        let mut marked: SecondaryMap<DefaultKey, ()> = SecondaryMap::new();
+        for node in nodes {
+            let mut node_key: DefaultKey = node.into();
+            // Stop as soon as we reach a node that has already been marked, as its ancestors will have been too
+            while marked.insert(node_key, ()).is_none() {
+                self.nodes[node_key].mark_dirty();
+                match self.parents.get(node_key) {
+                    Some(Some(parent)) => node_key = (*parent).into(),
+                    _ => break,
+                }
+            }
+        }
+        marked.len()
+    }
+
+    /// Marks the layout computation of every node in the tree as outdated, so that the next layout is a full layout of
+    /// every node. This is useful when something that affects every node has changed (such as the scale factor used by
+    /// measure functions).
+    ///
+    /// This is equivalent to [`TaffyTree::clear_caches`].
+    pub fn mark_dirty_all(&mut self) {
+        self.clear_caches();
+    }
+
+    /// Clears the cached layout results of every node in the tree. This marks every node as dirty (as a node is dirty
+    /// if it has no cached layout results), but the most recently computed [`Layout`] of each node is retained.
+    ///
+    /// Note that each node's cache is stored inline with the node, so clearing the caches does not free any memory.
+    /// See [`TaffyTree::cache_memory_estimate`].
+    pub fn clear_caches(&mut self) {
+        for (_, node_data) in self.nodes.iter_mut() {
+            node_data.mark_dirty();
+        }
+    }
+
+    /// Returns an estimate of the number of bytes used by the layout caches of all of the nodes in the tree
+    ///
+    /// A fixed amount of memory is reserved for the cache of each node when it is created, so this is proportional to
+    /// the number of nodes in the tree, and does not change when layout is computed or the caches are cleared.
+    pub fn cache_memory_estimate(&self) -> usize {
+        self.nodes.len() * core::mem::size_of::<Cache>()
+    }
+
     /// Indicates whether the layout of this node (and its children) need to be recomputed
+    ///
+    /// A node is dirty if it has no cached layout results. This is the case if it (or one of its descendants) has been
+    /// marked as dirty since the last time layout was computed, or if it has never been laid out.
     pub fn dirty(&self, node: NodeId) -> TaffyResult<bool> {
-        Ok(self.nodes[node.into()].cache.is_empty())
+        match self.nodes.get(node.into()) {
+            Some(node_data) => Ok(node_data.cache.is_empty()),
+            None => Err(TaffyError::InvalidInputNode(node)),
+        }
+    }
+
+    /// Indicates whether the layout of this node was computed by the most recent call to [`TaffyTree::compute_layout`]
+    /// (or [`TaffyTree::compute_layout_with_measure`]), rather than being retrieved from its cache or not being laid out
+    /// at all
+    ///
+    /// This is intended for debugging and testing incremental layout. For example, after modifying the style of a
+    /// single node and recomputing layout, only that node and its ancestors would usually be recomputed.
+    pub fn was_recomputed(&self, node: NodeId) -> TaffyResult<bool> {
+        match self.nodes.get(node.into()) {
+            Some(node_data) => Ok(node_data.computed_generation == self.layout_generation),
+            None => Err(TaffyError::InvalidInputNode(node)),
+        }
+    }
+
+    /// Returns an iterator over all of the nodes in the tree that are currently dirty (see [`TaffyTree::dirty`]),
+    /// in no particular order
+    pub fn dirty_nodes(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes.iter().filter(|(_, node_data)| node_data.cache.is_empty()).map(|(key, _)| NodeId::from(key))
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// The `measure_function` is called to size leaf nodes. It is passed the node's known dimensions and available
+    /// space, and should return the size of the node's content. All three are content-box sizes: Taffy subtracts the
+    /// node's padding, border and scrollbar gutter before calling the measure function, and adds them back on to the
+    /// size that it returns.
     pub fn compute_layout_with_measure<MeasureFunction>(
         &mut self,
         node_id: NodeId,
@@ -666,24 +1289,219 @@ impl<NodeContext> TaffyTree<NodeContext> {
     where
         MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>) -> Size<f32>,
     {
+        self.compute_layout_inner(node_id, Point::ZERO, available_space, measure_function)
+    }
+
+    /// Lays out `node_id` and its children, placing `node_id` at `origin`
+    fn compute_layout_inner<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        origin: Point<f32>,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>) -> Size<f32>,
+    {
+        #[cfg(feature = "profile")]
+        let outer_stats = crate::tree::stats::start_run();
+
+        self.layout_generation = self.layout_generation.wrapping_add(1);
+        #[cfg(feature = "fixed-capacity-grids")]
+        {
+            self.grid_track_limit_exceeded = None;
+        }
         let use_rounding = self.config.use_rounding;
         let mut taffy_view = TaffyView { taffy: self, measure_function };
         compute_root_layout(&mut taffy_view, node_id, available_space);
+        let root_layout = &mut taffy_view.taffy.nodes[node_id.into()].unrounded_layout;
+        root_layout.location = root_layout.location + origin;
         if use_rounding {
             round_layout(&mut taffy_view, node_id);
         }
+
+        #[cfg(feature = "profile")]
+        {
+            self.last_layout_stats = crate::tree::stats::finish_run(outer_stats);
+        }
+
+        // The grid container was laid out as if it were empty. Mark it dirty so that the error is reported again
+        // (rather than the cached layout being reused) the next time that layout is computed.
+        #[cfg(feature = "fixed-capacity-grids")]
+        if let Some(GridTrackLimitExceeded { node, track_count }) = self.grid_track_limit_exceeded.take() {
+            self.mark_dirty(node)?;
+            return Err(TaffyError::GridTrackLimitExceeded { node, track_count });
+        }
+
         Ok(())
     }
 
     /// Updates the stored layout of the provided `node` and its children
+    ///
+    /// A root node whose size is not set by its style fills any [`AvailableSpace::Definite`] space that it is given
+    /// (as a block-level box fills its containing block). To instead size the root to fit its content up to a limit
+    /// (for example the size of a window), lay it out under a max-content constraint and set the limit as its
+    /// `max_size`:
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// let mut taffy: TaffyTree<()> = TaffyTree::new();
+    /// let child_size = Size { width: length(300.0), height: length(200.0) };
+    /// let child = taffy.new_leaf(Style { size: child_size, ..Default::default() }).unwrap();
+    /// let window_size = Size { width: length(800.0), height: length(600.0) };
+    /// let root = taffy.new_with_children(Style { max_size: window_size, ..Default::default() }, &[child]).unwrap();
+    ///
+    /// taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    /// assert_eq!(taffy.layout(root).unwrap().size, Size { width: 300.0, height: 200.0 });
+    /// ```
     pub fn compute_layout(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> Result<(), TaffyError> {
         self.compute_layout_with_measure(node, available_space, |_, _, _, _| Size::ZERO)
     }
 
+    /// Updates the stored layout of the provided `node` and its children, placing `node` at `origin` rather than at
+    /// `(0, 0)`
+    ///
+    /// As the locations of children are relative to their parent, only the location of `node` itself is affected.
+    /// When rounding is enabled, descendants are snapped to whole pixels relative to `origin`.
+    ///
+    /// ```rust
+    /// # use taffy::prelude::*;
+    /// # use taffy::geometry::Point;
+    /// let mut taffy: TaffyTree<()> = TaffyTree::new();
+    /// let panel = taffy.new_leaf(Style { size: length(100.0), ..Default::default() }).unwrap();
+    ///
+    /// taffy.compute_layout_at(panel, Point { x: 40.0, y: 25.0 }, Size::MAX_CONTENT).unwrap();
+    /// assert_eq!(taffy.layout(panel).unwrap().location, Point { x: 40.0, y: 25.0 });
+    /// ```
+    pub fn compute_layout_at(
+        &mut self,
+        node: NodeId,
+        origin: Point<f32>,
+        available_space: Size<AvailableSpace>,
+    ) -> Result<(), TaffyError> {
+        self.compute_layout_inner(node, origin, available_space, |_, _, _, _| Size::ZERO)
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root node (as by
+    /// [`TaffyTree::compute_layout_with_measure`]) in the given available space, without performing a full layout
+    ///
+    /// Only the sizing passes of the layout algorithms are run, so no stored [`Layout`] is changed and no node is
+    /// marked dirty. This allows alternatives to be measured speculatively (for example, to find how tall some content
+    /// would be at a particular width). Measuring doesn't affect the results of the most recent layout: the layout
+    /// caches (and thus [`TaffyTree::intrinsic_sizes`]) and [`TaffyTree::was_recomputed`] are left unchanged.
+    pub fn measure_node_with_measure<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<Size<f32>>
+    where
+        MeasureFunction: FnMut(Size<Option<f32>>, Size<AvailableSpace>, NodeId, Option<&mut NodeContext>) -> Size<f32>,
+    {
+        if !self.nodes.contains_key(node_id.into()) {
+            return Err(TaffyError::InvalidInputNode(node_id));
+        }
+
+        /// The parts of a node's data that measuring may modify
+        struct SavedLayoutState {
+            /// The node whose state was saved
+            node: NodeId,
+            /// See [`NodeData::cache`]
+            cache: Cache,
+            /// See [`NodeData::unrounded_layout`]
+            unrounded_layout: Layout,
+            /// See [`NodeData::final_layout`]
+            final_layout: Layout,
+            /// See [`NodeData::first_baseline`]
+            first_baseline: Option<f32>,
+            /// See [`NodeData::computed_generation`]
+            computed_generation: u32,
+        }
+
+        // Sizing a node may still perform the layout of some of its descendants (for example, to find the baselines of
+        // flex items), so the state of every measured node is restored afterwards. This ensures that the speculative
+        // measurements neither change any stored layout nor evict the cache entries stored by the most recent layout.
+        let saved_state: Vec<SavedLayoutState> = core::iter::once(node_id)
+            .chain(self.descendants(node_id))
+            .map(|node| {
+                let node_data = &self.nodes[node.into()];
+                SavedLayoutState {
+                    node,
+                    cache: node_data.cache.clone(),
+                    unrounded_layout: node_data.unrounded_layout,
+                    final_layout: node_data.final_layout,
+                    first_baseline: node_data.first_baseline,
+                    computed_generation: node_data.computed_generation,
+                }
+            })
+            .collect();
+
+        let use_rounding = self.config.use_rounding;
+        let mut taffy_view = TaffyView { taffy: self, measure_function };
+        let parent_size = available_space.into_options();
+        let width = taffy_view.measure_child_size(
+            node_id,
+            Size::NONE,
+            parent_size,
+            available_space,
+            SizingMode::InherentSize,
+            AbsoluteAxis::Horizontal,
+            Line::FALSE,
+        );
+        let height = taffy_view.measure_child_size(
+            node_id,
+            Size { width: Some(width), height: None },
+            parent_size,
+            available_space,
+            SizingMode::InherentSize,
+            AbsoluteAxis::Vertical,
+            Line::FALSE,
+        );
+
+        for state in saved_state {
+            let node_data = &mut self.nodes[state.node.into()];
+            node_data.cache = state.cache;
+            node_data.unrounded_layout = state.unrounded_layout;
+            node_data.final_layout = state.final_layout;
+            node_data.first_baseline = state.first_baseline;
+            node_data.computed_generation = state.computed_generation;
+        }
+
+        let size = Size { width, height };
+        Ok(if use_rounding { size.map(round) } else { size })
+    }
+
+    /// Computes the size that `node` would have if it were laid out as a root node in the given available space,
+    /// without performing a full layout. See [`TaffyTree::measure_node_with_measure`].
+    pub fn measure_node(&mut self, node: NodeId, available_space: Size<AvailableSpace>) -> TaffyResult<Size<f32>> {
+        self.measure_node_with_measure(node, available_space, |_, _, _, _| Size::ZERO)
+    }
+
+    /// Returns statistics about the work performed by the most recent call to [`TaffyTree::compute_layout`] or
+    /// [`TaffyTree::compute_layout_with_measure`]
+    #[cfg(feature = "profile")]
+    pub fn last_layout_stats(&self) -> LayoutStats {
+        self.last_layout_stats
+    }
+
     /// Prints a debug representation of the tree's layout
+    ///
+    /// If the `profile` feature is enabled then the statistics for the most recent layout run are also printed.
     #[cfg(feature = "std")]
     pub fn print_tree(&mut self, root: NodeId) {
-        crate::util::print_tree(self, root)
+        crate::util::print_tree(self, root);
+        #[cfg(feature = "profile")]
+        println!("{}", self.last_layout_stats);
+    }
+
+    /// Returns a diagnostic report comparing the specified `size`, `min_size` and `max_size` of each node with its
+    /// computed size, flagging where the size was clamped by a min or max size. See [`crate::util::sizing_report`].
+    ///
+    /// The report is intended to be read by humans (for example when investigating why a node has an unexpected
+    /// size) and its format may change at any time.
+    #[cfg(feature = "std")]
+    pub fn sizing_report(&self, root: NodeId) -> String {
+        crate::util::sizing_report(self, root, |node| &self.nodes[node.into()].style)
     }
 
     /// Returns an instance of LayoutTree representing the TaffyTree
@@ -800,11 +1618,42 @@ mod tests {
     }
 
     #[test]
-    fn remove_last_node() {
+    fn remove_subtree() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
 
-        let parent = taffy.new_leaf(Style::default()).unwrap();
-        let child = taffy.new_leaf(Style::default()).unwrap();
+        // <root> <- <node0> <- [<node1> <- <node2>, <node3>]
+        let node2 = taffy.new_leaf(Style::default()).unwrap();
+        let node1 = taffy.new_with_children(Style::default(), &[node2]).unwrap();
+        let node3 = taffy.new_leaf(Style::default()).unwrap();
+        let node0 = taffy.new_with_children(Style::default(), &[node1, node3]).unwrap();
+        let sibling = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[node0, sibling]).unwrap();
+        taffy.set_node_key(node2, Some(2)).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(taffy.remove_subtree(node0).unwrap(), vec![node0, node1, node2, node3]);
+
+        // All of the removed ids are now invalid
+        for node in [node0, node1, node2, node3] {
+            assert!(matches!(taffy.set_node_key(node, None), Err(TaffyError::InvalidInputNode(_))));
+            assert!(matches!(taffy.remove_subtree(node), Err(TaffyError::InvalidInputNode(_))));
+        }
+        assert_eq!(taffy.node_for_key(2), None);
+        assert_eq!(taffy.total_node_count(), 2);
+
+        // The rest of the tree is unaffected (other than being marked dirty)
+        assert_eq!(taffy.children(root).unwrap(), vec![sibling]);
+        assert!(taffy.dirty(root).unwrap());
+        assert_eq!(taffy.remove_subtree(root).unwrap(), vec![root, sibling]);
+        assert_eq!(taffy.total_node_count(), 0);
+    }
+
+    #[test]
+    fn remove_last_node() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let parent = taffy.new_leaf(Style::default()).unwrap();
+        let child = taffy.new_leaf(Style::default()).unwrap();
         taffy.add_child(parent, child).unwrap();
 
         taffy.remove(child).unwrap();
@@ -835,6 +1684,119 @@ mod tests {
         assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
     }
 
+    #[test]
+    #[cfg(feature = "grid")]
+    fn intrinsic_sizes() {
+        // Wraps 10px wide words onto 10px tall lines
+        fn text_measure_function(
+            known_dimensions: Size<Option<f32>>,
+            available_space: Size<AvailableSpace>,
+            _node_id: NodeId,
+            node_context: Option<&mut f32>,
+        ) -> Size<f32> {
+            let text_width = match node_context {
+                Some(text_width) => *text_width,
+                None => return Size::ZERO,
+            };
+            let width = known_dimensions.width.unwrap_or(match available_space.width {
+                AvailableSpace::Definite(width) => width.clamp(10.0, text_width),
+                AvailableSpace::MinContent => 10.0,
+                AvailableSpace::MaxContent => text_width,
+            });
+            Size { width, height: known_dimensions.height.unwrap_or(10.0 * (text_width / width).ceil()) }
+        }
+
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        let text = taffy.new_leaf_with_context(Style::default(), 50.0).unwrap();
+        let fixed = taffy.new_leaf(Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() }).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), length(40.0)],
+                    ..Default::default()
+                },
+                &[text, fixed],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, text_measure_function).unwrap();
+
+        // The text is measured to size its `auto` track...
+        let min_content_size = Size { width: 10.0, height: 50.0 };
+        let max_content_size = Size { width: 50.0, height: 10.0 };
+        assert_eq!(taffy.intrinsic_sizes(text).unwrap(), Some((min_content_size, max_content_size)));
+        // ...but neither the item in the fixed size track nor the root needs to be
+        assert_eq!(taffy.intrinsic_sizes(fixed).unwrap(), None);
+        assert_eq!(taffy.intrinsic_sizes(root).unwrap(), None);
+
+        taffy.mark_dirty(text).unwrap();
+        assert_eq!(taffy.intrinsic_sizes(text).unwrap(), None);
+    }
+
+    #[test]
+    fn clear_measure() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let node = taffy.new_leaf_with_context(Style::default(), Size { width: 200.0, height: 200.0 }).unwrap();
+        assert!(taffy.has_node_context(node));
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+
+        taffy.set_node_context(node, None).unwrap();
+        assert!(!taffy.has_node_context(node));
+        assert!(taffy.dirty(node).unwrap());
+        taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 0.0);
+    }
+
+    #[test]
+    fn update_measure_context_in_place() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let node = taffy.new_leaf_with_context(Style::default(), Size { width: 200.0, height: 200.0 }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[node]).unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, size_measure_function).unwrap();
+
+        // Modifying the context in place doesn't invalidate the cached layout...
+        *taffy.get_node_context_mut(node).unwrap() = Size { width: 100.0, height: 100.0 };
+        assert!(!taffy.dirty(node).unwrap());
+
+        // ...until the node is explicitly marked as dirty
+        taffy.mark_dirty(node).unwrap();
+        assert!(taffy.dirty(root).unwrap());
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, size_measure_function).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
+        assert_eq!(taffy.layout(root).unwrap().size.width, 100.0);
+    }
+
+    #[test]
+    fn add_children_batch() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let existing_child = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[existing_child]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let styles = [
+            Style { size: length(10.0), ..Default::default() },
+            Style { display: Display::None, ..Default::default() },
+            Style { flex_grow: 1.0, ..Default::default() },
+        ];
+        let new_children = taffy.add_children_batch(node, styles.iter().cloned()).unwrap();
+
+        assert_eq!(new_children.len(), 3);
+        assert_eq!(taffy.children(node).unwrap(), [&[existing_child][..], &new_children].concat());
+        for (child, style) in new_children.iter().zip(styles.iter()) {
+            assert_eq!(taffy.style(*child).unwrap(), style);
+            assert_eq!(taffy.parents[(*child).into()], Some(node));
+            assert_eq!(taffy.child_count(*child), 0);
+        }
+        assert!(taffy.dirty(node).unwrap());
+
+        // Adding to a node that is not in the tree is an error
+        taffy.remove(existing_child).unwrap();
+        assert!(matches!(
+            taffy.add_children_batch(existing_child, styles),
+            Err(TaffyError::InvalidParentNode(parent)) if parent == existing_child
+        ));
+    }
+
     /// Test that adding `add_child()` works
     #[test]
     fn add_child() {
@@ -899,6 +1861,212 @@ mod tests {
         assert_eq!(taffy.children(node).unwrap()[1], child3);
     }
 
+    #[test]
+    fn set_children_reorders_removes_and_adds() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let child0 = taffy.new_leaf(Style::default()).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let child2 = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1, child2]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[node]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let child3 = taffy.new_leaf(Style::default()).unwrap();
+        taffy.set_children(node, &[child2, child3, child0]).unwrap();
+
+        assert_eq!(taffy.children(node).unwrap(), vec![child2, child3, child0]);
+        assert_eq!(taffy.parents[child0.into()], Some(node));
+        assert_eq!(taffy.parents[child1.into()], None);
+        assert_eq!(taffy.parents[child2.into()], Some(node));
+        assert_eq!(taffy.parents[child3.into()], Some(node));
+        assert!(taffy.dirty(node).unwrap());
+        assert!(taffy.dirty(root).unwrap());
+    }
+
+    #[test]
+    fn set_children_moves_child_from_previous_parent() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let child0 = taffy.new_leaf(Style::default()).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let previous_parent = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        taffy.compute_layout(previous_parent, Size::MAX_CONTENT).unwrap();
+
+        taffy.set_children(node, &[child1]).unwrap();
+
+        assert_eq!(taffy.children(node).unwrap(), vec![child1]);
+        assert_eq!(taffy.children(previous_parent).unwrap(), vec![child0]);
+        assert_eq!(taffy.parents[child1.into()], Some(node));
+        assert!(taffy.dirty(previous_parent).unwrap());
+    }
+
+    #[test]
+    fn set_children_moves_children_from_several_parents() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let leaves: Vec<NodeId> = (0..5).map(|_| taffy.new_leaf(Style::default()).unwrap()).collect();
+        let parent0 = taffy.new_with_children(Style::default(), &leaves[0..3]).unwrap();
+        let parent1 = taffy.new_with_children(Style::default(), &leaves[3..5]).unwrap();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[parent0, parent1, node]).unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        taffy.set_children(node, &[leaves[4], leaves[0], leaves[2]]).unwrap();
+
+        assert_eq!(taffy.children(node).unwrap(), vec![leaves[4], leaves[0], leaves[2]]);
+        assert_eq!(taffy.children(parent0).unwrap(), vec![leaves[1]]);
+        assert_eq!(taffy.children(parent1).unwrap(), vec![leaves[3]]);
+        let mut dirty_nodes: Vec<NodeId> = taffy.dirty_nodes().collect();
+        dirty_nodes.sort_by_key(|node| u64::from(*node));
+        assert_eq!(dirty_nodes, vec![parent0, parent1, node, root]);
+    }
+
+    #[test]
+    fn set_children_rejects_invalid_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let child = taffy.new_leaf(Style::default()).unwrap();
+        let removed = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(removed).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[node]).unwrap();
+
+        let result = taffy.set_children(node, &[child, child]);
+        let expected_error = TaffyError::DuplicateChildNode { parent: node, child };
+        assert!(matches!(result, Err(TaffyError::DuplicateChildNode { .. })));
+        assert_eq!(result.unwrap_err().to_string(), expected_error.to_string());
+        let result = taffy.set_children(node, &[root]);
+        assert!(matches!(result, Err(TaffyError::CyclicChildNode { parent: p, child: c }) if p == node && c == root));
+        let result = taffy.set_children(node, &[node]);
+        assert!(matches!(result, Err(TaffyError::CyclicChildNode { .. })));
+        let result = taffy.set_children(node, &[removed]);
+        assert!(matches!(result, Err(TaffyError::InvalidChildNode(c)) if c == removed));
+        let result = taffy.set_children(removed, &[child]);
+        assert!(matches!(result, Err(TaffyError::InvalidParentNode(p)) if p == removed));
+
+        // The tree is left unmodified
+        assert_eq!(taffy.children(node).unwrap(), vec![child]);
+        assert_eq!(taffy.parents[child.into()], Some(node));
+        assert_eq!(taffy.parents[node.into()], Some(root));
+    }
+
+    #[test]
+    fn descendants() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let grandchild0 = taffy.new_leaf(Style::default()).unwrap();
+        let grandchild1 = taffy.new_leaf(Style::default()).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild0, grandchild1]).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let grandchild2 = taffy.new_leaf(Style::default()).unwrap();
+        let child2 = taffy.new_with_children(Style::default(), &[grandchild2]).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1, child2]).unwrap();
+
+        let descendants: Vec<NodeId> = taffy.descendants(node).collect();
+        assert_eq!(descendants, [child0, grandchild0, grandchild1, child1, child2, grandchild2]);
+
+        assert_eq!(taffy.descendants(child0).collect::<Vec<_>>(), [grandchild0, grandchild1]);
+        assert_eq!(taffy.descendants(child1).count(), 0);
+    }
+
+    #[test]
+    fn paint_order_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let absolute = Style { position: Position::Absolute, ..Default::default() };
+
+        let child0 = taffy.new_leaf(Style { z_index: 1, ..Default::default() }).unwrap();
+        let child1 = taffy.new_leaf(absolute.clone()).unwrap();
+        let child2 = taffy.new_leaf(Style::default()).unwrap();
+        let child3 = taffy.new_leaf(Style { z_index: -1, ..absolute.clone() }).unwrap();
+        let child4 = taffy.new_leaf(Style::default()).unwrap();
+        let child5 = taffy.new_leaf(Style { display: Display::None, ..Default::default() }).unwrap();
+        let child6 = taffy.new_leaf(Style { z_index: 1, ..Default::default() }).unwrap();
+        let children = [child0, child1, child2, child3, child4, child5, child6];
+        let node = taffy.new_with_children(Style::default(), &children).unwrap();
+
+        // Sorted by z-index, then in-flow before absolutely positioned, then source order. Hidden children are omitted.
+        let expected = [child3, child2, child4, child1, child0, child6];
+        assert_eq!(taffy.paint_order_children(node).unwrap(), expected);
+
+        // The order doesn't change when layout is computed
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.paint_order_children(node).unwrap(), expected);
+        assert_eq!(taffy.paint_order_children(child0).unwrap(), []);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn sizing_report() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let max_clamped = taffy
+            .new_leaf(Style {
+                size: length(100.0),
+                max_size: Size { width: length(60.0), height: auto() },
+                ..Default::default()
+            })
+            .unwrap();
+        let min_clamped = taffy
+            .new_leaf(Style {
+                size: Size { width: percent(0.25), height: auto() },
+                min_size: Size { width: length(90.0), height: auto() },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .unwrap();
+        let node = taffy
+            .new_with_children(
+                Style { size: Size { width: length(300.0), height: auto() }, ..Default::default() },
+                &[max_clamped, min_clamped],
+            )
+            .unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let report = taffy.sizing_report(node);
+        let lines: Vec<&str> =
+            report.lines().map(|line| line.trim_start_matches(|c: char| c == '│' || c.is_whitespace())).collect();
+        assert_eq!(lines[0], "TREE");
+        assert!(lines[4].starts_with("├──  LEAF [w: 60"));
+        assert_eq!(lines[5], "width  size: 100      min: auto     max: 60       computed: 60 (clamped by max_size)");
+        assert_eq!(lines[6], "height size: 100      min: auto     max: auto     computed: 100");
+        assert_eq!(lines[8], "width  size: 25%      min: 90       max: auto     computed: 90 (clamped by min_size)");
+    }
+
+    #[test]
+    fn node_keys() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+        let node0 = taffy.new_leaf(Style::default()).unwrap();
+        let node1 = taffy.new_leaf(Style::default()).unwrap();
+        assert_eq!(taffy.node_for_key(1), None);
+
+        taffy.set_node_key(node0, Some(1)).unwrap();
+        assert_eq!(taffy.node_for_key(1), Some(node0));
+        assert_eq!(taffy.node_key(node0), Some(1));
+
+        // Changing a node's key releases its old key
+        taffy.set_node_key(node0, Some(2)).unwrap();
+        assert_eq!(taffy.node_for_key(1), None);
+        assert_eq!(taffy.node_for_key(2), Some(node0));
+
+        // Assigning a key which is already in use moves it to the new node
+        taffy.set_node_key(node1, Some(2)).unwrap();
+        assert_eq!(taffy.node_for_key(2), Some(node1));
+        assert_eq!(taffy.node_key(node0), None);
+        assert_eq!(taffy.node_key(node1), Some(2));
+
+        // Removing a node releases its key
+        taffy.remove(node1).unwrap();
+        assert_eq!(taffy.node_for_key(2), None);
+        assert!(taffy.set_node_key(node1, Some(3)).is_err());
+
+        taffy.set_node_key(node0, Some(3)).unwrap();
+        taffy.set_node_key(node0, None).unwrap();
+        assert_eq!(taffy.node_for_key(3), None);
+        assert_eq!(taffy.node_key(node0), None);
+    }
+
     /// Test that removing a child works
     #[test]
     fn remove_child() {
@@ -965,6 +2133,43 @@ mod tests {
         assert_eq!(taffy.child_count(node), 1);
         assert_eq!(taffy.children(node).unwrap()[0], child1);
     }
+
+    #[test]
+    fn swap_children() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grandchild = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+        let child1 = taffy.new_leaf(Style { size: length(20.0), ..Default::default() }).unwrap();
+        let child2 = taffy.new_leaf(Style { size: length(30.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1, child2]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        taffy.swap_children(node, 0, 2).unwrap();
+        assert_eq!(taffy.children(node).unwrap(), [child2, child1, child0]);
+
+        // Only the parent is dirtied, so the children's cached layouts are reused
+        assert!(taffy.dirty(node).unwrap());
+        assert!(!taffy.dirty(child0).unwrap());
+        assert!(!taffy.dirty(grandchild).unwrap());
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.was_recomputed(node).unwrap());
+        assert!(!taffy.was_recomputed(child0).unwrap());
+        assert!(!taffy.was_recomputed(grandchild).unwrap());
+        assert_eq!(taffy.layout(child2).unwrap().location.x, 0.0);
+        assert_eq!(taffy.layout(child1).unwrap().location.x, 30.0);
+        assert_eq!(taffy.layout(child0).unwrap().location.x, 50.0);
+
+        // Swapping a child with itself doesn't dirty the parent
+        taffy.swap_children(node, 1, 1).unwrap();
+        assert!(!taffy.dirty(node).unwrap());
+
+        assert!(matches!(
+            taffy.swap_children(node, 0, 3),
+            Err(TaffyError::ChildIndexOutOfBounds { child_index: 3, child_count: 3, .. })
+        ));
+        assert_eq!(taffy.children(node).unwrap(), [child2, child1, child0]);
+    }
+
     #[test]
     fn test_child_at_index() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
@@ -1063,6 +2268,286 @@ mod tests {
         assert_eq!(taffy.dirty(node).unwrap(), true);
     }
 
+    #[test]
+    #[cfg(feature = "profile")]
+    fn last_layout_stats() {
+        let mut taffy: TaffyTree<Size<f32>> = TaffyTree::new();
+        let leaf0 = taffy.new_leaf(Style::default()).unwrap();
+        let leaf1 = taffy.new_leaf(Style::default()).unwrap();
+        let grid = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[leaf0]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[grid, leaf1]).unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, size_measure_function).unwrap();
+        let stats = taffy.last_layout_stats();
+        assert!(stats.flexbox.nodes_visited > 0);
+        assert!(stats.grid.nodes_visited > 0);
+        assert!(stats.leaf.nodes_visited > 0);
+        assert_eq!(stats.block.nodes_visited, 0);
+        assert_eq!(stats.measure_function_calls, stats.leaf.nodes_visited);
+        // The grid's single item is auto-placed at the first position tried each time the grid is laid out
+        assert_eq!(stats.grid_placement_iterations, stats.grid.nodes_visited);
+        assert_eq!(
+            stats.cache_misses,
+            stats.leaf.nodes_visited + stats.block.nodes_visited + stats.flexbox.nodes_visited + stats.grid.nodes_visited
+        );
+
+        // Laying out again with nothing dirty is satisfied by the root node's cache
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, size_measure_function).unwrap();
+        let stats = taffy.last_layout_stats();
+        assert_eq!(stats.cache_misses, 0);
+        assert_eq!(stats.measure_function_calls, 0);
+        assert!(stats.cache_hits > 0);
+    }
+
+    #[test]
+    fn dirty_of_removed_node_is_an_error() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(node).unwrap();
+
+        assert!(matches!(taffy.dirty(node), Err(TaffyError::InvalidInputNode(n)) if n == node));
+    }
+
+    #[test]
+    fn dirty_nodes() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grandchild = taffy.new_leaf(Style::default()).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild]).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+
+        // Nodes which have never been laid out are dirty
+        let mut dirty_nodes: Vec<NodeId> = taffy.dirty_nodes().collect();
+        dirty_nodes.sort_by_key(|node| u64::from(*node));
+        assert_eq!(dirty_nodes, vec![grandchild, child0, child1, node]);
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.dirty_nodes().count(), 0);
+
+        // Marking a node as dirty also marks its ancestors as dirty
+        taffy.mark_dirty(grandchild).unwrap();
+        let mut dirty_nodes: Vec<NodeId> = taffy.dirty_nodes().collect();
+        dirty_nodes.sort_by_key(|node| u64::from(*node));
+        assert_eq!(dirty_nodes, vec![grandchild, child0, node]);
+    }
+
+    #[test]
+    fn set_styles() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grandchild0 = taffy.new_leaf(Style::default()).unwrap();
+        let grandchild1 = taffy.new_leaf(Style::default()).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild0, grandchild1]).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        let style0 = Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() };
+        let style1 = Style { size: Size::from_lengths(20.0, 20.0), ..Default::default() };
+        taffy.set_styles(&[(grandchild0, style0.clone()), (grandchild1, style1.clone())]).unwrap();
+        assert_eq!(taffy.style(grandchild0).unwrap(), &style0);
+        assert_eq!(taffy.style(grandchild1).unwrap(), &style1);
+
+        // The nodes and their ancestors are dirty, but unrelated nodes are not
+        let mut dirty_nodes: Vec<NodeId> = taffy.dirty_nodes().collect();
+        dirty_nodes.sort_by_key(|node| u64::from(*node));
+        assert_eq!(dirty_nodes, vec![grandchild0, grandchild1, child0, node]);
+
+        // If any node is invalid then no styles are changed
+        let removed = taffy.new_leaf(Style::default()).unwrap();
+        taffy.remove(removed).unwrap();
+        let result = taffy.set_styles(&[(child1, style0.clone()), (removed, style1)]);
+        assert!(matches!(result, Err(TaffyError::InvalidInputNode(n)) if n == removed));
+        assert_eq!(taffy.style(child1).unwrap(), &Style::default());
+    }
+
+    #[test]
+    fn with_style_mut() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy.new_leaf(Style::default()).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        // Nothing is marked dirty if the closure reports that it made no change
+        taffy
+            .with_style_mut(child0, |style| {
+                let changed = style.size.width != auto();
+                style.size.width = auto();
+                changed
+            })
+            .unwrap();
+        assert_eq!(taffy.dirty_nodes().count(), 0);
+
+        taffy
+            .with_style_mut(child0, |style| {
+                style.size.width = length(100.0);
+                true
+            })
+            .unwrap();
+        assert_eq!(taffy.style(child0).unwrap().size.width, length(100.0));
+        let mut dirty_nodes: Vec<NodeId> = taffy.dirty_nodes().collect();
+        dirty_nodes.sort_by_key(|node| u64::from(*node));
+        assert_eq!(dirty_nodes, vec![child0, node]);
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(node).unwrap().size.width, 100.0);
+
+        // Removed nodes are an error, and the closure is not called
+        taffy.remove(child1).unwrap();
+        let result = taffy.with_style_mut(child1, |_| unreachable!());
+        assert!(matches!(result, Err(TaffyError::InvalidInputNode(n)) if n == child1));
+    }
+
+    #[test]
+    fn mark_dirty_many_visits_shared_ancestors_once() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let grandchild0 = taffy.new_leaf(Style::default()).unwrap();
+        let grandchild1 = taffy.new_leaf(Style::default()).unwrap();
+        let child0 = taffy.new_with_children(Style::default(), &[grandchild0, grandchild1]).unwrap();
+        let child1 = taffy.new_leaf(Style::default()).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+
+        // Each of the 5 nodes is marked once, even though `child0` and `node` are ancestors of several of the nodes
+        let marked = taffy.mark_dirty_many([grandchild0, grandchild1, child1, grandchild0, node].into_iter());
+        assert_eq!(marked, 5);
+    }
+
+    #[test]
+    fn was_recomputed() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let grandchild = taffy.new_leaf(Style::default()).unwrap();
+        let child1 = taffy
+            .new_with_children(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }, &[grandchild])
+            .unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        let all_nodes = [node, child0, child1, grandchild];
+
+        // Nothing has been recomputed before layout is computed for the first time
+        assert!(all_nodes.iter().all(|&node| !taffy.was_recomputed(node).unwrap()));
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert!(all_nodes.iter().all(|&node| taffy.was_recomputed(node).unwrap()));
+
+        // Only the modified node and its ancestors are recomputed. The sibling's layout is retrieved from its cache.
+        taffy.set_style(child0, Style { size: Size::from_lengths(20.0, 10.0), ..Default::default() }).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert!(taffy.was_recomputed(node).unwrap());
+        assert!(taffy.was_recomputed(child0).unwrap());
+        assert!(!taffy.was_recomputed(child1).unwrap());
+        assert!(!taffy.was_recomputed(grandchild).unwrap());
+
+        // Nothing is recomputed if nothing has changed
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert!(all_nodes.iter().all(|&node| !taffy.was_recomputed(node).unwrap()));
+
+        taffy.remove(child0).unwrap();
+        assert!(matches!(taffy.was_recomputed(child0), Err(TaffyError::InvalidInputNode(_))));
+    }
+
+    #[test]
+    fn clear_caches() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let grandchild = taffy.new_leaf(Style::default()).unwrap();
+        let child1 = taffy
+            .new_with_children(Style { size: Size::from_lengths(50.0, 50.0), ..Default::default() }, &[grandchild])
+            .unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        let all_nodes = [node, child0, child1, grandchild];
+        assert_eq!(taffy.cache_memory_estimate(), 4 * core::mem::size_of::<Cache>());
+
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        let layouts = all_nodes.map(|node| *taffy.layout(node).unwrap());
+
+        // Clearing the caches marks every node as dirty, but keeps their layouts
+        taffy.clear_caches();
+        assert_eq!(taffy.dirty_nodes().count(), 4);
+        for (node, layout) in all_nodes.iter().zip(layouts) {
+            assert_eq!(taffy.layout(*node).unwrap().size, layout.size);
+        }
+
+        // The next layout recomputes every node, and produces the same layouts
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert!(all_nodes.iter().all(|&node| taffy.was_recomputed(node).unwrap()));
+        for (node, layout) in all_nodes.iter().zip(layouts) {
+            assert_eq!(taffy.layout(*node).unwrap().size, layout.size);
+            assert_eq!(taffy.layout(*node).unwrap().location, layout.location);
+        }
+
+        taffy.mark_dirty_all();
+        assert_eq!(taffy.dirty_nodes().count(), 4);
+        assert_eq!(taffy.cache_memory_estimate(), 4 * core::mem::size_of::<Cache>());
+    }
+
+    #[test]
+    fn layout_offset() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let child1 = taffy.new_leaf(Style { size: Size::from_lengths(10.0, 10.0), ..Default::default() }).unwrap();
+        let node = taffy.new_with_children(Style::default(), &[child0, child1]).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+        // The offset is applied immediately and does not affect siblings or invalidate the layout
+        taffy.set_layout_offset(child0, Point { x: 5.0, y: 3.0 }).unwrap();
+        assert_eq!(taffy.layout_offset(child0).unwrap(), Point { x: 5.0, y: 3.0 });
+        assert_eq!(taffy.layout(child0).unwrap().location, Point { x: 5.0, y: 3.0 });
+        assert_eq!(taffy.layout(child1).unwrap().location, Point { x: 10.0, y: 0.0 });
+        assert_eq!(taffy.layout(node).unwrap().size, Size { width: 20.0, height: 10.0 });
+        assert_eq!(taffy.dirty_nodes().count(), 0);
+
+        // The offset persists when the layout is recomputed
+        taffy.mark_dirty(child0).unwrap();
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child0).unwrap().location, Point { x: 5.0, y: 3.0 });
+
+        // Changing the offset replaces the previous offset rather than adding to it
+        taffy.disable_rounding();
+        taffy.set_layout_offset(child0, Point { x: 0.5, y: 0.0 }).unwrap();
+        assert_eq!(taffy.layout(child0).unwrap().location, Point { x: 0.5, y: 0.0 });
+
+        taffy.remove(child1).unwrap();
+        assert!(matches!(taffy.set_layout_offset(child1, Point::ZERO), Err(TaffyError::InvalidInputNode(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "block_layout")]
+    fn fragment_breaks() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let leaf = |taffy: &mut TaffyTree<()>, height: f32, break_inside: BreakInside| {
+            let size = Size { width: Dimension::Auto, height: Dimension::Length(height) };
+            taffy.new_leaf(Style { display: Display::Block, size, break_inside, ..Default::default() }).unwrap()
+        };
+        let child0 = leaf(&mut taffy, 40.0, BreakInside::Auto);
+        let child1 = leaf(&mut taffy, 30.0, BreakInside::Auto);
+        let child2 = leaf(&mut taffy, 50.0, BreakInside::Auto);
+        let grandchild0 = leaf(&mut taffy, 60.0, BreakInside::Auto);
+        let grandchild1 = leaf(&mut taffy, 60.0, BreakInside::Auto);
+        let child3 = taffy
+            .new_with_children(Style { display: Display::Block, ..Default::default() }, &[grandchild0, grandchild1])
+            .unwrap();
+        let root = taffy
+            .new_with_children(Style { display: Display::Block, ..Default::default() }, &[child0, child1, child2, child3])
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        // Content is broken at the end of each fragment: child2 (70-120) and grandchild1 (180-240) are split
+        assert_eq!(taffy.fragment_breaks(root, 100.0).unwrap(), [100.0, 200.0]);
+
+        // Nodes that avoid breaks are moved into the next fragment
+        let mut style = taffy.style(child2).unwrap().clone();
+        style.break_inside = BreakInside::Avoid;
+        taffy.set_style(child2, style).unwrap();
+        assert_eq!(taffy.fragment_breaks(root, 100.0).unwrap(), [70.0, 170.0]);
+
+        // ...unless they are taller than a fragment
+        assert_eq!(taffy.fragment_breaks(root, 40.0).unwrap(), [40.0, 80.0, 120.0, 160.0, 200.0]);
+
+        // Breaks are relative to the node
+        assert_eq!(taffy.fragment_breaks(child3, 50.0).unwrap(), [50.0, 100.0]);
+        assert!(taffy.fragment_breaks(root, 0.0).unwrap().is_empty());
+    }
+
     #[test]
     fn compute_layout_should_produce_valid_result() {
         let mut taffy: TaffyTree<()> = TaffyTree::new();
@@ -1079,6 +2564,182 @@ mod tests {
         assert!(layout_result.is_ok());
     }
 
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn baseline() {
+        use crate::prelude::Rect;
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child0 = taffy
+            .new_leaf(Style {
+                size: Size { width: length(10.0), height: length(20.0) },
+                margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: zero() },
+                ..Default::default()
+            })
+            .unwrap();
+        let child1 = taffy.new_leaf(Style { size: length(40.0), ..Default::default() }).unwrap();
+        let root_style = Style { padding: length(3.0), ..Default::default() };
+        let root = taffy.new_with_children(root_style, &[child0, child1]).unwrap();
+        let empty = taffy.new_leaf(Style::default()).unwrap();
+
+        // Baselines are not available before layout
+        assert_eq!(taffy.baseline(root).unwrap(), None);
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        // Synthesized from the bottom edge of the first item, as leaves have no baseline of their own
+        assert_eq!(taffy.baseline(root).unwrap(), Some(28.0));
+        assert_eq!(taffy.baseline(child0).unwrap(), None);
+
+        taffy.remove(empty).unwrap();
+        assert!(matches!(taffy.baseline(empty), Err(TaffyError::InvalidInputNode(n)) if n == empty));
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn measure_node_does_not_change_layout() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        // Text of the given width which wraps onto 10px tall lines
+        let measure_text = |known: Size<Option<f32>>, available: Size<AvailableSpace>, _, text: Option<&mut f32>| {
+            let text_width = text.copied().unwrap_or(0.0);
+            let width = known.width.unwrap_or(match available.width {
+                AvailableSpace::Definite(width) => width.min(text_width),
+                AvailableSpace::MinContent => 0.0,
+                AvailableSpace::MaxContent => text_width,
+            });
+            Size { width, height: known.height.unwrap_or(10.0 * (text_width / width).ceil()) }
+        };
+        let text = taffy.new_leaf_with_context(Style::default(), 600.0).unwrap();
+        let card = taffy
+            .new_with_children(
+                Style { flex_direction: FlexDirection::Column, padding: length(10.0), ..Default::default() },
+                &[text],
+            )
+            .unwrap();
+
+        let available_space =
+            |width| Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+        taffy.compute_layout_with_measure(card, available_space(320.0), measure_text).unwrap();
+        let card_layout = *taffy.layout(card).unwrap();
+        let text_layout = *taffy.layout(text).unwrap();
+        assert_eq!(card_layout.size, Size { width: 320.0, height: 40.0 });
+
+        // Measuring matches the size that a full layout would produce...
+        let size = taffy.measure_node_with_measure(card, available_space(320.0), measure_text).unwrap();
+        assert_eq!(size, card_layout.size);
+        let size = taffy.measure_node_with_measure(card, available_space(170.0), measure_text).unwrap();
+        assert_eq!(size, Size { width: 170.0, height: 60.0 });
+
+        // ...but leaves the stored layouts untouched
+        assert_eq!(taffy.layout(card).unwrap().size, card_layout.size);
+        assert_eq!(taffy.layout(text).unwrap().size, text_layout.size);
+        assert_eq!(taffy.layout(text).unwrap().location, text_layout.location);
+        assert!(!taffy.dirty(card).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn measure_node_does_not_change_layouts_of_baseline_aligned_descendants() {
+        for use_rounding in [true, false] {
+            let mut taffy: TaffyTree<()> = TaffyTree::new();
+            if !use_rounding {
+                taffy.disable_rounding();
+            }
+            // Finding the baselines of the flex items performs the layout of their descendants
+            let grandchild_style =
+                Style { size: Size { width: percent(1.0), height: length(10.0) }, ..Default::default() };
+            let grandchild = taffy.new_leaf(grandchild_style).unwrap();
+            let child_style = Style { size: Size { width: percent(0.5), height: auto() }, ..Default::default() };
+            let child = taffy.new_with_children(child_style, &[grandchild]).unwrap();
+            let sibling_style = Style { size: Size { width: length(0.0), height: length(5.0) }, ..Default::default() };
+            let sibling = taffy.new_leaf(sibling_style).unwrap();
+            let root_style = Style {
+                align_items: Some(crate::style::AlignItems::Baseline),
+                size: Size { width: percent(1.0), height: auto() },
+                ..Default::default()
+            };
+            let root = taffy.new_with_children(root_style, &[child, sibling]).unwrap();
+
+            let available_space =
+                |width| Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+            let layouts = |taffy: &TaffyTree<()>| {
+                [root, child, grandchild, sibling].map(|node| {
+                    let layout = taffy.layout(node).unwrap();
+                    (layout.location, layout.size)
+                })
+            };
+            taffy.compute_layout(root, available_space(6.0)).unwrap();
+            let computed = layouts(&taffy);
+            assert_eq!(computed[2].1.width, 3.0);
+
+            taffy.measure_node(root, available_space(100.0)).unwrap();
+            assert_eq!(layouts(&taffy), computed, "use_rounding: {use_rounding}");
+
+            taffy.compute_layout(root, available_space(6.0)).unwrap();
+            assert_eq!(layouts(&taffy), computed, "use_rounding: {use_rounding}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn measure_node_does_not_change_layout_caches() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        // Text of the given width which wraps onto 10px tall lines of words that are 50px wide
+        let measure_text = |known: Size<Option<f32>>, available: Size<AvailableSpace>, _, text: Option<&mut f32>| {
+            let text_width = text.copied().unwrap_or(0.0);
+            let width = known.width.unwrap_or(match available.width {
+                AvailableSpace::Definite(width) => width.clamp(50.0, text_width),
+                AvailableSpace::MinContent => 50.0,
+                AvailableSpace::MaxContent => text_width,
+            });
+            Size { width, height: known.height.unwrap_or(10.0 * (text_width / width).ceil()) }
+        };
+        let text = taffy.new_leaf_with_context(Style::default(), 600.0).unwrap();
+        let grid_style = Style { display: Display::Grid, grid_template_columns: vec![auto()], ..Default::default() };
+        let grid = taffy.new_with_children(grid_style, &[text]).unwrap();
+
+        let available_space =
+            |width| Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent };
+        taffy.compute_layout_with_measure(grid, available_space(320.0), measure_text).unwrap();
+        taffy.compute_layout_with_measure(grid, available_space(320.0), measure_text).unwrap();
+        let intrinsic_sizes = taffy.intrinsic_sizes(text).unwrap();
+        assert!(intrinsic_sizes.is_some());
+        assert!(!taffy.was_recomputed(grid).unwrap());
+        assert!(!taffy.was_recomputed(text).unwrap());
+
+        let size = taffy.measure_node_with_measure(grid, available_space(170.0), measure_text).unwrap();
+        assert_eq!(size, Size { width: 170.0, height: 40.0 });
+        // Measuring the text at a definite width would evict its cached max-content size if it weren't restored
+        let size = taffy.measure_node_with_measure(text, available_space(170.0), measure_text).unwrap();
+        assert_eq!(size, Size { width: 170.0, height: 40.0 });
+
+        assert_eq!(taffy.intrinsic_sizes(text).unwrap(), intrinsic_sizes);
+        assert!(!taffy.was_recomputed(grid).unwrap());
+        assert!(!taffy.was_recomputed(text).unwrap());
+        assert!(!taffy.dirty(grid).unwrap());
+        assert!(!taffy.dirty(text).unwrap());
+    }
+
+    #[test]
+    fn compute_layout_at_places_root_at_origin() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style { padding: length(5.0), ..Default::default() }, &[child]).unwrap();
+
+        taffy.compute_layout_at(root, Point { x: 40.0, y: 25.0 }, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 40.0, y: 25.0 });
+        assert_eq!(taffy.layout(root).unwrap().size, Size { width: 20.0, height: 20.0 });
+        // Children remain relative to their parent
+        assert_eq!(taffy.layout(child).unwrap().location, Point { x: 5.0, y: 5.0 });
+
+        // The origin is combined with any layout offset
+        taffy.set_layout_offset(root, Point { x: 1.0, y: 2.0 }).unwrap();
+        taffy.compute_layout_at(root, Point { x: 40.0, y: 25.0 }, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 41.0, y: 27.0 });
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(root).unwrap().location, Point { x: 1.0, y: 2.0 });
+    }
+
     #[test]
     fn make_sure_layout_location_is_top_left() {
         use crate::prelude::Rect;
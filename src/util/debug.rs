@@ -5,6 +5,9 @@ use core::fmt::{Debug, Display, Write};
 #[cfg(any(feature = "debug", feature = "profile"))]
 use std::sync::Mutex;
 
+#[cfg(any(feature = "debug", feature = "profile"))]
+use crate::tree::NodeId;
+
 #[doc(hidden)]
 #[cfg(any(feature = "debug", feature = "profile"))]
 pub struct DebugLogger {
@@ -139,5 +142,22 @@ macro_rules! time {
     };
 }
 
+/// Increment one of the counters in [`LayoutStats`](crate::tree::LayoutStats) (if the `profile` feature is enabled)
+macro_rules! profile_count {
+    ($counter:ident) => {
+        #[cfg(feature = "profile")]
+        $crate::tree::stats::record(|stats| stats.$counter += 1);
+    };
+}
+
+/// Record a visit by the specified layout algorithm to a node, and time the algorithm until the end of the enclosing
+/// scope (if the `profile` feature is enabled)
+macro_rules! profile_algorithm {
+    ($algorithm:ident) => {
+        #[cfg(feature = "profile")]
+        let _timer = $crate::tree::stats::AlgorithmTimer::start($crate::tree::stats::Algorithm::$algorithm);
+    };
+}
+
 #[allow(unused_imports)]
-pub(crate) use {debug_log, debug_log_node, debug_pop_node, debug_push_node, time};
+pub(crate) use {debug_log, debug_log_node, debug_pop_node, debug_push_node, profile_algorithm, profile_count, time};
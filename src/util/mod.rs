@@ -4,7 +4,7 @@ mod resolve;
 pub(crate) mod sys;
 
 pub(crate) use math::MaybeMath;
-pub(crate) use resolve::{MaybeResolve, ResolveOrZero};
+pub(crate) use resolve::{sanitize_aspect_ratio, sanitize_factor, sanitize_length, MaybeResolve, ResolveOrZero};
 
 #[doc(hidden)]
 #[macro_use]
@@ -13,4 +13,4 @@ pub(crate) mod debug;
 #[cfg(feature = "std")]
 mod print;
 #[cfg(feature = "std")]
-pub use print::print_tree;
+pub use print::{print_tree, sizing_report};
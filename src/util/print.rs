@@ -1,5 +1,9 @@
 //! Contains the print_tree function for printing a debug representation of the tree
+use crate::geometry::Size;
+use crate::style::{Dimension, Position, Style};
 use crate::tree::{NodeId, PrintTree};
+use crate::util::MaybeResolve;
+use std::fmt::Write;
 
 /// Prints a debug representation of the computed layout for a tree of nodes, starting with the passed root node.
 pub fn print_tree(tree: &impl PrintTree, root: NodeId) {
@@ -57,3 +61,109 @@ pub fn print_tree(tree: &impl PrintTree, root: NodeId) {
         }
     }
 }
+
+/// Returns a diagnostic report comparing the specified `size`, `min_size` and `max_size` of each node in a tree with
+/// its computed size, starting with the passed root node. Where a node's computed size matches its min or max size
+/// rather than its preferred size this is flagged (for example "clamped by max_size").
+///
+/// Percentages are resolved against the computed size of the parent's content box (or padding box for absolutely
+/// positioned nodes). The report is intended to be read by humans and its format may change at any time.
+pub fn sizing_report<'a, Tree: PrintTree>(
+    tree: &'a Tree,
+    root: NodeId,
+    get_style: impl Fn(NodeId) -> &'a Style,
+) -> String {
+    let mut report = String::from("TREE\n");
+    report_node(tree, &get_style, root, Size::NONE, false, String::new(), &mut report);
+    return report;
+
+    /// Recursive function that writes the report for each node in the tree
+    fn report_node<'a, Tree: PrintTree>(
+        tree: &'a Tree,
+        get_style: &impl Fn(NodeId) -> &'a Style,
+        node_id: NodeId,
+        parent_size: Size<Option<f32>>,
+        has_sibling: bool,
+        lines_string: String,
+        report: &mut String,
+    ) {
+        let layout = tree.get_final_layout(node_id);
+        let style = get_style(node_id);
+        let fork_string = if has_sibling { "├── " } else { "└── " };
+        let _ = writeln!(
+            report,
+            "{lines}{fork} {display} [w: {width:<4} h: {height:<4}] ({key:?})",
+            lines = lines_string,
+            fork = fork_string,
+            display = tree.get_debug_label(node_id),
+            width = layout.size.width,
+            height = layout.size.height,
+            key = node_id,
+        );
+
+        let bar = if has_sibling { "│   " } else { "    " };
+        let new_string = lines_string + bar;
+        let axes = [
+            ("width ", style.size.width, style.min_size.width, style.max_size.width, parent_size.width),
+            ("height", style.size.height, style.min_size.height, style.max_size.height, parent_size.height),
+        ];
+        for (computed, (label, size, min_size, max_size, parent_size)) in
+            [layout.size.width, layout.size.height].into_iter().zip(axes)
+        {
+            let note = sizing_note(
+                computed,
+                size.maybe_resolve(parent_size),
+                min_size.maybe_resolve(parent_size),
+                max_size.maybe_resolve(parent_size),
+            );
+            let _ = writeln!(
+                report,
+                "{new_string}    {label} size: {size:<8} min: {min:<8} max: {max:<8} computed: {computed}{note}",
+                size = format_dimension(size),
+                min = format_dimension(min_size),
+                max = format_dimension(max_size),
+            );
+        }
+
+        // Percentages in the styles of children resolve against this node's content box (or its padding box if the
+        // child is absolutely positioned)
+        let padding_box = Size {
+            width: layout.size.width - layout.border.left - layout.border.right - layout.scrollbar_size.width,
+            height: layout.size.height - layout.border.top - layout.border.bottom - layout.scrollbar_size.height,
+        };
+        let content_box = Size {
+            width: padding_box.width - layout.padding.left - layout.padding.right,
+            height: padding_box.height - layout.padding.top - layout.padding.bottom,
+        };
+
+        let num_children = tree.child_count(node_id);
+        for (index, child) in tree.child_ids(node_id).enumerate() {
+            let has_sibling = index < num_children - 1;
+            let child_parent_size =
+                if get_style(child).position == Position::Absolute { padding_box } else { content_box };
+            report_node(tree, get_style, child, child_parent_size.map(Some), has_sibling, new_string.clone(), report);
+        }
+    }
+}
+
+/// Describes how a node's computed size in one axis relates to its resolved size, min size and max size styles
+fn sizing_note(computed: f32, size: Option<f32>, min_size: Option<f32>, max_size: Option<f32>) -> &'static str {
+    // Computed sizes may have been rounded
+    let matches = |value: f32| (computed - value).abs() < 1.0;
+    match (size, min_size, max_size) {
+        (_, Some(min), _) if matches(min) && size.map_or(true, |size| size < min) => " (clamped by min_size)",
+        (_, _, Some(max)) if matches(max) && size.map_or(true, |size| size > max) => " (clamped by max_size)",
+        (Some(size), _, _) if !matches(size) => " (differs from size)",
+        _ => "",
+    }
+}
+
+/// Formats a [`Dimension`] style for the sizing report
+fn format_dimension(dimension: Dimension) -> String {
+    match dimension {
+        Dimension::Length(length) => format!("{length}"),
+        Dimension::Percent(fraction) => format!("{}%", fraction * 100.0),
+        Dimension::Auto => String::from("auto"),
+        Dimension::Content => String::from("content"),
+    }
+}
@@ -1,8 +1,55 @@
 //! Helper trait to calculate dimensions during layout resolution
 
 use crate::geometry::{Rect, Size};
-use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto};
+use crate::style::{Dimension, LengthPercentage, LengthPercentageAuto, MAX_SIZE};
 use crate::style_helpers::TaffyZero;
+use crate::util::debug::debug_log;
+
+/// Sanitize a length that has been resolved from a style value.
+///
+/// `NaN` is treated as if the value were `auto` (`None`), and infinities (and finite values so large that they would
+/// overflow to infinity when added together) are clamped to [`MAX_SIZE`].
+#[inline(always)]
+pub(crate) fn sanitize_length(value: f32) -> Option<f32> {
+    if value.is_nan() {
+        debug_log!("NaN style value treated as auto");
+        None
+    } else if value.abs() > MAX_SIZE {
+        debug_log!("Out of range style value clamped", value);
+        Some(value.clamp(-MAX_SIZE, MAX_SIZE))
+    } else {
+        Some(value)
+    }
+}
+
+/// Sanitize a flex factor (`flex-grow`, `flex-shrink` or an `fr` value) read from a style.
+///
+/// `NaN` and negative factors (which are invalid in CSS) are treated as zero, and infinities are clamped to [`MAX_SIZE`].
+/// Positive factors too small to have any effect (below `1 / MAX_SIZE`) are also treated as zero, so that dividing
+/// space by a factor can't overflow.
+#[inline(always)]
+pub(crate) fn sanitize_factor(value: f32) -> f32 {
+    match sanitize_length(value) {
+        Some(factor) if factor >= 1.0 / MAX_SIZE => factor,
+        _ => 0.0,
+    }
+}
+
+/// Sanitize an aspect ratio read from a style.
+///
+/// Ratios that are `NaN`, infinite, zero or negative are treated as if no aspect ratio were set (`None`), and the
+/// remaining ratios are clamped so that transferring a size through them can't overflow.
+#[inline(always)]
+pub(crate) fn sanitize_aspect_ratio(aspect_ratio: Option<f32>) -> Option<f32> {
+    match aspect_ratio {
+        Some(ratio) if ratio.is_finite() && ratio > 0.0 => Some(ratio.clamp(1.0 / MAX_SIZE, MAX_SIZE)),
+        None => None,
+        Some(_) => {
+            debug_log!("Invalid aspect ratio ignored");
+            None
+        }
+    }
+}
 
 /// Trait to encapsulate behaviour where we need to resolve from a
 /// potentially context-dependent size or dimension into
@@ -29,8 +76,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentage {
     /// Can return `None`
     fn maybe_resolve(self, context: Option<f32>) -> Option<f32> {
         match self {
-            LengthPercentage::Length(length) => Some(length),
-            LengthPercentage::Percent(percent) => context.map(|dim| dim * percent),
+            LengthPercentage::Length(length) => sanitize_length(length),
+            LengthPercentage::Percent(percent) => context.and_then(|dim| sanitize_length(dim * percent)),
         }
     }
 }
@@ -40,8 +87,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
     /// Can return `None`
     fn maybe_resolve(self, context: Option<f32>) -> Option<f32> {
         match self {
-            LengthPercentageAuto::Length(length) => Some(length),
-            LengthPercentageAuto::Percent(percent) => context.map(|dim| dim * percent),
+            LengthPercentageAuto::Length(length) => sanitize_length(length),
+            LengthPercentageAuto::Percent(percent) => context.and_then(|dim| sanitize_length(dim * percent)),
             LengthPercentageAuto::Auto => None,
         }
     }
@@ -53,9 +100,9 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
     /// Can return `None`
     fn maybe_resolve(self, context: Option<f32>) -> Option<f32> {
         match self {
-            Dimension::Length(length) => Some(length),
-            Dimension::Percent(percent) => context.map(|dim| dim * percent),
-            Dimension::Auto => None,
+            Dimension::Length(length) => sanitize_length(length),
+            Dimension::Percent(percent) => context.and_then(|dim| sanitize_length(dim * percent)),
+            Dimension::Auto | Dimension::Content => None,
         }
     }
 }
@@ -196,6 +243,41 @@ mod tests {
             mr_case(Dimension::Percent(1.0), Some(-5.0), Some(-5.0));
             mr_case(Dimension::Percent(1.0), Some(50.0), Some(50.0));
         }
+
+        /// Non-finite values should be sanitized: `NaN` resolves as if it were `auto`, and infinities
+        /// are clamped to `MAX_SIZE`.
+        #[test]
+        fn resolve_non_finite() {
+            use crate::style::MAX_SIZE;
+            mr_case(Dimension::Length(f32::NAN), None, None);
+            mr_case(Dimension::Length(f32::INFINITY), None, Some(MAX_SIZE));
+            mr_case(Dimension::Length(f32::NEG_INFINITY), None, Some(-MAX_SIZE));
+            mr_case(Dimension::Percent(f32::NAN), Some(5.0), None);
+            mr_case(Dimension::Percent(1.0), Some(f32::INFINITY), Some(MAX_SIZE));
+            mr_case(Dimension::Percent(0.0), Some(f32::INFINITY), None);
+            mr_case(Dimension::Length(f32::MAX), None, Some(MAX_SIZE));
+        }
+
+        /// Factors and aspect ratios which are invalid or so extreme that dividing by them would overflow are
+        /// treated as zero and as no aspect ratio respectively
+        #[test]
+        fn sanitize_factors_and_aspect_ratios() {
+            use crate::style::MAX_SIZE;
+            use crate::util::{sanitize_aspect_ratio, sanitize_factor};
+            assert_eq!(sanitize_factor(2.0), 2.0);
+            assert_eq!(sanitize_factor(f32::INFINITY), MAX_SIZE);
+            assert_eq!(sanitize_factor(f32::NAN), 0.0);
+            assert_eq!(sanitize_factor(-1.0), 0.0);
+            assert_eq!(sanitize_factor(f32::MIN_POSITIVE), 0.0);
+
+            assert_eq!(sanitize_aspect_ratio(Some(1.5)), Some(1.5));
+            assert_eq!(sanitize_aspect_ratio(Some(f32::MAX)), Some(MAX_SIZE));
+            assert_eq!(sanitize_aspect_ratio(Some(f32::MIN_POSITIVE)), Some(1.0 / MAX_SIZE));
+            assert_eq!(sanitize_aspect_ratio(Some(0.0)), None);
+            assert_eq!(sanitize_aspect_ratio(Some(-2.0)), None);
+            assert_eq!(sanitize_aspect_ratio(Some(f32::NAN)), None);
+            assert_eq!(sanitize_aspect_ratio(Some(f32::INFINITY)), None);
+        }
     }
 
     mod maybe_resolve_size_dimension {
@@ -12,6 +12,12 @@ pub(crate) use self::alloc::*;
 #[cfg(all(not(feature = "alloc"), not(feature = "std")))]
 pub(crate) use self::core::*;
 
+// When the `fixed-capacity-grids` feature is enabled, grid layouts store their tracks in fixed-capacity arrays
+#[cfg(feature = "fixed-capacity-grids")]
+pub use self::fixed_capacity_grids::MAX_GRID_TRACKS;
+#[cfg(feature = "fixed-capacity-grids")]
+pub(crate) use self::fixed_capacity_grids::*;
+
 /// For when `std` is enabled
 #[cfg(feature = "std")]
 mod std {
@@ -22,6 +28,13 @@ mod std {
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = std::vec::Vec<A>;
+    #[cfg(all(feature = "grid", not(feature = "fixed-capacity-grids")))]
+    /// The tracks of a grid container while its layout is being computed
+    pub(crate) type GridTrackBuffer<A> = std::vec::Vec<A>;
+    #[cfg(all(feature = "grid", not(feature = "fixed-capacity-grids")))]
+    /// The lines of a grid container (of which there is always one more than there are tracks) while its layout is
+    /// being computed
+    pub(crate) type GridLineBuffer<A> = std::vec::Vec<A>;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -69,6 +82,13 @@ mod alloc {
     #[cfg(feature = "grid")]
     /// A vector of grid tracks
     pub(crate) type GridTrackVec<A> = alloc::vec::Vec<A>;
+    #[cfg(all(feature = "grid", not(feature = "fixed-capacity-grids")))]
+    /// The tracks of a grid container while its layout is being computed
+    pub(crate) type GridTrackBuffer<A> = alloc::vec::Vec<A>;
+    #[cfg(all(feature = "grid", not(feature = "fixed-capacity-grids")))]
+    /// The lines of a grid container (of which there is always one more than there are tracks) while its layout is
+    /// being computed
+    pub(crate) type GridLineBuffer<A> = alloc::vec::Vec<A>;
 
     /// Creates a new vector with the capacity for the specified number of items before it must be resized
     #[must_use]
@@ -160,3 +180,18 @@ mod core {
         a.min(b)
     }
 }
+
+/// For when the `fixed-capacity-grids` feature is enabled
+#[cfg(feature = "fixed-capacity-grids")]
+mod fixed_capacity_grids {
+    /// The maximum number of tracks (rows or columns) that a grid can have in each axis when the `fixed-capacity-grids`
+    /// feature is enabled. This includes implicit tracks and tracks generated by `repeat()`.
+    pub const MAX_GRID_TRACKS: usize = 16;
+
+    /// The tracks of a grid container while its layout is being computed, whose length cannot exceed
+    /// [`MAX_GRID_TRACKS`]
+    pub(crate) type GridTrackBuffer<A> = arrayvec::ArrayVec<A, MAX_GRID_TRACKS>;
+    /// The lines of a grid container (of which there is always one more than there are tracks) while its layout is
+    /// being computed
+    pub(crate) type GridLineBuffer<A> = arrayvec::ArrayVec<A, { MAX_GRID_TRACKS + 1 }>;
+}
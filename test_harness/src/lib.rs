@@ -0,0 +1,282 @@
+//! Compare Taffy layouts against layouts recorded in a web browser
+//!
+//! Taffy's own test fixtures are generated by loading an HTML file in Chrome, describing the `#test-root` element
+//! and its descendants as JSON (using the `describeElement` function in `scripts/gentest/test_helper.js`), and
+//! then generating a Rust test from that JSON. This crate lets you work with the same JSON at runtime, so that you
+//! can pin Taffy against a browser for the style combinations that your application relies on:
+//!
+//! 1. Write an HTML fixture in the same format as those in Taffy's `test_fixtures` directory (the page must load
+//!    `test_helper.js` and `test_base_style.css`).
+//! 2. Open it in Chrome and run `JSON.stringify(describeElement(document.getElementById('test-root')))` in the
+//!    developer console, then save the output alongside your tests.
+//! 3. Check that Taffy's layout matches with [`assert_fixture`], or use [`build_tree`] and [`compare_layouts`]
+//!    directly if you want to modify the tree before laying it out.
+//!
+//! Text is measured with [`measure_function`], which matches the 10px square "Ahem" glyphs used by
+//! `test_base_style.css`. Text may only be wrapped at zero-width spaces (`&#8203;`).
+//!
+//! ```
+//! let description = serde_json::json!({
+//!     "style": { "display": "flex", "size": { "width": { "unit": "px", "value": 100.0 } } },
+//!     "useRounding": true,
+//!     "smartRoundedLayout": { "width": 100.0, "height": 10.0, "x": 0.0, "y": 0.0 },
+//!     "children": [{
+//!         "style": {},
+//!         "textContent": "HH\u{200B}HH",
+//!         "smartRoundedLayout": { "width": 40.0, "height": 10.0, "x": 0.0, "y": 0.0 },
+//!     }],
+//! });
+//! taffy_test_harness::assert_fixture(&description);
+//! ```
+#![deny(unsafe_code)]
+#![warn(missing_docs)]
+#![warn(clippy::missing_docs_in_private_items)]
+
+mod measure;
+mod style;
+
+pub use measure::{measure_function, TextMeasure, WritingMode};
+pub use style::style_from_description;
+
+use core::fmt;
+use serde_json::Value;
+use taffy::prelude::*;
+
+/// The default tolerance used when comparing unrounded layouts, which allows for differences in floating point
+/// arithmetic between Taffy and the browser
+pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+/// Add the node described by `description` and its descendants to `taffy`, returning the id of the new root node
+///
+/// Leaf nodes with text content are given a [`TextMeasure`] context, which [`measure_function`] measures.
+///
+/// # Panics
+///
+/// Panics if a style value does not have the shape that `describeElement` produces
+pub fn build_tree(taffy: &mut TaffyTree<TextMeasure>, description: &Value) -> NodeId {
+    let style = style_from_description(&description["style"]);
+    let children: Vec<NodeId> = match description["children"].as_array() {
+        Some(children) => children.iter().map(|child| build_tree(taffy, child)).collect(),
+        None => Vec::new(),
+    };
+
+    if !children.is_empty() {
+        return taffy.new_with_children(style, &children).unwrap();
+    }
+    match description["textContent"].as_str() {
+        Some(text) => {
+            let writing_mode = match description["style"]["writingMode"].as_str() {
+                Some("vertical-rl" | "vertical-lr") => WritingMode::Vertical,
+                _ => WritingMode::Horizontal,
+            };
+            let context = TextMeasure { text_content: text.trim().to_string(), writing_mode };
+            taffy.new_leaf_with_context(style, context).unwrap()
+        }
+        None => taffy.new_leaf(style).unwrap(),
+    }
+}
+
+/// The space available to the root node of a fixture, as recorded in its `viewport` (max-content if there is none)
+pub fn available_space(description: &Value) -> Size<AvailableSpace> {
+    let axis = |name| {
+        let viewport = &description["viewport"][name];
+        match viewport["unit"].as_str() {
+            Some("px") => AvailableSpace::Definite(viewport["value"].as_f64().unwrap_or(0.0) as f32),
+            Some("min-content") => AvailableSpace::MinContent,
+            _ => AvailableSpace::MaxContent,
+        }
+    };
+    Size { width: axis("width"), height: axis("height") }
+}
+
+/// A difference between the layout computed by Taffy and the layout recorded in a fixture
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutMismatch {
+    /// The child indexes leading from the root node to the mismatched node (empty for the root node itself)
+    pub path: Vec<usize>,
+    /// The name of the mismatched value: `width`, `height`, `x`, `y`, `scroll_width` or `scroll_height`
+    pub property: &'static str,
+    /// The value recorded in the fixture
+    pub expected: f32,
+    /// The value computed by Taffy
+    pub actual: f32,
+}
+
+impl fmt::Display for LayoutMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node at {:?}: {} expected {} but was {}", self.path, self.property, self.expected, self.actual)
+    }
+}
+
+/// Compare the layouts of `node` (which must have been created by [`build_tree`] from `description`) and its
+/// descendants with the layouts recorded in `description`, returning every value that differs by more than
+/// `tolerance`
+///
+/// If `use_rounding` is true the expected values are read from the `smartRoundedLayout` of each node, otherwise
+/// from the `unroundedLayout`. Scroll sizes are only compared when the description includes them.
+pub fn compare_layouts(
+    taffy: &TaffyTree<TextMeasure>,
+    node: NodeId,
+    description: &Value,
+    use_rounding: bool,
+    tolerance: f32,
+) -> Vec<LayoutMismatch> {
+    let mut mismatches = Vec::new();
+    compare_node(taffy, node, description, use_rounding, tolerance, &mut Vec::new(), &mut mismatches);
+    mismatches
+}
+
+/// Compare the layout of a single node and then recurse into its children
+fn compare_node(
+    taffy: &TaffyTree<TextMeasure>,
+    node: NodeId,
+    description: &Value,
+    use_rounding: bool,
+    tolerance: f32,
+    path: &mut Vec<usize>,
+    mismatches: &mut Vec<LayoutMismatch>,
+) {
+    let expected = if use_rounding { &description["smartRoundedLayout"] } else { &description["unroundedLayout"] };
+    let layout = taffy.layout(node).unwrap();
+
+    let mut check = |property, expected: Option<f64>, actual: f32| {
+        if let Some(expected) = expected.map(|expected| expected as f32) {
+            if (expected - actual).abs() > tolerance {
+                mismatches.push(LayoutMismatch { path: path.clone(), property, expected, actual });
+            }
+        }
+    };
+    check("width", expected["width"].as_f64(), layout.size.width);
+    check("height", expected["height"].as_f64(), layout.size.height);
+    check("x", expected["x"].as_f64(), layout.location.x);
+    check("y", expected["y"].as_f64(), layout.location.y);
+
+    // The browser's scroll size includes the node's own client size, whereas Taffy's only includes the overflow
+    let client_size = &description["naivelyRoundedLayout"];
+    let scroll_size =
+        |scroll: &str, client: &str| Some((expected[scroll].as_f64()? - client_size[client].as_f64()?).max(0.0));
+    check("scroll_width", scroll_size("scrollWidth", "clientWidth"), layout.scroll_width());
+    check("scroll_height", scroll_size("scrollHeight", "clientHeight"), layout.scroll_height());
+
+    let children = description["children"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    for (index, child) in children.iter().enumerate() {
+        path.push(index);
+        let child_node = taffy.child_at_index(node, index).unwrap();
+        compare_node(taffy, child_node, child, use_rounding, tolerance, path, mismatches);
+        path.pop();
+    }
+}
+
+/// Build a tree from a fixture description, lay it out in the fixture's viewport, and return every layout value
+/// that differs from the fixture
+///
+/// Rounding is enabled unless the fixture's `useRounding` is `false`, in which case values are compared with a
+/// tolerance of [`DEFAULT_TOLERANCE`].
+pub fn run_fixture(description: &Value) -> Vec<LayoutMismatch> {
+    let use_rounding = description["useRounding"].as_bool() != Some(false);
+    let mut taffy: TaffyTree<TextMeasure> = TaffyTree::new();
+    if !use_rounding {
+        taffy.disable_rounding();
+    }
+
+    let node = build_tree(&mut taffy, description);
+    taffy.compute_layout_with_measure(node, available_space(description), measure_function).unwrap();
+
+    let tolerance = if use_rounding { 0.0 } else { DEFAULT_TOLERANCE };
+    compare_layouts(&taffy, node, description, use_rounding, tolerance)
+}
+
+/// As [`run_fixture`], but panics with a list of the mismatched values if there are any
+pub fn assert_fixture(description: &Value) {
+    let mismatches = run_fixture(description);
+    if !mismatches.is_empty() {
+        let lines: Vec<String> = mismatches.iter().map(LayoutMismatch::to_string).collect();
+        panic!("layout does not match the fixture:\n{}", lines.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// A 200px wide flex container holding a grid with two `1fr` columns and a wrapping text node
+    fn description() -> Value {
+        let layout =
+            |width: f32, height: f32, x: f32, y: f32| json!({ "width": width, "height": height, "x": x, "y": y });
+        json!({
+            "style": {
+                "display": "flex",
+                "flexDirection": "column",
+                "size": { "width": { "unit": "px", "value": 200.0 } },
+                "padding": { "left": { "unit": "px", "value": 10.0 }, "top": { "unit": "px", "value": 20.0 } },
+            },
+            "useRounding": true,
+            "viewport": { "width": { "unit": "max-content" }, "height": { "unit": "max-content" } },
+            "smartRoundedLayout": layout(200.0, 50.0, 0.0, 0.0),
+            "children": [
+                {
+                    "style": {
+                        "display": "grid",
+                        "gridTemplateColumns": [
+                            { "kind": "scalar", "unit": "fraction", "value": 1.0 },
+                            { "kind": "function", "name": "minmax", "arguments": [
+                                { "kind": "scalar", "unit": "px", "value": 50.0 },
+                                { "kind": "scalar", "unit": "fraction", "value": 1.0 },
+                            ]},
+                        ],
+                        "gap": { "row": { "unit": "px", "value": 0.0 }, "column": { "unit": "px", "value": 10.0 } },
+                    },
+                    "smartRoundedLayout": layout(190.0, 10.0, 10.0, 20.0),
+                    "children": [
+                        { "style": { "size": { "height": { "unit": "px", "value": 10.0 } } },
+                          "smartRoundedLayout": layout(90.0, 10.0, 0.0, 0.0) },
+                        { "style": { "gridColumnStart": { "kind": "line", "value": 2 } },
+                          "smartRoundedLayout": layout(90.0, 10.0, 100.0, 0.0) },
+                    ],
+                },
+                {
+                    "style": { "maxSize": { "width": { "unit": "px", "value": 100.0 } } },
+                    "textContent": "HHHHHH\u{200B}HHHHHH\n",
+                    "smartRoundedLayout": layout(100.0, 20.0, 10.0, 30.0),
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn matching_fixture() {
+        assert_eq!(run_fixture(&description()), Vec::new());
+    }
+
+    #[test]
+    fn mismatched_fixture() {
+        let mut description = description();
+        description["children"][0]["children"][1]["smartRoundedLayout"]["x"] = json!(95.0);
+        description["children"][1]["smartRoundedLayout"]["height"] = json!(10.0);
+
+        let mismatches = run_fixture(&description);
+        assert_eq!(
+            mismatches,
+            vec![
+                LayoutMismatch { path: vec![0, 1], property: "x", expected: 95.0, actual: 100.0 },
+                LayoutMismatch { path: vec![1], property: "height", expected: 10.0, actual: 20.0 },
+            ]
+        );
+        assert_eq!(mismatches[1].to_string(), "node at [1]: height expected 10 but was 20");
+    }
+
+    #[test]
+    fn unrounded_fixture_uses_tolerance() {
+        let description = json!({
+            "style": { "size": { "width": { "unit": "px", "value": 100.0 }, "height": { "unit": "px", "value": 10.0 } } },
+            "useRounding": false,
+            "unroundedLayout": { "width": 100.0, "height": 10.0, "x": 0.0, "y": 0.0 },
+            "children": [
+                { "style": { "flexGrow": 1.0 }, "unroundedLayout": { "width": 33.36, "height": 10.0, "x": 0.0, "y": 0.0 } },
+                { "style": { "flexGrow": 2.0 }, "unroundedLayout": { "width": 66.6, "height": 10.0, "x": 33.3, "y": 0.0 } },
+            ],
+        });
+        assert_eq!(run_fixture(&description), Vec::new());
+    }
+}
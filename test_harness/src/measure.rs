@@ -0,0 +1,84 @@
+//! The measure function used for text nodes, which mirrors how `test_base_style.css` lays out text in the browser
+use taffy::geometry::AbsoluteAxis;
+use taffy::prelude::*;
+
+/// Zero-width space. Text is only ever broken into lines at these characters.
+const ZWS: char = '\u{200B}';
+/// The width of each character (`test_base_style.css` uses the "Ahem" font, in which each glyph is a 10px square)
+const H_WIDTH: f32 = 10.0;
+/// The height of each line of text
+const H_HEIGHT: f32 = 10.0;
+
+/// The direction in which text flows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WritingMode {
+    /// Text flows horizontally (`writing-mode: horizontal-tb`)
+    Horizontal,
+    /// Text flows vertically (`writing-mode: vertical-rl` or `writing-mode: vertical-lr`)
+    Vertical,
+}
+
+/// The context of a text leaf, which is measured by [`measure_function`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMeasure {
+    /// The text content of the node. Zero-width spaces mark the points at which the text may be wrapped.
+    pub text_content: String,
+    /// The direction in which the text flows
+    pub writing_mode: WritingMode,
+}
+
+/// Measure a node created by [`build_tree`](crate::build_tree). Nodes without a text context are measured as zero-sized.
+pub fn measure_function(
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    node_context: Option<&mut TextMeasure>,
+) -> Size<f32> {
+    if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+        return Size { width, height };
+    }
+
+    let node_context = match node_context {
+        Some(node_context) => node_context,
+        None => return Size::ZERO,
+    };
+
+    let inline_axis = match node_context.writing_mode {
+        WritingMode::Horizontal => AbsoluteAxis::Horizontal,
+        WritingMode::Vertical => AbsoluteAxis::Vertical,
+    };
+    let block_axis = inline_axis.other_axis();
+    let lines: Vec<&str> = node_context.text_content.split(ZWS).collect();
+
+    let min_line_length: usize = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let max_line_length: usize = lines.iter().map(|line| line.len()).sum();
+    let inline_size = known_dimensions
+        .get_abs(inline_axis)
+        .unwrap_or_else(|| match available_space.get_abs(inline_axis) {
+            AvailableSpace::MinContent => min_line_length as f32 * H_WIDTH,
+            AvailableSpace::MaxContent => max_line_length as f32 * H_WIDTH,
+            AvailableSpace::Definite(inline_size) => inline_size.min(max_line_length as f32 * H_WIDTH),
+        })
+        .max(min_line_length as f32 * H_WIDTH);
+    let block_size = known_dimensions.get_abs(block_axis).unwrap_or_else(|| {
+        let inline_line_length = (inline_size / H_WIDTH).floor() as usize;
+        let mut line_count = 1;
+        let mut current_line_length = 0;
+        for line in &lines {
+            if current_line_length + line.len() > inline_line_length {
+                if current_line_length > 0 {
+                    line_count += 1
+                };
+                current_line_length = line.len();
+            } else {
+                current_line_length += line.len();
+            };
+        }
+        (line_count as f32) * H_HEIGHT
+    });
+
+    match node_context.writing_mode {
+        WritingMode::Horizontal => Size { width: inline_size, height: block_size },
+        WritingMode::Vertical => Size { width: block_size, height: inline_size },
+    }
+}
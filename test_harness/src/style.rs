@@ -0,0 +1,311 @@
+//! Conversion of the `style` object of a node description into a Taffy [`Style`]
+//!
+//! Values which are missing, or which Taffy does not support, are left at their default.
+use serde_json::{Map, Value};
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{
+    AlignContent, AlignItems, Dimension, GridTrackRepetition, JustifyContent, LengthPercentage, LengthPercentageAuto,
+    MaxTrackSizingFunction, MinTrackSizingFunction, NonRepeatedTrackSizingFunction, Overflow, Position,
+    TrackSizingFunction,
+};
+
+/// Create a [`Style`] from the `style` object of a node description
+///
+/// # Panics
+///
+/// Panics if a value that is present does not have the shape that `describeElement` produces
+pub fn style_from_description(style: &Value) -> Style {
+    let mut result = Style::default();
+
+    if let Some(display) = style["display"].as_str() {
+        result.display = match display {
+            "none" => Display::None,
+            "block" => Display::Block,
+            "grid" => Display::Grid,
+            _ => Display::Flex,
+        };
+    }
+    if style["position"].as_str() == Some("absolute") {
+        result.position = Position::Absolute;
+    }
+    match style["flexDirection"].as_str() {
+        Some("row-reverse") => result.flex_direction = FlexDirection::RowReverse,
+        Some("column") => result.flex_direction = FlexDirection::Column,
+        Some("column-reverse") => result.flex_direction = FlexDirection::ColumnReverse,
+        _ => {}
+    }
+    match style["flexWrap"].as_str() {
+        Some("wrap") => result.flex_wrap = FlexWrap::Wrap,
+        Some("wrap-reverse") => result.flex_wrap = FlexWrap::WrapReverse,
+        _ => {}
+    }
+
+    let overflow_x = overflow(&style["overflowX"]);
+    let overflow_y = overflow(&style["overflowY"]);
+    if overflow_x.is_some() || overflow_y.is_some() {
+        result.overflow =
+            Point { x: overflow_x.unwrap_or(Overflow::Visible), y: overflow_y.unwrap_or(Overflow::Visible) };
+        if let Some(scrollbar_width) = style["scrollbarWidth"].as_f64() {
+            result.scrollbar_width = scrollbar_width as f32;
+        }
+    }
+
+    result.align_items = style["alignItems"].as_str().and_then(align_items);
+    result.align_self = style["alignSelf"].as_str().and_then(align_items);
+    result.justify_items = style["justifyItems"].as_str().and_then(align_items);
+    result.justify_self = style["justifySelf"].as_str().and_then(align_items);
+    result.align_content = style["alignContent"].as_str().and_then(align_content);
+    result.justify_content = style["justifyContent"].as_str().and_then(align_content);
+
+    if let Some(flex_grow) = style["flexGrow"].as_f64() {
+        result.flex_grow = flex_grow as f32;
+    }
+    if let Some(flex_shrink) = style["flexShrink"].as_f64() {
+        result.flex_shrink = flex_shrink as f32;
+    }
+    if let Some(flex_basis) = style["flexBasis"].as_object() {
+        result.flex_basis = dimension(flex_basis);
+    }
+
+    if let Some(size) = style["size"].as_object() {
+        result.size = size_of(size, dimension, Dimension::Auto);
+    }
+    if let Some(min_size) = style["minSize"].as_object() {
+        result.min_size = size_of(min_size, dimension, Dimension::Auto);
+    }
+    if let Some(max_size) = style["maxSize"].as_object() {
+        result.max_size = size_of(max_size, dimension, Dimension::Auto);
+    }
+    result.aspect_ratio = style["aspectRatio"].as_f64().map(|ratio| ratio as f32);
+    result.contain_size = style["containSize"].as_bool() == Some(true);
+    if let Some(contain_intrinsic_size) = style["containIntrinsicSize"].as_object() {
+        result.contain_intrinsic_size = size_of(contain_intrinsic_size, |value| Some(px(value)), None);
+    }
+
+    if let Some(gap) = style["gap"].as_object() {
+        let field = |name| gap.get(name).and_then(Value::as_object).map(length_percentage).unwrap_or(zero());
+        result.gap = Size { width: field("column"), height: field("row") };
+    }
+
+    if let Some(margin) = style["margin"].as_object() {
+        result.margin = edges_of(margin, length_percentage_auto, zero());
+    }
+    if let Some(padding) = style["padding"].as_object() {
+        result.padding = edges_of(padding, length_percentage, zero());
+    }
+    if let Some(border) = style["border"].as_object() {
+        result.border = edges_of(border, length_percentage, zero());
+    }
+    if let Some(inset) = style["inset"].as_object() {
+        result.inset = edges_of(inset, length_percentage_auto, auto());
+    }
+
+    if let Some(tracks) = style["gridTemplateRows"].as_array() {
+        result.grid_template_rows = tracks.iter().map(track_sizing_function).collect();
+    }
+    if let Some(tracks) = style["gridTemplateColumns"].as_array() {
+        result.grid_template_columns = tracks.iter().map(track_sizing_function).collect();
+    }
+    if let Some(tracks) = style["gridAutoRows"].as_array() {
+        result.grid_auto_rows = tracks.iter().map(non_repeated_track_sizing_function).collect();
+    }
+    if let Some(tracks) = style["gridAutoColumns"].as_array() {
+        result.grid_auto_columns = tracks.iter().map(non_repeated_track_sizing_function).collect();
+    }
+    if let Some(auto_flow) = style["gridAutoFlow"].as_object() {
+        let field = |name| auto_flow.get(name).and_then(Value::as_str);
+        result.grid_auto_flow = match (field("direction"), field("algorithm")) {
+            (Some("column"), Some("dense")) => GridAutoFlow::ColumnDense,
+            (Some("column"), _) => GridAutoFlow::Column,
+            (_, Some("dense")) => GridAutoFlow::RowDense,
+            _ => GridAutoFlow::Row,
+        };
+    }
+    result.grid_row = Line { start: grid_placement(&style["gridRowStart"]), end: grid_placement(&style["gridRowEnd"]) };
+    result.grid_column =
+        Line { start: grid_placement(&style["gridColumnStart"]), end: grid_placement(&style["gridColumnEnd"]) };
+
+    result
+}
+
+/// Parse an `overflow-x` or `overflow-y` value
+fn overflow(value: &Value) -> Option<Overflow> {
+    match value.as_str()? {
+        "hidden" => Some(Overflow::Hidden),
+        "clip" => Some(Overflow::Clip),
+        "scroll" => Some(Overflow::Scroll),
+        _ => None,
+    }
+}
+
+/// Parse an `align-items`, `align-self`, `justify-items` or `justify-self` value
+fn align_items(value: &str) -> Option<AlignItems> {
+    match value {
+        "start" => Some(AlignItems::Start),
+        "end" => Some(AlignItems::End),
+        "flex-start" => Some(AlignItems::FlexStart),
+        "flex-end" => Some(AlignItems::FlexEnd),
+        "center" => Some(AlignItems::Center),
+        "baseline" => Some(AlignItems::Baseline),
+        "stretch" => Some(AlignItems::Stretch),
+        _ => None,
+    }
+}
+
+/// Parse an `align-content` or `justify-content` value
+fn align_content(value: &str) -> Option<JustifyContent> {
+    match value {
+        "start" => Some(AlignContent::Start),
+        "end" => Some(AlignContent::End),
+        "flex-start" => Some(AlignContent::FlexStart),
+        "flex-end" => Some(AlignContent::FlexEnd),
+        "center" => Some(AlignContent::Center),
+        "safe end" => Some(AlignContent::SafeEnd),
+        "safe flex-end" => Some(AlignContent::SafeFlexEnd),
+        "safe center" => Some(AlignContent::SafeCenter),
+        "stretch" => Some(AlignContent::Stretch),
+        "space-between" => Some(AlignContent::SpaceBetween),
+        "space-around" => Some(AlignContent::SpaceAround),
+        "space-evenly" => Some(AlignContent::SpaceEvenly),
+        _ => None,
+    }
+}
+
+/// The `unit` of a `{ unit, value }` object
+fn unit(value: &Map<String, Value>) -> &str {
+    value.get("unit").and_then(Value::as_str).unwrap_or_else(|| panic!("missing unit in {value:?}"))
+}
+
+/// The numeric `value` of a `{ unit, value }` object
+fn number(value: &Map<String, Value>) -> f32 {
+    value.get("value").and_then(Value::as_f64).unwrap_or_else(|| panic!("missing value in {value:?}")) as f32
+}
+
+/// Parse a value which must be a length in pixels
+fn px(value: &Map<String, Value>) -> f32 {
+    match unit(value) {
+        "px" => number(value),
+        unit => panic!("expected a length in pixels, found unit {unit:?}"),
+    }
+}
+
+/// Parse a length or percentage
+fn length_percentage(value: &Map<String, Value>) -> LengthPercentage {
+    match unit(value) {
+        "px" => LengthPercentage::Length(number(value)),
+        "percent" => LengthPercentage::Percent(number(value)),
+        unit => panic!("unsupported unit {unit:?} for a length or percentage"),
+    }
+}
+
+/// Parse a length, percentage or `auto`
+fn length_percentage_auto(value: &Map<String, Value>) -> LengthPercentageAuto {
+    match unit(value) {
+        "auto" => LengthPercentageAuto::Auto,
+        _ => length_percentage(value).into(),
+    }
+}
+
+/// Parse a length, percentage, `auto` or `content`
+fn dimension(value: &Map<String, Value>) -> Dimension {
+    match unit(value) {
+        "auto" => Dimension::Auto,
+        "content" => Dimension::Content,
+        _ => length_percentage(value).into(),
+    }
+}
+
+/// Parse a `{ width, height }` object, using `default` for missing components
+fn size_of<T: Copy>(size: &Map<String, Value>, parse: impl Fn(&Map<String, Value>) -> T, default: T) -> Size<T> {
+    let field = |name| size.get(name).and_then(Value::as_object).map(&parse).unwrap_or(default);
+    Size { width: field("width"), height: field("height") }
+}
+
+/// Parse a `{ left, right, top, bottom }` object, using `default` for missing components
+fn edges_of<T: Copy>(edges: &Map<String, Value>, parse: impl Fn(&Map<String, Value>) -> T, default: T) -> Rect<T> {
+    let field = |name| edges.get(name).and_then(Value::as_object).map(&parse).unwrap_or(default);
+    Rect { left: field("left"), right: field("right"), top: field("top"), bottom: field("bottom") }
+}
+
+/// Parse a `grid-row-start`, `grid-row-end`, `grid-column-start` or `grid-column-end` value
+fn grid_placement(value: &Value) -> GridPlacement {
+    let index = || value["value"].as_i64().unwrap_or_else(|| panic!("missing value in {value:?}"));
+    match value["kind"].as_str() {
+        Some("span") => GridPlacement::Span(index() as u16),
+        Some("line") => line(index() as i16),
+        _ => GridPlacement::Auto,
+    }
+}
+
+/// Parse an entry of a `grid-template-rows` or `grid-template-columns` list
+fn track_sizing_function(value: &Value) -> TrackSizingFunction {
+    if value["kind"].as_str() != Some("function") || value["name"].as_str() != Some("repeat") {
+        return TrackSizingFunction::Single(non_repeated_track_sizing_function(value));
+    }
+
+    let arguments = value["arguments"].as_array().filter(|arguments| arguments.len() >= 2);
+    let arguments = arguments.unwrap_or_else(|| panic!("repeat function with the wrong number of arguments"));
+    let repetition = match arguments[0]["unit"].as_str() {
+        Some("auto-fill") => GridTrackRepetition::AutoFill,
+        Some("auto-fit") => GridTrackRepetition::AutoFit,
+        Some("integer") => GridTrackRepetition::Count(arguments[0]["value"].as_u64().unwrap_or(1) as u16),
+        _ => panic!("invalid repetition {:?}", arguments[0]),
+    };
+    let tracks = arguments[1..].iter().map(non_repeated_track_sizing_function).collect();
+    TrackSizingFunction::Repeat(repetition, tracks)
+}
+
+/// Parse a single grid track sizing function (a keyword, length, `minmax()` or `fit-content()`)
+fn non_repeated_track_sizing_function(value: &Value) -> NonRepeatedTrackSizingFunction {
+    let arguments = || value["arguments"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+    match (value["kind"].as_str(), value["name"].as_str()) {
+        (Some("function"), Some("minmax")) => match arguments() {
+            [min, max] => MinMax { min: min_track_sizing_function(min), max: max_track_sizing_function(max) },
+            _ => panic!("minmax function with the wrong number of arguments"),
+        },
+        (Some("function"), Some("fit-content")) => match arguments() {
+            [argument] => fit_content(length_percentage(scalar(argument))),
+            _ => panic!("fit-content function with the wrong number of arguments"),
+        },
+        (Some("function"), name) => panic!("unsupported grid track function {name:?}"),
+        _ => {
+            let value = scalar(value);
+            match unit(value) {
+                "fraction" => fr(number(value)),
+                "auto" => auto(),
+                "min-content" => min_content(),
+                "max-content" => max_content(),
+                _ => NonRepeatedTrackSizingFunction::fixed(length_percentage(value)),
+            }
+        }
+    }
+}
+
+/// Parse the first argument of a `minmax()` function
+fn min_track_sizing_function(value: &Value) -> MinTrackSizingFunction {
+    let value = scalar(value);
+    match unit(value) {
+        "auto" => MinTrackSizingFunction::Auto,
+        "min-content" => MinTrackSizingFunction::MinContent,
+        "max-content" => MinTrackSizingFunction::MaxContent,
+        _ => MinTrackSizingFunction::Fixed(length_percentage(value)),
+    }
+}
+
+/// Parse the second argument of a `minmax()` function
+fn max_track_sizing_function(value: &Value) -> MaxTrackSizingFunction {
+    let value = scalar(value);
+    match unit(value) {
+        "auto" => MaxTrackSizingFunction::Auto,
+        "min-content" => MaxTrackSizingFunction::MinContent,
+        "max-content" => MaxTrackSizingFunction::MaxContent,
+        "fraction" => MaxTrackSizingFunction::Fraction(number(value)),
+        _ => MaxTrackSizingFunction::Fixed(length_percentage(value)),
+    }
+}
+
+/// The `{ unit, value }` object of a scalar grid track sizing function
+fn scalar(value: &Value) -> &Map<String, Value> {
+    value.as_object().unwrap_or_else(|| panic!("expected a grid track sizing function, found {value:?}"))
+}
@@ -0,0 +1,150 @@
+//! Content distribution (`justify-content` / `align-content`) in containers whose content overflows them.
+//!
+//! With negative free space `space-between` and `stretch` fall back to `flex-start`, and `space-around` and
+//! `space-evenly` fall back to `center`. The `safe` variants of `end`, `flex-end` and `center` fall back to `start`.
+//! These are not generated test fixtures because browsers allow overflow at the start of reversed flex containers to
+//! be scrolled to, which Taffy's scroll size does not account for.
+use taffy::prelude::*;
+
+/// Lay out a container with the given style containing three 50x50 items (which can't shrink), and return the
+/// horizontal and vertical positions of the items
+fn item_positions(container_style: Style) -> Vec<(f32, f32)> {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let items: Vec<NodeId> = (0..3)
+        .map(|_| taffy.new_leaf(Style { size: length(50.0), flex_shrink: 0.0, ..Default::default() }).unwrap())
+        .collect();
+    let container = taffy.new_with_children(container_style, &items).unwrap();
+    taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+    items.iter().map(|&item| taffy.layout(item).unwrap().location).map(|location| (location.x, location.y)).collect()
+}
+
+/// The positions of three 50px items overflowing a 100px container in each direction, for each `justify-content`
+/// keyword in a reversed flex container
+const REVERSED_CASES: [(JustifyContent, [f32; 3]); 12] = [
+    (JustifyContent::FlexStart, [50.0, 0.0, -50.0]),
+    (JustifyContent::FlexEnd, [100.0, 50.0, 0.0]),
+    (JustifyContent::Start, [100.0, 50.0, 0.0]),
+    (JustifyContent::End, [50.0, 0.0, -50.0]),
+    (JustifyContent::Center, [75.0, 25.0, -25.0]),
+    (JustifyContent::SpaceBetween, [50.0, 0.0, -50.0]),
+    (JustifyContent::SpaceAround, [75.0, 25.0, -25.0]),
+    (JustifyContent::SpaceEvenly, [75.0, 25.0, -25.0]),
+    (JustifyContent::Stretch, [50.0, 0.0, -50.0]),
+    (JustifyContent::SafeEnd, [100.0, 50.0, 0.0]),
+    (JustifyContent::SafeFlexEnd, [100.0, 50.0, 0.0]),
+    (JustifyContent::SafeCenter, [100.0, 50.0, 0.0]),
+];
+
+/// As [`REVERSED_CASES`], but for containers which are not reversed
+const CASES: [(JustifyContent, [f32; 3]); 12] = [
+    (JustifyContent::FlexStart, [0.0, 50.0, 100.0]),
+    (JustifyContent::FlexEnd, [-50.0, 0.0, 50.0]),
+    (JustifyContent::Start, [0.0, 50.0, 100.0]),
+    (JustifyContent::End, [-50.0, 0.0, 50.0]),
+    (JustifyContent::Center, [-25.0, 25.0, 75.0]),
+    (JustifyContent::SpaceBetween, [0.0, 50.0, 100.0]),
+    (JustifyContent::SpaceAround, [-25.0, 25.0, 75.0]),
+    (JustifyContent::SpaceEvenly, [-25.0, 25.0, 75.0]),
+    (JustifyContent::Stretch, [0.0, 50.0, 100.0]),
+    (JustifyContent::SafeEnd, [0.0, 50.0, 100.0]),
+    (JustifyContent::SafeFlexEnd, [0.0, 50.0, 100.0]),
+    (JustifyContent::SafeCenter, [0.0, 50.0, 100.0]),
+];
+
+#[test]
+fn flex_justify_content_negative_space() {
+    for (justify_content, expected) in CASES {
+        let style = Style { justify_content: Some(justify_content), size: length(100.0), ..Default::default() };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(x, _)| x).collect();
+        assert_eq!(positions, expected, "{justify_content:?}");
+    }
+}
+
+#[test]
+fn flex_justify_content_negative_space_row_reverse() {
+    for (justify_content, expected) in REVERSED_CASES {
+        let style = Style {
+            flex_direction: FlexDirection::RowReverse,
+            justify_content: Some(justify_content),
+            size: length(100.0),
+            ..Default::default()
+        };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(x, _)| x).collect();
+        assert_eq!(positions, expected, "{justify_content:?}");
+    }
+}
+
+#[test]
+fn flex_justify_content_negative_space_column_reverse() {
+    for (justify_content, expected) in REVERSED_CASES {
+        let style = Style {
+            flex_direction: FlexDirection::ColumnReverse,
+            justify_content: Some(justify_content),
+            size: length(100.0),
+            ..Default::default()
+        };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(_, y)| y).collect();
+        assert_eq!(positions, expected, "{justify_content:?}");
+    }
+}
+
+#[test]
+fn flex_space_between_single_item_row_reverse() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let item = taffy.new_leaf(Style { size: length(20.0), ..Default::default() }).unwrap();
+    let container_style = Style {
+        flex_direction: FlexDirection::RowReverse,
+        justify_content: Some(JustifyContent::SpaceBetween),
+        size: length(100.0),
+        ..Default::default()
+    };
+    let container = taffy.new_with_children(container_style, &[item]).unwrap();
+    taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+    // A single item is placed at the main-start edge, which is the right hand edge of a row-reverse container
+    assert_eq!(taffy.layout(item).unwrap().location.x, 80.0);
+}
+
+#[test]
+fn flex_align_content_negative_space_wrap_reverse() {
+    // The container is too narrow for two items, so each item is in a flex line of its own
+    for (align_content, expected) in REVERSED_CASES {
+        let style = Style {
+            flex_wrap: FlexWrap::WrapReverse,
+            align_content: Some(align_content),
+            size: Size { width: length(60.0), height: length(100.0) },
+            ..Default::default()
+        };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(_, y)| y).collect();
+        assert_eq!(positions, expected, "{align_content:?}");
+    }
+}
+
+#[test]
+fn grid_content_alignment_negative_space() {
+    for (alignment, expected) in CASES {
+        let style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![length(50.0); 3],
+            grid_template_rows: vec![length(50.0); 3],
+            grid_auto_flow: GridAutoFlow::Row,
+            justify_content: Some(alignment),
+            size: length(100.0),
+            ..Default::default()
+        };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(x, _)| x).collect();
+        assert_eq!(positions, expected, "justify-content: {alignment:?}");
+
+        let style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![length(50.0); 3],
+            grid_template_rows: vec![length(50.0); 3],
+            grid_auto_flow: GridAutoFlow::Column,
+            align_content: Some(alignment),
+            size: length(100.0),
+            ..Default::default()
+        };
+        let positions: Vec<f32> = item_positions(style).into_iter().map(|(_, y)| y).collect();
+        assert_eq!(positions, expected, "align-content: {alignment:?}");
+    }
+}
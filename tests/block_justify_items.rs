@@ -0,0 +1,99 @@
+//! Inline-axis alignment of the children of block containers using `justify_items` and `justify_self`.
+//!
+//! Browsers do not yet implement `justify-self` for block-level boxes, so these cannot be generated test fixtures.
+use taffy::prelude::*;
+
+/// Lay out a 300px wide block container with the given `justify_items` containing a single child with the given style
+fn layout_child(justify_items: Option<JustifyItems>, child_style: Style) -> Layout {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let grandchild = taffy.new_leaf(Style { size: length(40.0), ..Default::default() }).unwrap();
+    let child = taffy.new_with_children(Style { display: Display::Block, ..child_style }, &[grandchild]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                justify_items,
+                size: Size { width: length(300.0), height: auto() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    *taffy.layout(child).unwrap()
+}
+
+/// A child with a definite width of 100px
+fn sized_child() -> Style {
+    Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() }
+}
+
+#[test]
+fn justify_items_positions_sized_child() {
+    let cases = [
+        (None, 0.0),
+        (Some(JustifyItems::Stretch), 0.0),
+        (Some(JustifyItems::Start), 0.0),
+        (Some(JustifyItems::Center), 100.0),
+        (Some(JustifyItems::End), 200.0),
+        (Some(JustifyItems::FlexEnd), 200.0),
+    ];
+    for (justify_items, expected_x) in cases {
+        let layout = layout_child(justify_items, sized_child());
+        assert_eq!(layout.location.x, expected_x, "{justify_items:?}");
+        assert_eq!(layout.size.width, 100.0, "{justify_items:?}");
+    }
+}
+
+#[test]
+fn justify_self_overrides_justify_items() {
+    let layout =
+        layout_child(Some(JustifyItems::Center), Style { justify_self: Some(JustifySelf::End), ..sized_child() });
+    assert_eq!(layout.location.x, 200.0);
+
+    let layout = layout_child(None, Style { justify_self: Some(JustifySelf::Center), ..sized_child() });
+    assert_eq!(layout.location.x, 100.0);
+}
+
+#[test]
+fn auto_width_child_is_stretched_by_default() {
+    for justify_items in [None, Some(JustifyItems::Stretch)] {
+        let layout = layout_child(justify_items, Style::default());
+        assert_eq!(layout.location.x, 0.0, "{justify_items:?}");
+        assert_eq!(layout.size.width, 300.0, "{justify_items:?}");
+    }
+}
+
+#[test]
+fn aligned_auto_width_child_is_sized_to_fit_its_content() {
+    let layout = layout_child(Some(JustifyItems::Center), Style::default());
+    assert_eq!(layout.size.width, 40.0);
+    assert_eq!(layout.location.x, 130.0);
+
+    let layout = layout_child(Some(JustifyItems::End), Style::default());
+    assert_eq!(layout.size.width, 40.0);
+    assert_eq!(layout.location.x, 260.0);
+}
+
+#[test]
+fn alignment_is_applied_within_margins() {
+    let margin = Rect { left: length(20.0), right: length(40.0), top: zero(), bottom: zero() };
+    let layout = layout_child(Some(JustifyItems::Center), Style { margin, ..sized_child() });
+    assert_eq!(layout.location.x, 20.0 + 70.0);
+
+    let layout = layout_child(Some(JustifyItems::End), Style { margin, ..sized_child() });
+    assert_eq!(layout.location.x, 160.0);
+}
+
+#[test]
+fn auto_margins_take_precedence_over_alignment() {
+    let margin = Rect { left: auto(), right: zero(), top: zero(), bottom: zero() };
+    let layout = layout_child(Some(JustifyItems::Start), Style { margin, ..sized_child() });
+    assert_eq!(layout.location.x, 200.0);
+
+    // An aligned auto-width child is sized to fit its content and then centered by its auto margins
+    let margin = Rect { left: auto(), right: auto(), top: zero(), bottom: zero() };
+    let layout = layout_child(Some(JustifyItems::End), Style { margin, ..Default::default() });
+    assert_eq!(layout.size.width, 40.0);
+    assert_eq!(layout.location.x, 130.0);
+}
@@ -37,6 +37,47 @@ mod caching {
         assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 4);
     }
 
+    #[test]
+    fn measure_count_contain_size() {
+        // A column of fixed-height items (such as a virtualised list) whose contents are only laid out in their final
+        // layout if the items have size containment
+        fn measure_count(contain_size: bool) -> usize {
+            let mut taffy: TaffyTree<CountMeasure> = TaffyTree::new();
+
+            let leaf = taffy.new_leaf_with_context(Style::default(), CountMeasure::new()).unwrap();
+            let node = taffy.new_with_children(Style::DEFAULT, &[leaf]).unwrap();
+            let item_style =
+                Style { contain_size, size: Size { width: auto(), height: length(30.0) }, ..Default::default() };
+            let item = taffy.new_with_children(item_style, &[node]).unwrap();
+            let root_style = Style { flex_direction: FlexDirection::Column, size: length(200.0), ..Default::default() };
+            let root = taffy.new_with_children(root_style, &[item]).unwrap();
+
+            taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, count_measure_function).unwrap();
+            taffy.get_node_context_mut(leaf).unwrap().count
+        }
+
+        assert_eq!(measure_count(false), 6);
+        assert_eq!(measure_count(true), 4);
+    }
+
+    #[test]
+    fn measure_count_contain_size_leaf() {
+        // A leaf with size containment is never measured, and is sized by its `contain_intrinsic_size` instead
+        let mut taffy: TaffyTree<CountMeasure> = TaffyTree::new();
+
+        let leaf_style = Style {
+            contain_size: true,
+            contain_intrinsic_size: Size { width: Some(20.0), height: None },
+            ..Default::default()
+        };
+        let leaf = taffy.new_leaf_with_context(leaf_style, CountMeasure::new()).unwrap();
+        let root = taffy.new_with_children(Style::DEFAULT, &[leaf]).unwrap();
+
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, count_measure_function).unwrap();
+        assert_eq!(taffy.get_node_context_mut(leaf).unwrap().count, 0);
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 20.0, height: 0.0 });
+    }
+
     #[test]
     #[cfg(feature = "grid")]
     fn measure_count_grid() {
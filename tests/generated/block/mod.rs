@@ -16,6 +16,7 @@ mod block_absolute_layout_child_order;
 mod block_absolute_layout_no_size;
 mod block_absolute_layout_percentage_bottom_based_on_parent_height;
 mod block_absolute_layout_percentage_height;
+mod block_absolute_layout_percentage_inset_with_border;
 mod block_absolute_layout_row_width_height_end_bottom;
 mod block_absolute_layout_start_top_end_bottom;
 mod block_absolute_layout_width_height_end_bottom;
@@ -65,11 +66,14 @@ mod block_aspect_ratio_fill_max_width;
 mod block_aspect_ratio_fill_min_height;
 mod block_aspect_ratio_fill_min_width;
 mod block_aspect_ratio_fill_width;
+mod block_aspect_ratio_stretched_width_container;
 mod block_basic;
 mod block_border_fixed_size;
 mod block_border_intrinsic_size;
 mod block_border_percentage_fixed_size;
 mod block_border_percentage_intrinsic_size;
+mod block_contain_intrinsic_size;
+mod block_contain_size_is_sized_as_if_empty;
 mod block_display_none;
 mod block_display_none_with_child;
 mod block_display_none_with_inset;
@@ -169,6 +173,7 @@ mod block_margin_y_simple_positive_percentage_other;
 mod block_margin_y_simple_positive_percentage_self;
 mod block_margin_y_total_collapse;
 mod block_margin_y_total_collapse_complex;
+mod block_overflow_scroll_end_padding;
 mod block_overflow_scrollbars_overriden_by_available_space;
 mod block_overflow_scrollbars_overriden_by_max_size;
 mod block_overflow_scrollbars_overriden_by_size;
@@ -186,3 +191,4 @@ mod block_padding_fixed_size;
 mod block_padding_intrinsic_size;
 mod block_padding_percentage_fixed_size;
 mod block_padding_percentage_intrinsic_size;
+mod block_percentage_width_resolves_against_content_sized_container;
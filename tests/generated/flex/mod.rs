@@ -30,6 +30,7 @@ mod absolute_layout_justify_content_center;
 mod absolute_layout_no_size;
 mod absolute_layout_percentage_bottom_based_on_parent_height;
 mod absolute_layout_percentage_height;
+mod absolute_layout_percentage_inset_with_border;
 mod absolute_layout_row_width_height_end_bottom;
 mod absolute_layout_start_top_end_bottom;
 mod absolute_layout_width_height_end_bottom;
@@ -44,6 +45,11 @@ mod absolute_minmax_top_left_bottom_right_max;
 mod absolute_minmax_top_left_bottom_right_min_max;
 mod absolute_padding_border_overrides_max_size;
 mod absolute_padding_border_overrides_size;
+mod absolute_percentage_insets_bottom_right_asymmetric_border_padding;
+mod absolute_percentage_insets_overflow_scroll_asymmetric_border_padding;
+mod absolute_percentage_insets_top_left_asymmetric_border_padding;
+mod absolute_static_position_with_left_inset;
+mod absolute_static_position_with_top_inset;
 mod align_baseline;
 mod align_baseline_child;
 mod align_baseline_child_margin;
@@ -79,6 +85,7 @@ mod align_content_flex_start;
 mod align_content_flex_start_with_flex;
 mod align_content_flex_start_without_height_on_children;
 mod align_content_not_stretch_with_align_items_stretch;
+mod align_content_safe_center_negative_space;
 mod align_content_space_around_single_line;
 mod align_content_space_around_single_line_negative_space;
 mod align_content_space_around_single_line_negative_space_gap;
@@ -116,6 +123,7 @@ mod align_content_stretch_row_with_max_height;
 mod align_content_stretch_row_with_min_height;
 mod align_content_stretch_row_with_padding;
 mod align_content_stretch_row_with_single_row;
+mod align_content_stretch_row_with_unequal_lines;
 mod align_flex_start_with_shrinking_children;
 mod align_flex_start_with_shrinking_children_with_stretch;
 mod align_flex_start_with_stretching_children;
@@ -124,6 +132,9 @@ mod align_items_center_child_with_margin_bigger_than_parent;
 mod align_items_center_child_without_margin_bigger_than_parent;
 mod align_items_center_justify_content_center;
 mod align_items_center_min_max_with_padding;
+mod align_items_center_nowrap_definite_height_align_content_flex_start;
+mod align_items_center_nowrap_max_height;
+mod align_items_center_nowrap_min_height_align_content_end;
 mod align_items_center_with_child_margin;
 mod align_items_center_with_child_top;
 mod align_items_flex_end;
@@ -133,6 +144,7 @@ mod align_items_flex_start;
 mod align_items_min_max;
 mod align_items_stretch;
 mod align_items_stretch_min_cross;
+mod align_items_stretch_nowrap_min_height_align_content_flex_start;
 mod align_self_baseline;
 mod align_self_center;
 mod align_self_center_undefined_max_height;
@@ -186,6 +198,10 @@ mod border_no_size;
 mod border_stretch_child;
 mod child_min_max_width_flexing;
 mod child_with_padding_align_end;
+mod contain_intrinsic_size_clamped_by_max_size;
+mod contain_intrinsic_size_flex_item;
+mod contain_size_allows_flex_item_to_shrink_below_content_size;
+mod contain_size_flex_item_is_sized_as_if_empty;
 mod container_with_unsized_child;
 mod content_size;
 mod display_none;
@@ -198,6 +214,13 @@ mod display_none_with_position;
 mod display_none_with_position_absolute;
 mod do_not_clamp_height_of_absolute_node_to_height_of_its_overflow_hidden_parent;
 mod flex_basis_and_main_dimen_set_when_flexing;
+mod flex_basis_auto_content_column;
+mod flex_basis_auto_size_column;
+mod flex_basis_auto_size_column_reverse;
+mod flex_basis_auto_size_row;
+mod flex_basis_auto_size_row_reverse;
+mod flex_basis_content_column;
+mod flex_basis_content_row;
 mod flex_basis_flex_grow_column;
 mod flex_basis_flex_grow_row;
 mod flex_basis_flex_shrink_column;
@@ -217,7 +240,10 @@ mod flex_basis_smaller_then_content_with_flex_grow_very_large_size;
 mod flex_basis_unconstraint_column;
 mod flex_basis_unconstraint_row;
 mod flex_basis_zero_undefined_main_size;
+mod flex_column_max_height_clamps_content_size;
+mod flex_column_min_height_expands_content_size;
 mod flex_column_relative_all_sides;
+mod flex_column_wrap_max_height_clamps_content_size_before_align_content;
 mod flex_direction_column;
 mod flex_direction_column_no_height;
 mod flex_direction_column_reverse;
@@ -225,6 +251,13 @@ mod flex_direction_column_reverse_no_height;
 mod flex_direction_row;
 mod flex_direction_row_no_width;
 mod flex_direction_row_reverse;
+mod flex_gap_column_reverse_uses_row_gap_for_main_axis;
+mod flex_gap_column_uses_row_gap_for_main_axis;
+mod flex_gap_column_wrap_uses_column_gap_for_cross_axis;
+mod flex_gap_row_reverse_uses_column_gap_for_main_axis;
+mod flex_gap_shrink_wrap_max_content;
+mod flex_gap_shrink_wrap_max_content_without_gap;
+mod flex_gap_wrap_min_content;
 mod flex_grow_child;
 mod flex_grow_flex_basis_percent_min_max;
 mod flex_grow_height_maximized;
@@ -233,6 +266,8 @@ mod flex_grow_less_than_factor_one;
 mod flex_grow_root_minimized;
 mod flex_grow_shrink_at_most;
 mod flex_grow_to_min;
+mod flex_grow_with_flex_basis_larger_than_max_width;
+mod flex_grow_with_max_width_redistributes_space;
 mod flex_grow_within_constrained_max_column;
 mod flex_grow_within_constrained_max_row;
 mod flex_grow_within_constrained_max_width;
@@ -242,10 +277,15 @@ mod flex_grow_within_constrained_min_row;
 mod flex_grow_within_max_width;
 mod flex_root_ignored;
 mod flex_row_relative_all_sides;
+mod flex_row_wrap_max_width_wraps_at_max_width;
 mod flex_shrink_by_outer_margin_with_max_size;
 mod flex_shrink_flex_grow_child_flex_shrink_other_child;
 mod flex_shrink_flex_grow_row;
+mod flex_shrink_floors_at_padding_border_with_zero_min_size;
+mod flex_shrink_floors_at_padding_border_with_zero_min_size_column;
+mod flex_shrink_min_content_of_container_with_padding_border;
 mod flex_shrink_to_zero;
+mod flex_shrink_with_min_width_redistributes_space;
 mod flex_wrap_align_stretch_fits_one_row;
 mod flex_wrap_children_with_min_main_overriding_flex_basis;
 mod flex_wrap_wrap_to_child_height;
@@ -261,6 +301,8 @@ mod gap_column_gap_justify_flex_start;
 mod gap_column_gap_justify_space_around;
 mod gap_column_gap_justify_space_between;
 mod gap_column_gap_justify_space_evenly;
+mod gap_column_gap_larger_than_container;
+mod gap_column_gap_larger_than_container_row_reverse;
 mod gap_column_gap_mixed_flexible;
 mod gap_column_gap_percentage_cyclic_partially_shrinkable;
 mod gap_column_gap_percentage_cyclic_shrinkable;
@@ -330,6 +372,10 @@ mod justify_content_row_min_width_and_margin;
 mod justify_content_row_space_around;
 mod justify_content_row_space_between;
 mod justify_content_row_space_evenly;
+mod justify_content_safe_center_negative_space;
+mod justify_content_safe_center_positive_space;
+mod justify_content_safe_end_negative_space;
+mod justify_content_safe_flex_end_positive_space;
 mod margin_and_flex_column;
 mod margin_and_flex_row;
 mod margin_and_stretch_column;
@@ -404,6 +450,7 @@ mod overflow_main_axis;
 mod overflow_main_axis_shrink_hidden;
 mod overflow_main_axis_shrink_scroll;
 mod overflow_main_axis_shrink_visible;
+mod overflow_scroll_end_padding;
 mod overflow_scroll_main_axis_justify_content_end;
 mod overflow_scrollbars_overriden_by_available_space;
 mod overflow_scrollbars_overriden_by_max_size;
@@ -411,6 +458,8 @@ mod overflow_scrollbars_overriden_by_size;
 mod overflow_scrollbars_take_up_space_both_axis;
 mod overflow_scrollbars_take_up_space_cross_axis;
 mod overflow_scrollbars_take_up_space_main_axis;
+mod overflow_x_scroll_y_visible_intrinsic_size;
+mod overflow_y_scroll_x_visible_intrinsic_size;
 mod padding_align_end_child;
 mod padding_border_overrides_max_size;
 mod padding_border_overrides_min_size;
@@ -420,6 +469,7 @@ mod padding_border_overrides_size_flex_basis_0_growable;
 mod padding_center_child;
 mod padding_container_match_child;
 mod padding_flex_child;
+mod padding_larger_than_container_justify_content_center;
 mod padding_no_child;
 mod padding_no_size;
 mod padding_stretch_child;
@@ -439,6 +489,7 @@ mod percentage_flex_basis_cross_min_width;
 mod percentage_flex_basis_main_max_height;
 mod percentage_flex_basis_main_max_width;
 mod percentage_flex_basis_main_min_width;
+mod percentage_height_resolves_against_stretched_item;
 mod percentage_main_max_height;
 mod percentage_margin_should_calculate_based_only_on_width;
 mod percentage_moderate_complexity;
@@ -452,6 +503,7 @@ mod percentage_size_of_flex_basis;
 mod percentage_sizes_should_not_prevent_flex_shrinking;
 mod percentage_width_height;
 mod percentage_width_height_undefined_parent_size;
+mod percentage_width_resolves_against_content_sized_container;
 mod position_root_with_rtl_should_position_withoutdirection;
 mod relative_position_should_not_nudge_siblings;
 mod rounding_flex_basis_flex_grow_row_prime_number_width;
@@ -29,14 +29,26 @@ mod grid_absolute_container_top_right_margin;
 #[cfg(feature = "grid")]
 mod grid_absolute_justify_self_sized_all;
 #[cfg(feature = "grid")]
+mod grid_absolute_layout_percentage_inset_with_border;
+#[cfg(feature = "grid")]
 mod grid_absolute_layout_within_border;
 #[cfg(feature = "grid")]
 mod grid_absolute_layout_within_border_static;
 #[cfg(feature = "grid")]
+mod grid_absolute_percentage_insets_bottom_right_asymmetric_border_padding;
+#[cfg(feature = "grid")]
+mod grid_absolute_percentage_insets_overflow_scroll_asymmetric_border_padding;
+#[cfg(feature = "grid")]
+mod grid_absolute_percentage_insets_top_left_asymmetric_border_padding;
+#[cfg(feature = "grid")]
 mod grid_absolute_row_end;
 #[cfg(feature = "grid")]
 mod grid_absolute_row_start;
 #[cfg(feature = "grid")]
+mod grid_absolute_static_position_with_left_inset;
+#[cfg(feature = "grid")]
+mod grid_absolute_static_position_with_top_inset;
+#[cfg(feature = "grid")]
 mod grid_absolute_top_overrides_bottom;
 #[cfg(feature = "grid")]
 mod grid_absolute_with_padding;
@@ -53,6 +65,10 @@ mod grid_align_content_end_negative_space_gap;
 #[cfg(feature = "grid")]
 mod grid_align_content_end_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_align_content_safe_end_negative_space;
+#[cfg(feature = "grid")]
+mod grid_align_content_safe_end_positive_space;
+#[cfg(feature = "grid")]
 mod grid_align_content_space_around;
 #[cfg(feature = "grid")]
 mod grid_align_content_space_around_negative_space_gap;
@@ -109,6 +125,8 @@ mod grid_align_items_baseline_multiline_row_and_column;
 #[cfg(feature = "grid")]
 mod grid_align_items_baseline_nested_column;
 #[cfg(feature = "grid")]
+mod grid_align_items_center_with_align_self_overrides;
+#[cfg(feature = "grid")]
 mod grid_align_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_end;
@@ -117,6 +135,8 @@ mod grid_align_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_align_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_align_items_stretch_suppressed_by_definite_height;
+#[cfg(feature = "grid")]
 mod grid_align_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_aspect_ratio_absolute_aspect_ratio_overrides_height_of_full_inset;
@@ -151,16 +171,50 @@ mod grid_aspect_ratio_overriden_by_explicit_sizes_flex;
 #[cfg(feature = "grid")]
 mod grid_auto_columns;
 #[cfg(feature = "grid")]
+mod grid_auto_columns_cycle_across_items;
+#[cfg(feature = "grid")]
 mod grid_auto_columns_fixed_width;
 #[cfg(feature = "grid")]
+mod grid_auto_columns_shrink_wrap_container;
+#[cfg(feature = "grid")]
+mod grid_auto_columns_shrink_wrap_container_with_empty_implicit_tracks;
+#[cfg(feature = "grid")]
+mod grid_auto_columns_shrink_wrap_flex_item;
+#[cfg(feature = "grid")]
+mod grid_auto_fill_empty;
+#[cfg(feature = "grid")]
 mod grid_auto_fill_fixed_size;
 #[cfg(feature = "grid")]
+mod grid_auto_fill_min_size_larger_than_max_size;
+#[cfg(feature = "grid")]
 mod grid_auto_fill_with_empty_auto_track;
 #[cfg(feature = "grid")]
+mod grid_auto_fit_empty_min_size;
+#[cfg(feature = "grid")]
+mod grid_auto_fit_empty_with_fixed_track;
+#[cfg(feature = "grid")]
+mod grid_auto_fit_sparse_first_track_justify_content_center;
+#[cfg(feature = "grid")]
+mod grid_auto_fit_sparse_middle_track_justify_content_center;
+#[cfg(feature = "grid")]
+mod grid_auto_fit_sparse_outer_tracks_justify_content_center;
+#[cfg(feature = "grid")]
 mod grid_auto_fit_with_empty_auto_track;
 #[cfg(feature = "grid")]
+mod grid_auto_flow_column_explicit_rows_only;
+#[cfg(feature = "grid")]
+mod grid_auto_flow_row_explicit_columns_only;
+#[cfg(feature = "grid")]
+mod grid_auto_placement_span_both_axes;
+#[cfg(feature = "grid")]
+mod grid_auto_placement_span_both_axes_column_flow;
+#[cfg(feature = "grid")]
+mod grid_auto_placement_span_both_axes_dense;
+#[cfg(feature = "grid")]
 mod grid_auto_rows;
 #[cfg(feature = "grid")]
+mod grid_auto_rows_cycle_across_items;
+#[cfg(feature = "grid")]
 mod grid_auto_single_item;
 #[cfg(feature = "grid")]
 mod grid_auto_single_item_fixed_width;
@@ -183,8 +237,14 @@ mod grid_basic_with_overflow;
 #[cfg(feature = "grid")]
 mod grid_basic_with_padding;
 #[cfg(feature = "grid")]
+mod grid_contain_intrinsic_width_only;
+#[cfg(feature = "grid")]
+mod grid_contain_size_is_sized_by_explicit_tracks;
+#[cfg(feature = "grid")]
 mod grid_display_none_fixed_size;
 #[cfg(feature = "grid")]
+mod grid_empty_explicit_tracks;
+#[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_argument;
 #[cfg(feature = "grid")]
 mod grid_fit_content_percent_definite_max_content;
@@ -209,6 +269,18 @@ mod grid_fit_content_points_min_content;
 #[cfg(feature = "grid")]
 mod grid_fit_content_points_min_content_hidden;
 #[cfg(feature = "grid")]
+mod grid_fixed_max_track_item_margin;
+#[cfg(feature = "grid")]
+mod grid_fr_auto_min_grows_to_fit_wide_content;
+#[cfg(feature = "grid")]
+mod grid_fr_column_item_margin_percent;
+#[cfg(feature = "grid")]
+mod grid_fr_column_max_content_item_margin;
+#[cfg(feature = "grid")]
+mod grid_fr_column_max_content_item_margin_asymmetric;
+#[cfg(feature = "grid")]
+mod grid_fr_columns_spanning_item_margin;
+#[cfg(feature = "grid")]
 mod grid_fr_fixed_size_no_content_proportions;
 #[cfg(feature = "grid")]
 mod grid_fr_fixed_size_no_content_proportions_sub_1_sum;
@@ -219,6 +291,8 @@ mod grid_fr_no_sized_items_indefinite;
 #[cfg(feature = "grid")]
 mod grid_fr_single_item_indefinite;
 #[cfg(feature = "grid")]
+mod grid_fr_span_2_minmax_min_content_unequal_definite;
+#[cfg(feature = "grid")]
 mod grid_fr_span_2_proportion;
 #[cfg(feature = "grid")]
 mod grid_fr_span_2_proportion_sub_1_sum;
@@ -229,8 +303,18 @@ mod grid_fr_span_2_proportion_zero_sum;
 #[cfg(feature = "grid")]
 mod grid_fr_span_2_proportion_zero_sum_with_non_spanned_track;
 #[cfg(feature = "grid")]
+mod grid_fr_span_3_equal_proportion;
+#[cfg(feature = "grid")]
+mod grid_fr_span_3_unequal_proportion;
+#[cfg(feature = "grid")]
+mod grid_fr_span_3_with_fixed_track;
+#[cfg(feature = "grid")]
+mod grid_fr_zero_min_does_not_grow_to_fit_wide_content;
+#[cfg(feature = "grid")]
 mod grid_gap;
 #[cfg(feature = "grid")]
+mod grid_gap_larger_than_container;
+#[cfg(feature = "grid")]
 mod grid_hidden;
 #[cfg(feature = "grid")]
 mod grid_justify_content_center;
@@ -245,6 +329,8 @@ mod grid_justify_content_end_negative_space_gap;
 #[cfg(feature = "grid")]
 mod grid_justify_content_end_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_content_safe_center_negative_space;
+#[cfg(feature = "grid")]
 mod grid_justify_content_space_around;
 #[cfg(feature = "grid")]
 mod grid_justify_content_space_around_negative_space_gap;
@@ -269,6 +355,8 @@ mod grid_justify_content_start_negative_space_gap;
 #[cfg(feature = "grid")]
 mod grid_justify_content_start_with_padding_border;
 #[cfg(feature = "grid")]
+mod grid_justify_items_center_with_justify_self_overrides;
+#[cfg(feature = "grid")]
 mod grid_justify_items_sized_center;
 #[cfg(feature = "grid")]
 mod grid_justify_items_sized_end;
@@ -277,6 +365,8 @@ mod grid_justify_items_sized_start;
 #[cfg(feature = "grid")]
 mod grid_justify_items_sized_stretch;
 #[cfg(feature = "grid")]
+mod grid_justify_items_stretch_suppressed_by_definite_width;
+#[cfg(feature = "grid")]
 mod grid_justify_self_sized_all;
 #[cfg(feature = "grid")]
 mod grid_margins_auto_margins;
@@ -341,6 +431,16 @@ mod grid_min_content_maximum_single_item;
 #[cfg(feature = "grid")]
 mod grid_min_content_single_item;
 #[cfg(feature = "grid")]
+mod grid_min_height_auto_fixed_row;
+#[cfg(feature = "grid")]
+mod grid_min_height_auto_fr_row;
+#[cfg(feature = "grid")]
+mod grid_min_height_auto_spanning_auto_rows_beside_fr_row;
+#[cfg(feature = "grid")]
+mod grid_minmax_100px_auto_definite;
+#[cfg(feature = "grid")]
+mod grid_minmax_100px_auto_justify_content_start;
+#[cfg(feature = "grid")]
 mod grid_minmax_auto_fixed_10px;
 #[cfg(feature = "grid")]
 mod grid_minmax_auto_max_content;
@@ -387,6 +487,10 @@ mod grid_minmax_min_content_percent_definite;
 #[cfg(feature = "grid")]
 mod grid_minmax_min_content_percent_indefinite;
 #[cfg(feature = "grid")]
+mod grid_nested_percent_tracks_in_auto_track;
+#[cfg(feature = "grid")]
+mod grid_nested_percent_tracks_resolve_against_grid_area;
+#[cfg(feature = "grid")]
 mod grid_out_of_order_items;
 #[cfg(feature = "grid")]
 mod grid_overflow_inline_axis_hidden;
@@ -397,6 +501,8 @@ mod grid_overflow_inline_axis_visible;
 #[cfg(feature = "grid")]
 mod grid_overflow_rows;
 #[cfg(feature = "grid")]
+mod grid_overflow_scroll_end_padding;
+#[cfg(feature = "grid")]
 mod grid_overflow_scrollbars_overriden_by_available_space;
 #[cfg(feature = "grid")]
 mod grid_overflow_scrollbars_overriden_by_max_size;
@@ -409,6 +515,8 @@ mod grid_overflow_scrollbars_take_up_space_x_axis;
 #[cfg(feature = "grid")]
 mod grid_overflow_scrollbars_take_up_space_y_axis;
 #[cfg(feature = "grid")]
+mod grid_overflow_x_scroll_y_visible_intrinsic_size;
+#[cfg(feature = "grid")]
 mod grid_padding_border_overrides_container_max_size;
 #[cfg(feature = "grid")]
 mod grid_padding_border_overrides_container_size;
@@ -419,6 +527,8 @@ mod grid_padding_border_overrides_min_size;
 #[cfg(feature = "grid")]
 mod grid_padding_border_overrides_size;
 #[cfg(feature = "grid")]
+mod grid_padding_larger_than_container;
+#[cfg(feature = "grid")]
 mod grid_percent_item_inside_stretch_item;
 #[cfg(feature = "grid")]
 mod grid_percent_items_nested_inside_stretch_alignment;
@@ -443,8 +553,12 @@ mod grid_percent_tracks_indefinite_with_content_overflow;
 #[cfg(feature = "grid")]
 mod grid_percent_tracks_indefinite_with_content_underflow;
 #[cfg(feature = "grid")]
+mod grid_percentage_size_resolves_against_grid_area;
+#[cfg(feature = "grid")]
 mod grid_placement_auto_negative;
 #[cfg(feature = "grid")]
+mod grid_placement_definite_column_behind_cursor;
+#[cfg(feature = "grid")]
 mod grid_placement_definite_in_secondary_axis_with_fully_definite_negative;
 #[cfg(feature = "grid")]
 mod grid_relative_all_sides;
@@ -453,8 +567,14 @@ mod grid_relayout_vertical_text;
 #[cfg(feature = "grid")]
 mod grid_repeat_integer;
 #[cfg(feature = "grid")]
+mod grid_repeat_integer_and_auto_fill;
+#[cfg(feature = "grid")]
 mod grid_repeat_mixed;
 #[cfg(feature = "grid")]
+mod grid_self_alignment_auto_uses_container_items;
+#[cfg(feature = "grid")]
+mod grid_self_alignment_defaults_to_stretch;
+#[cfg(feature = "grid")]
 mod grid_size_child_fixed_tracks;
 #[cfg(feature = "grid")]
 mod grid_span_13_most_non_flex_with_minmax_indefinite;
@@ -500,3 +620,5 @@ mod grid_span_6_all_non_flex_indefinite;
 mod grid_span_6_all_non_flex_indefinite_hidden;
 #[cfg(feature = "grid")]
 mod grid_span_8_all_track_types_indefinite;
+#[cfg(feature = "grid")]
+mod grid_wrapped_text_row_height;
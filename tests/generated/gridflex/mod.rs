@@ -10,3 +10,13 @@ mod gridflex_kitchen_sink_minimise2;
 mod gridflex_kitchen_sink_minimise3;
 #[cfg(feature = "grid")]
 mod gridflex_row_integration;
+#[cfg(feature = "grid")]
+mod gridflex_wrap_item_auto_column;
+#[cfg(feature = "grid")]
+mod gridflex_wrap_item_auto_column_auto_width_grid;
+#[cfg(feature = "grid")]
+mod gridflex_wrap_item_auto_column_beside_fr_column;
+#[cfg(feature = "grid")]
+mod gridflex_wrap_item_fit_content_column;
+#[cfg(feature = "grid")]
+mod gridflex_wrap_item_fr_column;
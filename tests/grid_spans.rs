@@ -0,0 +1,45 @@
+//! Grid items with invalid (zero) or enormous spans. Spans are normalised to be between 1 and `MAX_GRID_SPAN` tracks.
+use taffy::geometry::Point;
+use taffy::prelude::*;
+
+/// Lay out a grid container with two 10px columns containing an item with the given column placement followed by an
+/// auto-placed item, and return the layouts of the two items
+fn item_layouts(grid_column: Line<GridPlacement>) -> (Layout, Layout) {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let item =
+        taffy.new_leaf(Style { grid_column, size: Size { width: auto(), height: length(10.0) }, ..Default::default() });
+    let auto_item = taffy.new_leaf(Style { size: Size { width: auto(), height: length(10.0) }, ..Default::default() });
+    let (item, auto_item) = (item.unwrap(), auto_item.unwrap());
+    let container_style =
+        Style { display: Display::Grid, grid_template_columns: vec![length(10.0); 2], ..Default::default() };
+    let container = taffy.new_with_children(container_style, &[item, auto_item]).unwrap();
+    taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+    (*taffy.layout(item).unwrap(), *taffy.layout(auto_item).unwrap())
+}
+
+#[test]
+fn zero_span_is_treated_as_one() {
+    // Auto-placed
+    let (item, auto_item) = item_layouts(Line { start: GridPlacement::Span(0), end: auto() });
+    assert_eq!((item.location, item.size.width), (Point { x: 0.0, y: 0.0 }, 10.0));
+    assert_eq!(auto_item.location, Point { x: 10.0, y: 0.0 });
+
+    // Combined with an explicit line
+    let (item, auto_item) = item_layouts(Line { start: GridPlacement::Span(0), end: line(-1) });
+    assert_eq!((item.location, item.size.width), (Point { x: 10.0, y: 0.0 }, 10.0));
+    assert_eq!(auto_item.location, Point { x: 0.0, y: 10.0 });
+
+    // The `span` helper also normalises its argument
+    assert_eq!(span::<GridPlacement>(0), GridPlacement::Span(1));
+}
+
+#[test]
+// The item needs `MAX_GRID_SPAN` columns, which is more than a grid can have with fixed-capacity tracks
+#[cfg(not(feature = "fixed-capacity-grids"))]
+fn enormous_span_is_clamped() {
+    let (item, auto_item) = item_layouts(Line { start: line(2), end: GridPlacement::Span(u16::MAX) });
+
+    // The item covers the second explicit column and `MAX_GRID_SPAN - 1` empty implicit columns
+    assert_eq!((item.location, item.size.width), (Point { x: 10.0, y: 0.0 }, 10.0));
+    assert_eq!(auto_item.location, Point { x: 0.0, y: 10.0 });
+}
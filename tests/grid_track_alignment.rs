@@ -0,0 +1,64 @@
+//! Per-track alignment of grid items with the non-standard `align_tracks` and `justify_tracks` properties
+use taffy::geometry::Point;
+use taffy::prelude::*;
+
+/// Lay out a 100x100 grid container with the given style, containing one 10x10 item in each of its two 50px rows (or
+/// columns, if `grid_auto_flow` is `Column`), and return the locations of the items
+fn item_locations(container_style: Style, item_styles: [Style; 2]) -> [Point<f32>; 2] {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let items = item_styles.map(|style| {
+        taffy.new_leaf(Style { size: Size { width: length(10.0), height: length(10.0) }, ..style }).unwrap()
+    });
+    let container_style = Style {
+        display: Display::Grid,
+        size: Size { width: length(100.0), height: length(100.0) },
+        grid_template_rows: vec![length(50.0); 2],
+        grid_template_columns: vec![length(50.0); 2],
+        ..container_style
+    };
+    let container = taffy.new_with_children(container_style, &items).unwrap();
+    taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+    items.map(|item| taffy.layout(item).unwrap().location)
+}
+
+#[test]
+fn align_tracks_aligns_each_row() {
+    let style = Style { align_tracks: vec![AlignContent::Start, AlignContent::End], ..Default::default() };
+    let [first, second] = item_locations(style, [Style::default(), Style { grid_row: line(2), ..Default::default() }]);
+    assert_eq!(first, Point { x: 0.0, y: 0.0 });
+    assert_eq!(second, Point { x: 0.0, y: 90.0 });
+}
+
+#[test]
+fn justify_tracks_aligns_each_column() {
+    let style = Style { justify_tracks: vec![AlignContent::End, AlignContent::Center], ..Default::default() };
+    let [first, second] = item_locations(style, [Style::default(), Style::default()]);
+    assert_eq!(first, Point { x: 40.0, y: 0.0 });
+    assert_eq!(second, Point { x: 70.0, y: 0.0 });
+}
+
+#[test]
+fn last_track_alignment_repeats() {
+    let style =
+        Style { align_tracks: vec![AlignContent::End], align_items: Some(AlignItems::Start), ..Default::default() };
+    let [first, second] = item_locations(style, [Style::default(), Style { grid_row: line(2), ..Default::default() }]);
+    assert_eq!(first, Point { x: 0.0, y: 40.0 });
+    assert_eq!(second, Point { x: 0.0, y: 90.0 });
+}
+
+#[test]
+fn align_self_takes_precedence() {
+    let style = Style { align_tracks: vec![AlignContent::End], ..Default::default() };
+    let [first, second] =
+        item_locations(style, [Style { align_self: Some(AlignSelf::Center), ..Default::default() }, Style::default()]);
+    assert_eq!(first, Point { x: 0.0, y: 20.0 });
+    assert_eq!(second, Point { x: 50.0, y: 40.0 });
+}
+
+#[test]
+fn empty_track_alignment_is_ignored() {
+    let style = Style { align_items: Some(AlignItems::Center), ..Default::default() };
+    let [first, second] = item_locations(style, [Style::default(), Style::default()]);
+    assert_eq!(first, Point { x: 0.0, y: 20.0 });
+    assert_eq!(second, Point { x: 50.0, y: 20.0 });
+}
@@ -0,0 +1,66 @@
+//! Baseline alignment of leaf nodes whose first baseline is computed from their `Style::line_metrics`
+use taffy::prelude::*;
+use taffy::style::LineMetrics;
+
+/// Lay out a container with the given style containing two fixed-size leaves with line metrics (the first of which
+/// has padding), and return the vertical positions of the leaves and the baseline of the container
+fn baseline_aligned_positions(container_style: Style) -> (f32, f32, Option<f32>) {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+
+    // The baselines are 6 + (20 / 2 + 4) = 20 and 10 / 2 + 2 = 7 below the top of each leaf
+    let first = taffy
+        .new_leaf(Style {
+            size: Size { width: length(50.0), height: length(40.0) },
+            padding: Rect { left: zero(), right: zero(), top: length(6.0), bottom: zero() },
+            line_metrics: Some(LineMetrics { line_height: 20.0, baseline_offset: 4.0 }),
+            ..Default::default()
+        })
+        .unwrap();
+    let second = taffy
+        .new_leaf(Style {
+            size: length(30.0),
+            line_metrics: Some(LineMetrics { line_height: 10.0, baseline_offset: 2.0 }),
+            ..Default::default()
+        })
+        .unwrap();
+    let container = taffy.new_with_children(container_style, &[first, second]).unwrap();
+    taffy.compute_layout(container, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(taffy.baseline(first).unwrap(), Some(20.0));
+    assert_eq!(taffy.baseline(second).unwrap(), Some(7.0));
+    let y = |node| taffy.layout(node).unwrap().location.y;
+    (y(first), y(second), taffy.baseline(container).unwrap())
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn flex_items_are_aligned_by_line_metrics() {
+    let style = Style { align_items: Some(AlignItems::Baseline), ..Default::default() };
+    assert_eq!(baseline_aligned_positions(style), (0.0, 13.0, Some(20.0)));
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn grid_items_are_aligned_by_line_metrics() {
+    let style = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![auto(), auto()],
+        align_items: Some(AlignItems::Baseline),
+        ..Default::default()
+    };
+    assert_eq!(baseline_aligned_positions(style), (0.0, 13.0, Some(20.0)));
+}
+
+#[test]
+fn leaf_without_valid_line_metrics_has_no_baseline() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let without_metrics = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+    let nan_metrics = Some(LineMetrics { line_height: f32::NAN, baseline_offset: 0.0 });
+    let with_nan_metrics =
+        taffy.new_leaf(Style { size: length(10.0), line_metrics: nan_metrics, ..Default::default() }).unwrap();
+
+    for node in [without_metrics, with_nan_metrics] {
+        taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.baseline(node).unwrap(), None);
+    }
+}
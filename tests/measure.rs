@@ -36,6 +36,57 @@ mod measure {
         Size { width, height }
     }
 
+    // Wraps `text_width` worth of 10px words onto 10px tall lines
+    fn text_measure_function(
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _node_id: NodeId,
+        node_context: Option<&mut f32>,
+    ) -> taffy::geometry::Size<f32> {
+        let text_width = node_context.copied().unwrap_or(0.0);
+        let line_width = known_dimensions.width.unwrap_or(match available_space.width {
+            AvailableSpace::Definite(width) => width.min(text_width),
+            AvailableSpace::MinContent => 10.0,
+            AvailableSpace::MaxContent => text_width,
+        });
+        let line_width = line_width.max(10.0);
+        let height = known_dimensions.height.unwrap_or(10.0 * (text_width / line_width).ceil());
+        Size { width: line_width.min(text_width), height }
+    }
+
+    // Wraps words of the given widths onto 10px tall lines, breaking lines only between words. So the min-content
+    // width is the width of the longest word, however small the available space is.
+    fn words_measure_function(
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _node_id: NodeId,
+        node_context: Option<&mut &'static [f32]>,
+    ) -> taffy::geometry::Size<f32> {
+        let words = node_context.map_or(&[][..], |words| *words);
+        let min_content_width = words.iter().copied().fold(0.0, f32::max);
+        let max_content_width = words.iter().sum();
+        let wrap_width = known_dimensions.width.unwrap_or(match available_space.width {
+            AvailableSpace::Definite(width) => width.clamp(min_content_width, max_content_width),
+            AvailableSpace::MinContent => min_content_width,
+            AvailableSpace::MaxContent => max_content_width,
+        });
+
+        let (mut line_count, mut line_width, mut width) = (0, f32::INFINITY, 0.0f32);
+        for &word in words {
+            if line_width + word > wrap_width {
+                line_count += 1;
+                line_width = word;
+            } else {
+                line_width += word;
+            }
+            width = width.max(line_width);
+        }
+        Size {
+            width: known_dimensions.width.unwrap_or(width),
+            height: known_dimensions.height.unwrap_or(10.0 * line_count as f32),
+        }
+    }
+
     #[test]
     fn measure_root() {
         let mut taffy: TaffyTree<FixedMeasure> = TaffyTree::new();
@@ -277,6 +328,82 @@ mod measure {
         assert_eq!(taffy.layout(child).unwrap().size.height, 100.0);
     }
 
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn remeasure_text_in_nested_stretched_flex_containers() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+
+        // A row containing a fixed-width sidebar and a growing column, which contains a row stretched to the width of
+        // the column, which contains a column containing 600px of text. The text should wrap at the 150px width that
+        // the column ends up with, not at the width that it had when the containers were first measured.
+        let text = taffy.new_leaf_with_context(Style::default(), 600.0).unwrap();
+        let column_style = Style { flex_direction: FlexDirection::Column, ..Default::default() };
+        let inner_column = taffy.new_with_children(column_style.clone(), &[text]).unwrap();
+        let row = taffy.new_with_children(Style::default(), &[inner_column]).unwrap();
+        let outer_column = taffy.new_with_children(Style { flex_grow: 1.0, ..column_style }, &[row]).unwrap();
+        let sidebar = taffy
+            .new_leaf(Style {
+                size: Size { width: length(50.0), height: auto() },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+                &[sidebar, outer_column],
+            )
+            .unwrap();
+
+        let measure_count = std::cell::Cell::new(0);
+        let mut measure_function = |known, available, node_id, context: Option<&mut f32>| {
+            measure_count.set(measure_count.get() + 1);
+            text_measure_function(known, available, node_id, context)
+        };
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, &mut measure_function).unwrap();
+
+        assert_eq!(taffy.layout(text).unwrap().size, Size { width: 150.0, height: 40.0 });
+        assert_eq!(taffy.layout(inner_column).unwrap().size, Size { width: 150.0, height: 40.0 });
+        assert_eq!(taffy.layout(outer_column).unwrap().size, Size { width: 150.0, height: 40.0 });
+        assert_eq!(taffy.layout(sidebar).unwrap().size, Size { width: 50.0, height: 40.0 });
+
+        // Laying out again without any changes reuses the cached layouts rather than measuring the text again
+        let first_measure_count = measure_count.get();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, &mut measure_function).unwrap();
+        assert_eq!(measure_count.get(), first_measure_count);
+    }
+
+    #[test]
+    fn measure_with_available_space_below_min_content() {
+        const WORDS: &[f32] = &[20.0, 30.0, 10.0];
+        let available_space = Size { width: AvailableSpace::Definite(10.0), height: AvailableSpace::MaxContent };
+
+        // The leaf overflows the available space rather than being clamped to it
+        let mut taffy: TaffyTree<&'static [f32]> = TaffyTree::new();
+        let leaf = taffy.new_leaf_with_context(Style::default(), WORDS).unwrap();
+        taffy.compute_layout_with_measure(leaf, available_space, words_measure_function).unwrap();
+        assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 30.0, height: 30.0 });
+
+        // Flex items don't shrink below their min-content size, and grid tracks grow to fit the min-content size of
+        // their items, even in a container that is narrower than that
+        let containers = [
+            #[cfg(feature = "flexbox")]
+            Style { display: Display::Flex, ..Default::default() },
+            #[cfg(feature = "grid")]
+            Style { display: Display::Grid, grid_template_columns: vec![auto()], ..Default::default() },
+        ];
+        for container_style in containers {
+            let display = container_style.display;
+            let mut taffy: TaffyTree<&'static [f32]> = TaffyTree::new();
+            let leaf = taffy.new_leaf_with_context(Style::default(), WORDS).unwrap();
+            let container_style = Style { size: Size { width: length(10.0), height: auto() }, ..container_style };
+            let container = taffy.new_with_children(container_style, &[leaf]).unwrap();
+            taffy.compute_layout_with_measure(container, available_space, words_measure_function).unwrap();
+            assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 30.0, height: 30.0 }, "{display:?}");
+            assert_eq!(taffy.layout(container).unwrap().size, Size { width: 10.0, height: 30.0 }, "{display:?}");
+        }
+    }
+
     #[test]
     fn width_overrides_measure() {
         let mut taffy: TaffyTree<FixedMeasure> = TaffyTree::new();
@@ -410,4 +537,185 @@ mod measure {
         assert_eq!(taffy.layout(child).unwrap().size.width, 100.0);
         assert_eq!(taffy.layout(child).unwrap().size.height, 100.0);
     }
+
+    #[test]
+    #[cfg(feature = "grid")]
+    fn measure_grid_items_with_track_sizes() {
+        let mut taffy: TaffyTree<f32> = TaffyTree::new();
+        let long_text = taffy.new_leaf_with_context(Style::default(), 300.0).unwrap();
+        let short_text = taffy.new_leaf_with_context(Style::default(), 50.0).unwrap();
+        let node = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![fr(1.0), fr(1.0)],
+                    size: Size { width: length(200.0), height: auto() },
+                    ..Default::default()
+                },
+                &[long_text, short_text],
+            )
+            .unwrap();
+
+        let mut measured_widths = Vec::new();
+        taffy
+            .compute_layout_with_measure(node, Size::MAX_CONTENT, |known, available, node_id, context| {
+                measured_widths.push(available.width);
+                text_measure_function(known, available, node_id, context)
+            })
+            .unwrap();
+
+        // The long text wraps onto 3 lines within its 100px column (not 2 lines within the container's 200px)
+        assert_eq!(taffy.layout(node).unwrap().size.height, 30.0);
+        assert_eq!(taffy.layout(long_text).unwrap().size, Size { width: 100.0, height: 30.0 });
+        assert_eq!(taffy.layout(short_text).unwrap().size, Size { width: 100.0, height: 30.0 });
+
+        // Items are only ever measured with an intrinsic constraint or the size of the track they span
+        assert!(measured_widths.iter().all(|width| match width {
+            AvailableSpace::Definite(width) => *width == 100.0,
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => true,
+        }));
+    }
+
+    #[test]
+    #[cfg(feature = "flexbox")]
+    fn measure_leaf_size_precedence() {
+        let padding = Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) };
+        let flex_column = Style {
+            flex_direction: FlexDirection::Column,
+            size: Size { width: length(300.0), height: auto() },
+            ..Default::default()
+        };
+
+        // (description, parent style, leaf style, expected leaf size). Each leaf contains 600px of 10px tall text.
+        let cases = [
+            (
+                "style width is the content-box wrap width",
+                None,
+                Style { size: Size { width: length(200.0), height: auto() }, padding, ..Default::default() },
+                Size { width: 200.0, height: 60.0 },
+            ),
+            (
+                "style width as a flex item",
+                Some(Style::default()),
+                Style { size: Size { width: length(200.0), height: auto() }, padding, ..Default::default() },
+                Size { width: 200.0, height: 60.0 },
+            ),
+            (
+                "stretched width",
+                Some(flex_column.clone()),
+                Style { padding, ..Default::default() },
+                Size { width: 300.0, height: 50.0 },
+            ),
+            (
+                "style height",
+                None,
+                Style { size: Size { width: auto(), height: length(100.0) }, padding, ..Default::default() },
+                Size { width: 620.0, height: 100.0 },
+            ),
+            (
+                "aspect ratio",
+                None,
+                Style {
+                    size: Size { width: length(200.0), height: auto() },
+                    aspect_ratio: Some(2.0),
+                    padding,
+                    ..Default::default()
+                },
+                Size { width: 200.0, height: 100.0 },
+            ),
+            (
+                "max width",
+                None,
+                Style { max_size: Size { width: length(150.0), height: auto() }, padding, ..Default::default() },
+                Size { width: 150.0, height: 70.0 },
+            ),
+            (
+                "min width",
+                None,
+                Style { min_size: Size { width: length(300.0), height: auto() }, padding, ..Default::default() },
+                Size { width: 620.0, height: 30.0 },
+            ),
+            (
+                "min width overrides style width",
+                Some(Style::default()),
+                Style {
+                    size: Size { width: length(200.0), height: auto() },
+                    min_size: Size { width: length(250.0), height: auto() },
+                    padding,
+                    ..Default::default()
+                },
+                Size { width: 250.0, height: 50.0 },
+            ),
+            (
+                "padding and border",
+                Some(Style::default()),
+                Style {
+                    size: Size { width: length(200.0), height: auto() },
+                    padding,
+                    border: Rect { left: length(5.0), right: length(5.0), top: length(5.0), bottom: length(5.0) },
+                    ..Default::default()
+                },
+                Size { width: 200.0, height: 70.0 },
+            ),
+        ];
+
+        for (description, parent_style, leaf_style, expected_size) in cases {
+            let mut taffy: TaffyTree<f32> = TaffyTree::new();
+            let leaf = taffy.new_leaf_with_context(leaf_style, 600.0).unwrap();
+            let root = match parent_style {
+                Some(parent_style) => taffy.new_with_children(parent_style, &[leaf]).unwrap(),
+                None => leaf,
+            };
+            taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, text_measure_function).unwrap();
+            assert_eq!(taffy.layout(leaf).unwrap().size, expected_size, "{description}");
+        }
+    }
+
+    #[test]
+    fn measure_result_excludes_padding_and_border() {
+        let padding = Rect { left: length(8.0), right: length(8.0), top: length(8.0), bottom: length(8.0) };
+        let border = Rect { left: length(2.0), right: length(2.0), top: length(2.0), bottom: length(2.0) };
+
+        // (description, leaf style, expected leaf size, available width first passed to the measure function)
+        let cases = [
+            ("padding", Style { padding, ..Default::default() }, Size { width: 116.0, height: 66.0 }, 284.0),
+            ("border", Style { border, ..Default::default() }, Size { width: 104.0, height: 54.0 }, 296.0),
+            (
+                "padding and border",
+                Style { padding, border, ..Default::default() },
+                Size { width: 120.0, height: 70.0 },
+                280.0,
+            ),
+            (
+                "min size smaller than padding plus content",
+                Style { padding, min_size: Size { width: length(110.0), height: auto() }, ..Default::default() },
+                Size { width: 116.0, height: 66.0 },
+                284.0,
+            ),
+            (
+                "min size larger than padding plus content",
+                Style { padding, min_size: Size { width: length(150.0), height: auto() }, ..Default::default() },
+                Size { width: 150.0, height: 66.0 },
+                284.0,
+            ),
+        ];
+
+        for (description, leaf_style, expected_size, expected_available_width) in cases {
+            let mut taffy: TaffyTree<FixedMeasure> = TaffyTree::new();
+            let leaf = taffy.new_leaf_with_context(leaf_style, FixedMeasure { width: 100.0, height: 50.0 }).unwrap();
+            let available_space = Size { width: AvailableSpace::Definite(300.0), height: AvailableSpace::MaxContent };
+
+            let mut available_widths = Vec::new();
+            taffy
+                .compute_layout_with_measure(leaf, available_space, |known, available, node_id, context| {
+                    available_widths.push(available.width);
+                    fixed_measure_function(known, available, node_id, context)
+                })
+                .unwrap();
+
+            assert_eq!(taffy.layout(leaf).unwrap().size, expected_size, "{description}");
+            // The node is first measured within the available space (then again within its final content box)
+            assert_eq!(available_widths[0], AvailableSpace::Definite(expected_available_width), "{description}");
+        }
+    }
 }
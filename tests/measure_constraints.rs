@@ -0,0 +1,94 @@
+//! The available space that measure functions are called with. Intrinsic sizing passes measure nodes under
+//! min-content and max-content constraints (so that a node can report its unwrapped size), and the final layout pass
+//! measures them in the definite space that they are laid out in (so that they can wrap at that width).
+use taffy::prelude::*;
+use AvailableSpace::{Definite, MaxContent, MinContent};
+
+/// Wraps words of the given widths onto 10px tall lines, breaking lines only between words
+fn words_measure_function(
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    node_context: Option<&mut &'static [f32]>,
+) -> Size<f32> {
+    let words = node_context.map_or(&[][..], |words| *words);
+    let min_content_width = words.iter().copied().fold(0.0, f32::max);
+    let max_content_width = words.iter().sum();
+    let wrap_width = known_dimensions.width.unwrap_or(match available_space.width {
+        Definite(width) => width.clamp(min_content_width, max_content_width),
+        MinContent => min_content_width,
+        MaxContent => max_content_width,
+    });
+
+    let (mut line_count, mut line_width, mut width) = (0, f32::INFINITY, 0.0f32);
+    for &word in words {
+        if line_width + word > wrap_width {
+            line_count += 1;
+            line_width = word;
+        } else {
+            line_width += word;
+        }
+        width = width.max(line_width);
+    }
+    Size {
+        width: known_dimensions.width.unwrap_or(width),
+        height: known_dimensions.height.unwrap_or(10.0 * line_count as f32),
+    }
+}
+
+/// Lay out a leaf containing 60px of words (the longest of which is 30px) in 200px of available space, inside a
+/// container with the given style if there is one, and return the known width and the available width of each call
+/// to the measure function in order
+fn record_constraints(container_style: Option<Style>) -> Vec<(Option<f32>, AvailableSpace)> {
+    const WORDS: &[f32] = &[20.0, 30.0, 10.0];
+    let mut taffy: TaffyTree<&'static [f32]> = TaffyTree::new();
+    let leaf = taffy.new_leaf_with_context(Style::default(), WORDS).unwrap();
+    let root = match container_style {
+        Some(style) => taffy.new_with_children(style, &[leaf]).unwrap(),
+        None => leaf,
+    };
+
+    let mut constraints = Vec::new();
+    let available_space = Size { width: Definite(200.0), height: MaxContent };
+    taffy
+        .compute_layout_with_measure(root, available_space, |known, available, node_id, context| {
+            constraints.push((known.width, available.width));
+            words_measure_function(known, available, node_id, context)
+        })
+        .unwrap();
+    assert_eq!(taffy.layout(leaf).unwrap().size, Size { width: 60.0, height: 10.0 });
+    constraints
+}
+
+#[test]
+fn leaf_is_measured_in_definite_space() {
+    // The leaf is measured in the available space, and then at the size it was given
+    assert_eq!(record_constraints(None), vec![(None, Definite(200.0)), (None, Definite(60.0))]);
+}
+
+#[test]
+#[cfg(feature = "flexbox")]
+fn flex_item_is_measured_under_intrinsic_constraints() {
+    let style = Style { size: Size { width: length(200.0), height: auto() }, ..Default::default() };
+
+    // The flex base size is the max-content size and the automatic minimum size is the min-content size, neither of
+    // which depend on the width of the container. The item is then measured at its final width.
+    assert_eq!(record_constraints(Some(style)), vec![(None, MaxContent), (None, MinContent), (None, Definite(60.0))]);
+}
+
+#[test]
+#[cfg(feature = "grid")]
+fn grid_item_is_measured_under_intrinsic_constraints() {
+    let style = Style {
+        display: Display::Grid,
+        justify_items: Some(AlignItems::Start),
+        size: Size { width: length(200.0), height: auto() },
+        ..Default::default()
+    };
+    let constraints = record_constraints(Some(style));
+
+    // The `auto` column is sized using the min-content and max-content contributions of the item. The item is then
+    // laid out in the 200px width of its grid area, in which it fits without wrapping.
+    assert_eq!(constraints[..2], [(None, MinContent), (None, MaxContent)]);
+    assert_eq!(constraints.last(), Some(&(None, Definite(200.0))));
+}
@@ -68,4 +68,179 @@ mod min_max_overrides {
 
         assert_eq!(taffy.layout(child).unwrap().size, Size { width: 100.0, height: 100.0 });
     }
+
+    /// A measure function for content with an aspect ratio of 2 that is 100px wide unless constrained
+    fn aspect_ratio_content_measure(
+        known_dimensions: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+        _node_id: NodeId,
+        _node_context: Option<&mut ()>,
+    ) -> Size<f32> {
+        let width = known_dimensions.width.unwrap_or(match available_space.width {
+            AvailableSpace::Definite(available_width) => available_width.min(100.0),
+            _ => 100.0,
+        });
+        Size { width, height: known_dimensions.height.unwrap_or(width / 2.0) }
+    }
+
+    /// Rows of the CSS 2 table for violated min/max constraints on a box with an aspect ratio
+    /// (https://www.w3.org/TR/CSS22/visudet.html#min-max-widths) as
+    /// `((min_width, max_width, min_height, max_height), (expected_width, expected_height))`
+    #[allow(clippy::type_complexity)]
+    const ASPECT_RATIO_CONSTRAINT_CASES: [((Option<f32>, Option<f32>, Option<f32>, Option<f32>), (f32, f32)); 13] = [
+        ((None, None, None, None), (100.0, 50.0)),
+        ((None, Some(80.0), None, None), (80.0, 40.0)),
+        ((Some(120.0), None, None, None), (120.0, 60.0)),
+        ((None, None, None, Some(40.0)), (80.0, 40.0)),
+        ((None, None, Some(60.0), None), (120.0, 60.0)),
+        ((None, Some(80.0), None, Some(30.0)), (60.0, 30.0)),
+        ((None, Some(60.0), None, Some(40.0)), (60.0, 30.0)),
+        ((Some(120.0), None, Some(70.0), None), (140.0, 70.0)),
+        ((Some(150.0), None, Some(60.0), None), (150.0, 75.0)),
+        ((Some(120.0), None, None, Some(40.0)), (120.0, 40.0)),
+        ((None, Some(80.0), Some(60.0), None), (80.0, 60.0)),
+        ((None, Some(80.0), Some(45.0), None), (80.0, 45.0)),
+        ((Some(90.0), None, None, Some(40.0)), (90.0, 40.0)),
+    ];
+
+    fn aspect_ratio_style(constraints: (Option<f32>, Option<f32>, Option<f32>, Option<f32>)) -> Style {
+        let (min_width, max_width, min_height, max_height) = constraints;
+        let to_dimension = |value: Option<f32>| value.map(Dimension::Length).unwrap_or(Dimension::Auto);
+        Style {
+            aspect_ratio: Some(2.0),
+            min_size: Size { width: to_dimension(min_width), height: to_dimension(min_height) },
+            max_size: Size { width: to_dimension(max_width), height: to_dimension(max_height) },
+            justify_self: Some(JustifySelf::Start),
+            align_self: Some(AlignSelf::Start),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn aspect_ratio_min_max_constraints() {
+        for (constraints, (width, height)) in ASPECT_RATIO_CONSTRAINT_CASES {
+            let expected = Size { width, height };
+
+            // Leaf node as the root
+            let mut taffy: TaffyTree<()> = TaffyTree::new();
+            let node = taffy.new_leaf_with_context(aspect_ratio_style(constraints), ()).unwrap();
+            taffy.compute_layout_with_measure(node, Size::MAX_CONTENT, aspect_ratio_content_measure).unwrap();
+            assert_eq!(taffy.layout(node).unwrap().size, expected, "leaf with {constraints:?}");
+
+            // Flex and grid items
+            for display in [Display::Flex, Display::Grid] {
+                let mut taffy: TaffyTree<()> = TaffyTree::new();
+                let child = taffy.new_leaf_with_context(aspect_ratio_style(constraints), ()).unwrap();
+                let root = taffy
+                    .new_with_children(Style { display, size: length(500.0), ..Default::default() }, &[child])
+                    .unwrap();
+                taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, aspect_ratio_content_measure).unwrap();
+                assert_eq!(taffy.layout(child).unwrap().size, expected, "{display:?} item with {constraints:?}");
+            }
+        }
+    }
+
+    /// The min and max size of the root node clamp the available space that it is laid out in
+    #[test]
+    fn root_min_max_size_clamp_available_space() {
+        let available_spaces = [
+            Size::MAX_CONTENT,
+            Size::MIN_CONTENT,
+            Size { width: AvailableSpace::Definite(1000.0), height: AvailableSpace::Definite(1000.0) },
+        ];
+        for display in [Display::Block, Display::Flex, Display::Grid] {
+            for available_space in available_spaces {
+                let mut taffy: TaffyTree<()> = TaffyTree::new();
+                let style = Style {
+                    display,
+                    min_size: Size { width: Dimension::Length(100.0), height: Dimension::Length(50.0) },
+                    max_size: Size { width: Dimension::Length(500.0), height: Dimension::Length(400.0) },
+                    ..Default::default()
+                };
+
+                // Content larger than the max size
+                let large_child = taffy
+                    .new_leaf(Style {
+                        size: Size { width: Dimension::Length(800.0), height: Dimension::Length(800.0) },
+                        flex_shrink: 0.0,
+                        ..Default::default()
+                    })
+                    .unwrap();
+                let root = taffy.new_with_children(style.clone(), &[large_child]).unwrap();
+                taffy.compute_layout(root, available_space).unwrap();
+                assert_eq!(
+                    taffy.layout(root).unwrap().size,
+                    Size { width: 500.0, height: 400.0 },
+                    "{display:?} {available_space:?}"
+                );
+
+                // Content smaller than the min size
+                let small_child = taffy
+                    .new_leaf(Style {
+                        size: Size { width: Dimension::Length(10.0), height: Dimension::Length(10.0) },
+                        ..Default::default()
+                    })
+                    .unwrap();
+                let root = taffy.new_with_children(style, &[small_child]).unwrap();
+                taffy.compute_layout(root, available_space).unwrap();
+                // Block nodes stretch to fill a definite available width (up to their max size)
+                let expected_width = match (display, available_space.width) {
+                    (Display::Block, AvailableSpace::Definite(_)) => 500.0,
+                    _ => 100.0,
+                };
+                assert_eq!(
+                    taffy.layout(root).unwrap().size,
+                    Size { width: expected_width, height: 50.0 },
+                    "{display:?} {available_space:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn root_max_size_limits_max_content_size() {
+        // A leaf of text of the given length which wraps into 10px high lines to fit the available width
+        fn measure_text(
+            known_dimensions: Size<Option<f32>>,
+            available_space: Size<AvailableSpace>,
+            text_length: f32,
+        ) -> Size<f32> {
+            let width = known_dimensions.width.unwrap_or(match available_space.width {
+                AvailableSpace::Definite(width) => width.min(text_length),
+                AvailableSpace::MinContent => 10.0,
+                AvailableSpace::MaxContent => text_length,
+            });
+            Size { width, height: 10.0 * (text_length / width).ceil() }
+        }
+
+        for display in [Display::Block, Display::Flex, Display::Grid] {
+            for (text_length, expected_size) in
+                [(40.0, Size { width: 40.0, height: 10.0 }), (300.0, Size { width: 100.0, height: 30.0 })]
+            {
+                let mut taffy: TaffyTree<f32> = TaffyTree::new();
+                let text = taffy.new_leaf_with_context(Style::default(), text_length).unwrap();
+                let root = taffy
+                    .new_with_children(
+                        Style {
+                            display,
+                            max_size: Size { width: Dimension::Length(100.0), height: Dimension::Length(60.0) },
+                            ..Default::default()
+                        },
+                        &[text],
+                    )
+                    .unwrap();
+                taffy
+                    .compute_layout_with_measure(
+                        root,
+                        Size::MAX_CONTENT,
+                        |known_dimensions, available_space, _, ctx| {
+                            measure_text(known_dimensions, available_space, *ctx.unwrap())
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(taffy.layout(root).unwrap().size, expected_size, "{display:?} {text_length}");
+                assert_eq!(taffy.layout(text).unwrap().size, expected_size, "{display:?} {text_length}");
+            }
+        }
+    }
 }
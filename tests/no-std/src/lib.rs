@@ -0,0 +1,101 @@
+//! Checks that Taffy's grid layout builds and runs without the standard library, with grid tracks stored in
+//! fixed-capacity arrays (the `fixed-capacity-grids` feature).
+//!
+//! Run with `cargo test --manifest-path tests/no-std/Cargo.toml`. This crate is `no_std`, so it will fail to build if
+//! any of the code that it uses depends on `std`.
+#![no_std]
+
+#[cfg(test)]
+mod tests {
+    use taffy::geometry::Point;
+    use taffy::prelude::*;
+    use taffy::{TaffyError, MAX_GRID_SPAN, MAX_GRID_TRACKS};
+
+    #[test]
+    fn grid_layout() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let children: [NodeId; 4] = core::array::from_fn(|_| taffy.new_leaf(Style::default()).unwrap());
+        let style = Style {
+            display: Display::Grid,
+            size: Size { width: length(200.0), height: length(100.0) },
+            grid_template_columns: evenly_sized_tracks(2),
+            grid_template_rows: [length(40.0), fr(1.0)].into_iter().collect(),
+            ..Default::default()
+        };
+        let root = taffy.new_with_children(style, &children).unwrap();
+
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let expected =
+            [(0.0, 0.0, 100.0, 40.0), (100.0, 0.0, 100.0, 40.0), (0.0, 40.0, 100.0, 60.0), (100.0, 40.0, 100.0, 60.0)];
+        for (child, (x, y, width, height)) in children.into_iter().zip(expected) {
+            let layout = taffy.layout(child).unwrap();
+            assert_eq!(layout.location, Point { x, y });
+            assert_eq!(layout.size, Size { width, height });
+        }
+    }
+
+    #[test]
+    fn too_many_explicit_tracks_is_an_error() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+        let style = Style {
+            display: Display::Grid,
+            size: Size { width: length(100.0), height: auto() },
+            padding: length(5.0),
+            grid_template_columns: [repeat(MAX_GRID_TRACKS as u16 + 1, [length(10.0)].into_iter().collect())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let grid = taffy.new_with_children(style, &[child]).unwrap();
+
+        let result = taffy.compute_layout(grid, Size::MAX_CONTENT);
+        let expected_track_count = MAX_GRID_TRACKS + 1;
+        assert!(matches!(result, Err(TaffyError::GridTrackLimitExceeded { node, track_count })
+            if node == grid && track_count == expected_track_count));
+
+        // The grid is laid out as if it were empty, and its children are hidden
+        assert_eq!(taffy.layout(grid).unwrap().size, Size { width: 100.0, height: 10.0 });
+        assert_eq!(taffy.layout(child).unwrap().size, Size::ZERO);
+
+        // The error is reported again (rather than the cached layout being reused) until the style is fixed
+        let result = taffy.compute_layout(grid, Size::MAX_CONTENT);
+        assert!(matches!(result, Err(TaffyError::GridTrackLimitExceeded { node, .. }) if node == grid));
+        let mut style = taffy.style(grid).unwrap().clone();
+        style.grid_template_columns = evenly_sized_tracks(MAX_GRID_TRACKS as u16);
+        taffy.set_style(grid, style).unwrap();
+        taffy.compute_layout(grid, Size::MAX_CONTENT).unwrap();
+        assert_eq!(taffy.layout(child).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn too_many_implicit_tracks_in_nested_grid_is_an_error() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let items: [NodeId; MAX_GRID_TRACKS + 1] = core::array::from_fn(|_| taffy.new_leaf(Style::default()).unwrap());
+        let grid = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &items).unwrap();
+        let sibling = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[grid, sibling]).unwrap();
+
+        let result = taffy.compute_layout(root, Size::MAX_CONTENT);
+        let expected_track_count = MAX_GRID_TRACKS + 1;
+        assert!(matches!(result, Err(TaffyError::GridTrackLimitExceeded { node, track_count })
+            if node == grid && track_count == expected_track_count));
+
+        // The rest of the tree is still laid out
+        assert_eq!(taffy.layout(sibling).unwrap().size, Size { width: 10.0, height: 10.0 });
+    }
+
+    #[test]
+    fn enormous_span_is_clamped_before_the_track_limit_is_checked() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let item = taffy.new_leaf(Style { grid_column: span(u16::MAX), ..Default::default() }).unwrap();
+        let grid = taffy.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[item]).unwrap();
+
+        // The span is clamped to `MAX_GRID_SPAN` tracks, which is still more than can be stored
+        let result = taffy.compute_layout(grid, Size::MAX_CONTENT);
+        let expected_track_count = MAX_GRID_SPAN as usize;
+        assert!(matches!(result, Err(TaffyError::GridTrackLimitExceeded { node, track_count })
+            if node == grid && track_count == expected_track_count));
+    }
+}
@@ -0,0 +1,292 @@
+use taffy::prelude::*;
+use taffy::style::MAX_SIZE;
+
+/// Assert that the layout of every node in the tree has a finite size and location
+fn assert_layout_is_finite(taffy: &TaffyTree<()>, node: NodeId) {
+    let layout = taffy.layout(node).unwrap();
+    assert!(layout.size.width.is_finite(), "width of {node:?} is {}", layout.size.width);
+    assert!(layout.size.height.is_finite(), "height of {node:?} is {}", layout.size.height);
+    assert!(layout.location.x.is_finite(), "x of {node:?} is {}", layout.location.x);
+    assert!(layout.location.y.is_finite(), "y of {node:?} is {}", layout.location.y);
+    for (name, edges) in [("padding", layout.padding), ("border", layout.border)] {
+        let Rect { left, right, top, bottom } = edges;
+        assert!([left, right, top, bottom].iter().all(|value| value.is_finite()), "{name} of {node:?} is {edges:?}");
+    }
+    #[cfg(feature = "content_size")]
+    assert!(
+        layout.content_size.width.is_finite() && layout.content_size.height.is_finite(),
+        "content size of {node:?} is {:?}",
+        layout.content_size
+    );
+    for child in taffy.children(node).unwrap() {
+        assert_layout_is_finite(taffy, child);
+    }
+}
+
+/// Lay out a 100x100 container with the given style containing two 10x10 children with the given style
+fn layout_container(container_style: Style, child_style: Style) -> (TaffyTree<()>, NodeId, NodeId) {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy.new_leaf(Style { size: length(10.0), ..child_style }).unwrap();
+    let sibling = taffy.new_leaf(Style { size: length(10.0), ..Default::default() }).unwrap();
+    let root = taffy.new_with_children(Style { size: length(100.0), ..container_style }, &[child, sibling]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_layout_is_finite(&taffy, root);
+    (taffy, root, child)
+}
+
+#[test]
+fn nan_size_is_treated_as_auto() {
+    for display in [Display::Flex, Display::Grid, Display::Block] {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(Style { size: Size { width: length(f32::NAN), height: length(10.0) }, ..Default::default() })
+            .unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display, flex_direction: FlexDirection::Column, size: length(100.0), ..Default::default() },
+                &[child],
+            )
+            .unwrap();
+        taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_layout_is_finite(&taffy, root);
+        // An auto-width child stretches to fill its container in all three layout modes
+        assert_eq!(taffy.layout(child).unwrap().size.width, 100.0, "{display:?}");
+        assert_eq!(taffy.layout(child).unwrap().size.height, 10.0, "{display:?}");
+    }
+}
+
+#[test]
+fn nan_percentage_size_is_treated_as_auto() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let child = taffy
+        .new_leaf(Style { size: Size { width: percent(f32::NAN), height: length(10.0) }, ..Default::default() })
+        .unwrap();
+    let root = taffy.new_with_children(Style { size: length(100.0), ..Default::default() }, &[child]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_layout_is_finite(&taffy, root);
+    assert_eq!(taffy.layout(child).unwrap().size.width, 0.0);
+}
+
+#[test]
+fn infinite_size_is_clamped_to_max_size() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let node = taffy
+        .new_leaf(Style {
+            size: Size { width: length(f32::INFINITY), height: length(f32::NEG_INFINITY) },
+            ..Default::default()
+        })
+        .unwrap();
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+
+    assert_layout_is_finite(&taffy, node);
+    assert_eq!(taffy.layout(node).unwrap().size.width, MAX_SIZE);
+    assert_eq!(taffy.layout(node).unwrap().size.height, 0.0);
+}
+
+#[test]
+fn non_finite_margins() {
+    for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        for display in [Display::Flex, Display::Grid, Display::Block] {
+            let child_style = Style { margin: Rect { left: length(value), ..Rect::zero() }, ..Default::default() };
+            layout_container(Style { display, ..Default::default() }, child_style);
+        }
+    }
+
+    // A NaN margin is treated as zero
+    let child_style = Style { margin: Rect { left: length(f32::NAN), ..Rect::zero() }, ..Default::default() };
+    let (taffy, _, child) = layout_container(Style::default(), child_style);
+    assert_eq!(taffy.layout(child).unwrap().location.x, 0.0);
+}
+
+#[test]
+fn non_finite_flex_factors() {
+    for value in [f32::NAN, f32::INFINITY] {
+        let grow_style = Style { flex_grow: value, ..Default::default() };
+        let shrink_style = Style { flex_shrink: value, flex_basis: length(200.0), ..Default::default() };
+        layout_container(Style::default(), grow_style);
+        layout_container(Style::default(), shrink_style);
+    }
+
+    // A NaN flex-grow is treated as zero
+    let (taffy, _, child) = layout_container(Style::default(), Style { flex_grow: f32::NAN, ..Default::default() });
+    assert_eq!(taffy.layout(child).unwrap().size.width, 10.0);
+}
+
+#[test]
+fn non_finite_flex_basis() {
+    for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        for flex_wrap in [FlexWrap::NoWrap, FlexWrap::Wrap] {
+            let child_style = Style { flex_basis: length(value), flex_grow: 1.0, ..Default::default() };
+            layout_container(Style { flex_wrap, ..Default::default() }, child_style);
+        }
+    }
+}
+
+#[test]
+fn non_finite_gaps() {
+    for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        for display in [Display::Flex, Display::Grid] {
+            let container_style = Style { display, gap: length(value), ..Default::default() };
+            layout_container(container_style, Style::default());
+        }
+    }
+
+    // A NaN gap is treated as zero
+    let container_style = Style { gap: length(f32::NAN), ..Default::default() };
+    let (taffy, root, _) = layout_container(container_style, Style::default());
+    let sibling = taffy.child_at_index(root, 1).unwrap();
+    assert_eq!(taffy.layout(sibling).unwrap().location.x, 10.0);
+}
+
+#[test]
+fn non_finite_grid_tracks() {
+    for value in [f32::NAN, f32::INFINITY] {
+        let container_style = Style {
+            display: Display::Grid,
+            grid_template_columns: vec![fr(value), fr(1.0), length(value), minmax(length(value), fr(value))],
+            grid_template_rows: vec![fit_content(length(value)), percent(value)],
+            ..Default::default()
+        };
+        layout_container(container_style, Style::default());
+    }
+}
+
+/// A small deterministic pseudo-random number generator (xorshift), so that the fuzz test is reproducible without
+/// depending on an external crate
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<T: Copy>(&mut self, values: &[T]) -> T {
+        values[self.below(values.len())]
+    }
+
+    /// A random number, which is often non-finite, negative or very large
+    fn number(&mut self) -> f32 {
+        match self.below(8) {
+            0 => f32::NAN,
+            1 => f32::INFINITY,
+            2 => f32::NEG_INFINITY,
+            3 => self.pick(&[f32::MAX, f32::MIN, f32::MIN_POSITIVE, -0.0, 1e30]),
+            4 => f32::from_bits(self.next() as u32),
+            _ => (self.below(4000) as f32 - 1000.0) / 10.0,
+        }
+    }
+
+    fn dimension(&mut self) -> Dimension {
+        match self.below(3) {
+            0 => Dimension::Auto,
+            1 => length(self.number()),
+            _ => percent(self.number()),
+        }
+    }
+
+    fn length_percentage(&mut self) -> LengthPercentage {
+        if self.below(2) == 0 {
+            length(self.number())
+        } else {
+            percent(self.number())
+        }
+    }
+
+    fn length_percentage_auto(&mut self) -> LengthPercentageAuto {
+        match self.below(3) {
+            0 => LengthPercentageAuto::Auto,
+            1 => length(self.number()),
+            _ => percent(self.number()),
+        }
+    }
+
+    fn track(&mut self) -> TrackSizingFunction {
+        match self.below(5) {
+            0 => fr(self.number()),
+            1 => length(self.number()),
+            2 => percent(self.number()),
+            3 => fit_content(self.length_percentage()),
+            _ => minmax(length(self.number()), fr(self.number())),
+        }
+    }
+
+    fn style(&mut self) -> Style {
+        Style {
+            display: self.pick(&[Display::Flex, Display::Grid, Display::Block]),
+            position: self.pick(&[Position::Relative, Position::Relative, Position::Absolute]),
+            flex_direction: self.pick(&[FlexDirection::Row, FlexDirection::Column, FlexDirection::RowReverse]),
+            flex_wrap: self.pick(&[FlexWrap::NoWrap, FlexWrap::Wrap]),
+            align_items: self.pick(&[None, Some(AlignItems::Center), Some(AlignItems::Baseline)]),
+            justify_content: self.pick(&[None, Some(JustifyContent::SpaceEvenly), Some(JustifyContent::End)]),
+            size: Size { width: self.dimension(), height: self.dimension() },
+            min_size: Size { width: self.dimension(), height: self.dimension() },
+            max_size: Size { width: self.dimension(), height: self.dimension() },
+            aspect_ratio: self.pick(&[None, Some(1.5)]).map(|_| self.number()),
+            inset: Rect {
+                left: self.length_percentage_auto(),
+                right: self.length_percentage_auto(),
+                top: self.length_percentage_auto(),
+                bottom: self.length_percentage_auto(),
+            },
+            margin: Rect {
+                left: self.length_percentage_auto(),
+                right: self.length_percentage_auto(),
+                top: self.length_percentage_auto(),
+                bottom: self.length_percentage_auto(),
+            },
+            padding: Rect {
+                left: self.length_percentage(),
+                right: self.length_percentage(),
+                top: self.length_percentage(),
+                bottom: self.length_percentage(),
+            },
+            border: Rect {
+                left: self.length_percentage(),
+                right: self.length_percentage(),
+                top: self.length_percentage(),
+                bottom: self.length_percentage(),
+            },
+            gap: Size { width: self.length_percentage(), height: self.length_percentage() },
+            flex_basis: self.dimension(),
+            flex_grow: self.number(),
+            flex_shrink: self.number(),
+            grid_template_columns: (0..self.below(3)).map(|_| self.track()).collect(),
+            grid_template_rows: (0..self.below(3)).map(|_| self.track()).collect(),
+            grid_auto_columns: (0..self.below(2)).map(|_| minmax(length(self.number()), fr(self.number()))).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Add a randomly styled subtree (up to `depth` levels deep) to the tree
+fn random_subtree(taffy: &mut TaffyTree<()>, rng: &mut Rng, depth: usize) -> NodeId {
+    let child_count = if depth == 0 { 0 } else { rng.below(4) };
+    let children: Vec<NodeId> = (0..child_count).map(|_| random_subtree(taffy, rng, depth - 1)).collect();
+    let style = rng.style();
+    taffy.new_with_children(style, &children).unwrap()
+}
+
+#[test]
+fn fuzz_random_non_finite_styles() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..500 {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let root = random_subtree(&mut taffy, &mut rng, 3);
+        let available_height = rng.number();
+        let available_space = Size {
+            width: rng.pick(&[AvailableSpace::MinContent, AvailableSpace::MaxContent, AvailableSpace::Definite(80.0)]),
+            height: rng.pick(&[AvailableSpace::MaxContent, AvailableSpace::Definite(available_height)]),
+        };
+        taffy.compute_layout(root, available_space).unwrap();
+        assert_layout_is_finite(&taffy, root);
+    }
+}
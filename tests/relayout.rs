@@ -318,6 +318,34 @@ fn toggle_grid_container_display_none() {
     assert_eq!(layout.size.height, 0.0);
 }
 
+#[test]
+fn set_flex_child_max_width() {
+    let flex_style = Style { flex_grow: 1.0, flex_shrink: 1.0, flex_basis: percent(0.0), ..Default::default() };
+    let max_width_style = Style { max_size: Size { width: length(50.0), height: auto() }, ..flex_style.clone() };
+
+    // Setup
+    let mut taffy: TaffyTree<()> = taffy::TaffyTree::new();
+    let children: Vec<NodeId> = (0..3).map(|_| taffy.new_leaf(flex_style.clone()).unwrap()).collect();
+    let root = taffy
+        .new_with_children(
+            Style { size: Size { width: length(600.0), height: length(100.0) }, ..Default::default() },
+            &children,
+        )
+        .unwrap();
+
+    // Layout 1 (no max width)
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    let widths: Vec<f32> = children.iter().map(|child| taffy.layout(*child).unwrap().size.width).collect();
+    assert_eq!(widths, [200.0, 200.0, 200.0]);
+
+    // Layout 2 (max width on the middle child). The space it can no longer take is redistributed to its siblings.
+    taffy.set_style(children[1], max_width_style).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    let widths: Vec<f32> = children.iter().map(|child| taffy.layout(*child).unwrap().size.width).collect();
+    assert_eq!(widths, [275.0, 50.0, 275.0]);
+    assert_eq!(taffy.layout(children[2]).unwrap().location.x, 325.0);
+}
+
 #[test]
 fn relayout_is_stable_with_rounding() {
     let mut taffy: TaffyTree<()> = taffy::TaffyTree::new();
@@ -383,7 +411,8 @@ fn relayout_is_stable_with_rounding() {
         assert_eq!(wrapper_layout.size.height, 1080.0);
 
         let inner_layout = taffy.layout(inner).unwrap();
-        assert_eq!(inner_layout.location.x, -150.0);
+        // The inner node's absolute left edge (-148.5px) rounds to -149px, which is -151px from the outer node's 2px
+        assert_eq!(inner_layout.location.x, -151.0);
         assert_eq!(inner_layout.location.y, 0.0);
         assert_eq!(inner_layout.size.width, 301.0);
         assert_eq!(inner_layout.size.height, 1080.0);
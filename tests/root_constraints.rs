@@ -78,4 +78,109 @@ mod root_constraints {
         assert_eq!(layout.size.width, 200.0);
         assert_eq!(layout.size.height, 200.0);
     }
+
+    #[test]
+    fn root_with_aspect_ratio_and_width_from_available_space() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy
+            .new_leaf(taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: taffy::style::Dimension::Length(10.0),
+                    height: taffy::style::Dimension::Length(10.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let node = taffy
+            .new_with_children(
+                taffy::style::Style {
+                    display: taffy::style::Display::Block,
+                    aspect_ratio: Some(2.0),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                taffy::geometry::Size {
+                    width: AvailableSpace::Definite(200.0),
+                    height: AvailableSpace::Definite(500.0),
+                },
+            )
+            .unwrap();
+        let layout = taffy.layout(node).unwrap();
+
+        assert_eq!(layout.size.width, 200.0);
+        assert_eq!(layout.size.height, 100.0);
+    }
+
+    #[test]
+    fn root_with_aspect_ratio_and_percentage_width() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let node = taffy
+            .new_with_children(
+                taffy::style::Style {
+                    size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Percent(1.0),
+                        height: taffy::style::Dimension::Auto,
+                    },
+                    aspect_ratio: Some(2.0),
+                    ..Default::default()
+                },
+                &[],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                taffy::geometry::Size {
+                    width: AvailableSpace::Definite(200.0),
+                    height: AvailableSpace::Definite(500.0),
+                },
+            )
+            .unwrap();
+        let layout = taffy.layout(node).unwrap();
+
+        assert_eq!(layout.size.width, 200.0);
+        assert_eq!(layout.size.height, 100.0);
+    }
+
+    #[test]
+    fn root_with_aspect_ratio_and_max_height() {
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let child = taffy.new_leaf(taffy::style::Style::default()).unwrap();
+        let node = taffy
+            .new_with_children(
+                taffy::style::Style {
+                    display: taffy::style::Display::Block,
+                    max_size: taffy::geometry::Size {
+                        width: taffy::style::Dimension::Auto,
+                        height: taffy::style::Dimension::Length(60.0),
+                    },
+                    aspect_ratio: Some(2.0),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        taffy
+            .compute_layout(
+                node,
+                taffy::geometry::Size {
+                    width: AvailableSpace::Definite(200.0),
+                    height: AvailableSpace::Definite(500.0),
+                },
+            )
+            .unwrap();
+        let layout = taffy.layout(node).unwrap();
+
+        // The max height is transferred to the width through the aspect ratio
+        assert_eq!(layout.size.width, 120.0);
+        assert_eq!(layout.size.height, 60.0);
+    }
 }
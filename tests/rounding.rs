@@ -1,3 +1,4 @@
+use taffy::geometry::Point;
 use taffy::prelude::*;
 
 #[test]
@@ -27,3 +28,71 @@ fn rounding_doesnt_leave_gaps() {
     let layout_b = taffy.layout(child_b).unwrap();
     assert_eq!(layout_a.location.x + layout_a.size.width, layout_b.location.x);
 }
+
+#[test]
+fn rounding_end_aligned_items_end_at_container_edge() {
+    for justify_content in [JustifyContent::FlexEnd, JustifyContent::End] {
+        // The container is placed at a fractional offset so that the rounding of its position also comes into play
+        for container_offset in [0.0, 0.4, 0.6] {
+            let mut taffy: TaffyTree<()> = TaffyTree::new();
+            let item_style = Style { size: Size { width: length(33.33), height: length(10.0) }, ..Default::default() };
+            let items: Vec<NodeId> = (0..3).map(|_| taffy.new_leaf(item_style.clone()).unwrap()).collect();
+            let container_style = Style {
+                justify_content: Some(justify_content),
+                size: Size { width: length(100.0), height: length(10.0) },
+                ..Default::default()
+            };
+            let container = taffy.new_with_children(container_style, &items).unwrap();
+            let root_style =
+                Style { padding: Rect { left: length(container_offset), ..Rect::zero() }, ..Default::default() };
+            let root = taffy.new_with_children(root_style, &[container]).unwrap();
+            taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+            let container_width = taffy.layout(container).unwrap().size.width;
+            let mut edge = 0.0;
+            for &item in &items {
+                let layout = taffy.layout(item).unwrap();
+                assert_eq!(layout.location.x, edge, "{justify_content:?} with container offset {container_offset}");
+                edge = layout.location.x + layout.size.width;
+            }
+            assert_eq!(edge, container_width, "{justify_content:?} with container offset {container_offset}");
+        }
+    }
+}
+
+#[test]
+fn rounding_layout_offset_matches_round_layout() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let leaf_style = Style { size: Size { width: length(10.3), height: length(10.0) }, ..Default::default() };
+    let grandchild = taffy.new_leaf(leaf_style).unwrap();
+    let offset_style = Style { padding: Rect { left: length(0.6), ..Rect::zero() }, ..Default::default() };
+    let child = taffy.new_with_children(offset_style.clone(), &[grandchild]).unwrap();
+    let parent = taffy.new_with_children(offset_style.clone(), &[child]).unwrap();
+    let root = taffy.new_with_children(offset_style, &[parent]).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // The child is at x=0.6 within a parent at x=0.6, so both of their rounded left edges are at x=1
+    let layouts = |taffy: &TaffyTree<()>| {
+        [child, grandchild].map(|node| {
+            let layout = taffy.layout(node).unwrap();
+            (layout.location, layout.size)
+        })
+    };
+    let computed = layouts(&taffy);
+    assert_eq!(computed[0].0.x, 0.0);
+
+    // Setting a zero offset doesn't move anything
+    taffy.set_layout_offset(child, Point::ZERO).unwrap();
+    assert_eq!(layouts(&taffy), computed);
+
+    // Offsetting the child re-rounds its subtree from its new absolute position
+    taffy.set_layout_offset(child, Point { x: 0.3, y: 0.0 }).unwrap();
+    let offset = layouts(&taffy);
+    assert_eq!((offset[0].0.x, offset[0].1.width), (1.0, 10.0));
+    assert_eq!((offset[1].0.x, offset[1].1.width), (0.0, 10.0));
+
+    // Which is the same as recomputing the layout with the offset applied
+    taffy.mark_dirty(child).unwrap();
+    taffy.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(layouts(&taffy), offset);
+}